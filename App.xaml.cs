@@ -1,4 +1,5 @@
 using System;
+using System.Threading;
 using System.Threading.Tasks;
 using System.Windows;
 using System.Net.Http;
@@ -43,9 +44,13 @@ namespace EliteWhisper
                             services.AddSingleton<TrayIconService>();
                             services.AddSingleton<ModelRegistryService>();
                             services.AddSingleton<HistoryService>();
+                            services.AddSingleton<RecentModelsService>();
                             
                             // Local Models (Llama)
                             services.AddSingleton<ModelDownloadService>();
+                            services.AddSingleton<InstalledModelService>();
+                            services.AddSingleton<DefaultModelSetupService>();
+                            services.AddSingleton<ConfigBackupService>();
                             services.AddSingleton<LocalModelService>();
                             services.AddSingleton<LlamaCppService>();
                             services.AddSingleton<Services.LLM.LocalLlmProvider>();
@@ -135,8 +140,15 @@ namespace EliteWhisper
                             
                             // Speech Engines
                             services.AddSingleton<EliteWhisper.Services.Speech.HardwareDetectionService>();
+                            services.AddSingleton<EliteWhisper.Services.Speech.HotwordsService>();
+                            services.AddSingleton<EliteWhisper.Services.Speech.ReplacementsService>();
+                            services.AddSingleton<EliteWhisper.Services.Speech.ProfanityFilterService>();
+                            services.AddSingleton<EliteWhisper.Services.Speech.HallucinationFilterService>();
+                            services.AddSingleton<EliteWhisper.Services.Speech.SpeakerDiarizationService>();
                             services.AddSingleton<EliteWhisper.Services.Speech.SpeechEngineSelector>();
                             services.AddSingleton<EliteWhisper.Services.Speech.SpeechRecognitionService>();
+                            services.AddSingleton<EliteWhisper.Services.Speech.TranscriptionQueueService>();
+                            services.AddSingleton<BatchTranscriptionService>();
                             
                             // Updates
                             services.AddSingleton<IUpdateService, UpdateService>();
@@ -197,6 +209,9 @@ namespace EliteWhisper
                 // Proceed with normal startup
                 Log("Starting AppHost...");
                 await AppHost!.StartAsync();
+
+                // Temp recordings left by a crash or an earlier failed transcription
+                _ = Task.Run(TempFileCleanup.SweepStale);
                 
                 // Start Update Service
                 Log("Starting Update Service...");
@@ -228,6 +243,44 @@ namespace EliteWhisper
                     Shutdown();
                 };
                 
+                _trayIcon.ModelSwitchRequested += async (s, entry) =>
+                {
+                    if (AppHost.Services.GetRequiredService<AIEngineService>().IsActivating)
+                    {
+                        _trayIcon?.ShowBalloon("Elite Whisper", "Another model is still loading. Try again in a moment.", System.Windows.Forms.ToolTipIcon.Info);
+                        return;
+                    }
+
+                    try
+                    {
+                        if (entry.Engine == "Sherpa")
+                        {
+                            var selector = AppHost.Services.GetRequiredService<EliteWhisper.Services.Speech.SpeechEngineSelector>();
+                            string? error = await Task.Run(() => selector.ActivateSherpaModel(entry.Path));
+                            if (error != null)
+                            {
+                                _trayIcon?.ShowBalloon("Elite Whisper", $"Could not switch to {entry.Name}: {error}", System.Windows.Forms.ToolTipIcon.Warning);
+                                return;
+                            }
+                            AppHost.Services.GetRequiredService<RecentModelsService>().RecordUsage(entry.Path, entry.Engine);
+                        }
+                        else
+                        {
+                            var aiEngine = AppHost.Services.GetRequiredService<AIEngineService>();
+                            if (!await aiEngine.ActivateModelAsync(entry.Path))
+                            {
+                                _trayIcon?.ShowBalloon("Elite Whisper", $"Could not switch to {entry.Name}. {aiEngine.LastActivationError}", System.Windows.Forms.ToolTipIcon.Warning);
+                                return;
+                            }
+                        }
+                        _trayIcon?.SetStatus(entry.Name);
+                    }
+                    catch (Exception ex)
+                    {
+                        _trayIcon?.ShowBalloon("Elite Whisper", $"Could not switch to {entry.Name}: {ex.Message}", System.Windows.Forms.ToolTipIcon.Warning);
+                    }
+                };
+                
                 _trayIcon.ShowWidgetRequested += (s, args) =>
                 {
                     if (_widgetWindow != null)
@@ -236,6 +289,59 @@ namespace EliteWhisper
                         _widgetWindow.Activate();
                     }
                 };
+
+                _trayIcon.ResetWidgetPositionRequested += (s, args) => _widgetWindow?.ResetPosition();
+                
+                // Tell the user where the text went when typing into the target window isn't possible
+                var injectionService = AppHost.Services.GetRequiredService<TextInjectionService>();
+                injectionService.TypingUnavailable += (s, reason) =>
+                {
+                    Dispatcher.Invoke(() => _trayIcon?.ShowBalloon("Elite Whisper",
+                        $"Couldn't type the text, so it was copied to the clipboard. {reason}",
+                        System.Windows.Forms.ToolTipIcon.Warning));
+                };
+                
+                // Let the user know when the primary speech engine had to be bypassed
+                var speechService = AppHost.Services.GetRequiredService<EliteWhisper.Services.Speech.SpeechRecognitionService>();
+                speechService.FallbackUsed += (s, reason) =>
+                {
+                    Dispatcher.Invoke(() => _trayIcon?.ShowBalloon("Elite Whisper",
+                        $"Used Whisper fallback ({reason}).",
+                        System.Windows.Forms.ToolTipIcon.Info));
+                };
+                
+                var engineSelector = AppHost.Services.GetRequiredService<EliteWhisper.Services.Speech.SpeechEngineSelector>();
+                engineSelector.EngineReset += (s, message) =>
+                {
+                    Dispatcher.Invoke(() => _trayIcon?.ShowBalloon("Elite Whisper",
+                        $"The speech engine hit an error and was reset: {message}.",
+                        System.Windows.Forms.ToolTipIcon.Warning));
+                };
+
+                var whisperEngine = AppHost.Services.GetRequiredService<AIEngineService>();
+                whisperEngine.GpuUnavailable += (s, reason) =>
+                {
+                    Dispatcher.Invoke(() => _trayIcon?.ShowBalloon("Elite Whisper",
+                        $"GPU acceleration is unavailable, transcribing on the CPU instead ({reason}).",
+                        System.Windows.Forms.ToolTipIcon.Warning));
+                };
+
+                var audioCapture = AppHost.Services.GetRequiredService<AudioCaptureService>();
+                audioCapture.DeviceLost += (s, reason) =>
+                {
+                    Dispatcher.Invoke(() => _trayIcon?.ShowBalloon("Elite Whisper",
+                        "The microphone was disconnected. Transcribing what was recorded before it dropped.",
+                        System.Windows.Forms.ToolTipIcon.Warning));
+                };
+
+                speechService.LowQualityResult += (s, score) =>
+                {
+                    bool suppressed = configService.CurrentConfiguration.SuppressLowQualityOutput;
+                    Dispatcher.Invoke(() => _trayIcon?.ShowBalloon("Elite Whisper",
+                        $"The transcript looks unreliable (quality {score:P0}). " +
+                        (suppressed ? "It was saved to history but not typed. Please re-record." : "Please check it."),
+                        System.Windows.Forms.ToolTipIcon.Warning));
+                };
                 
                 // Initialize widget window
                 Log("Initializing Widget Window...");
@@ -265,6 +371,9 @@ namespace EliteWhisper
                 });
 
                 base.OnStartup(e);
+
+                // Asked once the window and tray are up, so startup doesn't wait on the prompt or the download
+                _ = Dispatcher.InvokeAsync(OfferDefaultModelAsync, System.Windows.Threading.DispatcherPriority.ApplicationIdle);
             }
             catch (Exception ex)
             {
@@ -274,6 +383,42 @@ namespace EliteWhisper
             }
         }
 
+        /// <summary>
+        /// Offers to download a small default model when none is installed. Asked only once.
+        /// </summary>
+        private async Task OfferDefaultModelAsync()
+        {
+            var setup = AppHost!.Services.GetRequiredService<DefaultModelSetupService>();
+            if (!setup.ShouldOfferDefaultModel()) return;
+
+            setup.MarkOffered();
+            var info = setup.GetDefaultModelInfo();
+            if (info == null) return;
+
+            Log("No speech model installed - offering default model");
+            var answer = MessageBox.Show(
+                $"No speech model is installed yet.\n\nDownload the \"{info.Value.Name}\" model (~{info.Value.SizeMB} MB) now?",
+                "Elite Whisper", MessageBoxButton.YesNo, MessageBoxImage.Question);
+            if (answer != MessageBoxResult.Yes) return;
+
+            try
+            {
+                var progress = new Progress<Models.DownloadProgress>(p => _trayIcon?.SetStatus($"Downloading model {p.Percent:F0}%"));
+                await setup.InstallDefaultModelAsync(progress, CancellationToken.None);
+
+                _trayIcon?.SetStatus("Ready");
+                _trayIcon?.ShowBalloon("Elite Whisper", $"{info.Value.Name} model installed. You're ready to dictate.",
+                    System.Windows.Forms.ToolTipIcon.Info);
+            }
+            catch (Exception ex)
+            {
+                Log($"Default model download failed: {ex.Message}");
+                _trayIcon?.SetStatus("Ready");
+                MessageBox.Show($"Model download failed: {ex.Message}\n\nYou can download a model from the Models page.",
+                    "Elite Whisper", MessageBoxButton.OK, MessageBoxImage.Warning);
+            }
+        }
+
         protected override async void OnExit(ExitEventArgs e)
         {
             Log("OnExit Called");
@@ -0,0 +1,101 @@
+using EliteWhisper.Models;
+using EliteWhisper.Services;
+
+namespace EliteWhisper.Tests.Services
+{
+    public class AIEngineServiceTests : IDisposable
+    {
+        private readonly string _directory = Path.Combine(Path.GetTempPath(), $"elitewhisper_tests_{Guid.NewGuid():N}");
+
+        public void Dispose()
+        {
+            try { Directory.Delete(_directory, recursive: true); } catch { }
+        }
+
+        [Theory]
+        [InlineData("plain", "\"plain\"")]
+        [InlineData("say \"hi\"", "\"say \\\"hi\\\"\"")]
+        [InlineData(@"\[.*\]", @"""\[.*\]""")]
+        [InlineData(@"ends with \", @"""ends with \\""")]
+        [InlineData(@"a\""b", @"""a\\\""b""")]
+        public void QuoteArgument_SurvivesWindowsArgumentSplitting(string value, string expected)
+        {
+            Assert.Equal(expected, AIEngineService.QuoteArgument(value));
+        }
+
+        [Fact]
+        public void DecodingArguments_GreedyUsesBeamSizeOne()
+        {
+            var config = new WhisperConfiguration { DecodingStrategy = DecodingStrategy.Greedy, BeamSize = 8 };
+
+            string args = AIEngineService.BuildDecodingArguments(config, null);
+
+            Assert.Contains(" -bs 1 ", args);
+        }
+
+        [Theory]
+        [InlineData(5, 5)]
+        [InlineData(1, 2)]
+        [InlineData(50, 16)]
+        public void DecodingArguments_BeamSearchUsesClampedBeamSize(int beamSize, int expected)
+        {
+            var config = new WhisperConfiguration { DecodingStrategy = DecodingStrategy.BeamSearch, BeamSize = beamSize };
+
+            string args = AIEngineService.BuildDecodingArguments(config, null);
+
+            Assert.Contains($" -bs {expected} ", args);
+        }
+
+        [Fact]
+        public void DecodingArguments_DefaultsEnableTemperatureFallback()
+        {
+            string args = AIEngineService.BuildDecodingArguments(new WhisperConfiguration(), null);
+
+            Assert.Contains(" -tp 0.0 ", args);
+            Assert.Contains(" -tpi 0.2 ", args);
+            Assert.Contains(" -et 2.4 ", args);
+            Assert.Contains(" -lpt -1.0 ", args);
+            Assert.Contains(" -nth 0.6", args);
+            Assert.DoesNotContain(" -nf", args);
+        }
+
+        [Fact]
+        public void DecodingArguments_ZeroIncrementDisablesFallback()
+        {
+            var config = new WhisperConfiguration { WhisperTemperature = 0.3, WhisperTemperatureIncrement = 0.0 };
+
+            string args = AIEngineService.BuildDecodingArguments(config, null);
+
+            Assert.Contains(" -tp 0.3 ", args);
+            Assert.Contains(" -nf", args);
+            Assert.DoesNotContain(" -tpi ", args);
+        }
+
+        [Fact]
+        public async Task ActivateModelAsync_RejectsASecondLoadWhileTheFirstRuns()
+        {
+            var config = new WhisperConfigurationService(_directory);
+            config.CurrentConfiguration.ExecutablePath = CreateFile("whisper-cli.exe");
+            string model = CreateFile("ggml-base.bin");
+
+            var probe = new TaskCompletionSource();
+            var engine = new AIEngineService(config, new RecentModelsService(_directory), _ => probe.Task);
+
+            var first = engine.ActivateModelAsync(model);
+            Assert.True(engine.IsActivating);
+            await Assert.ThrowsAsync<InvalidOperationException>(() => engine.ActivateModelAsync(model));
+
+            probe.SetResult();
+            Assert.True(await first);
+            Assert.False(engine.IsActivating);
+            Assert.Equal(model, config.CurrentConfiguration.DefaultModelPath);
+        }
+
+        private string CreateFile(string name)
+        {
+            string path = Path.Combine(_directory, name);
+            File.WriteAllBytes(path, new byte[16]);
+            return path;
+        }
+    }
+}
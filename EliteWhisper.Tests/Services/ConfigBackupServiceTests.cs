@@ -0,0 +1,140 @@
+using EliteWhisper.Services;
+using EliteWhisper.Services.Speech;
+
+namespace EliteWhisper.Tests.Services
+{
+    public class ConfigBackupServiceTests : IDisposable
+    {
+        private readonly string _directory = Path.Combine(Path.GetTempPath(), $"elitewhisper_tests_{Guid.NewGuid():N}");
+
+        public void Dispose()
+        {
+            try { Directory.Delete(_directory, recursive: true); } catch { }
+        }
+
+        [Theory]
+        [InlineData(true, false)]
+        [InlineData(false, true)]
+        public void Import_KeepsTheLocalHistoryEncryptionSetting(bool exported, bool local)
+        {
+            var (sourceConfig, source) = CreateMachine("source");
+            sourceConfig.CurrentConfiguration.EncryptHistory = exported;
+            string backup = Path.Combine(_directory, "backup.json");
+            source.Export(backup);
+
+            var (targetConfig, target) = CreateMachine("target");
+            targetConfig.CurrentConfiguration.EncryptHistory = local;
+            target.Import(backup);
+
+            Assert.Equal(local, targetConfig.CurrentConfiguration.EncryptHistory);
+        }
+
+        [Fact]
+        public void Import_KeepsTheLocalMicrophone()
+        {
+            var (sourceConfig, source) = CreateMachine("source");
+            sourceConfig.CurrentConfiguration.InputDeviceName = "USB Headset";
+            string backup = Path.Combine(_directory, "backup.json");
+            source.Export(backup);
+
+            var (targetConfig, target) = CreateMachine("target");
+            targetConfig.CurrentConfiguration.InputDeviceName = "Laptop Microphone";
+            target.Import(backup);
+
+            Assert.Equal("Laptop Microphone", targetConfig.CurrentConfiguration.InputDeviceName);
+        }
+
+        [Fact]
+        public void Import_KeepsTheLocalWidgetPosition()
+        {
+            var (sourceConfig, source) = CreateMachine("source");
+            sourceConfig.CurrentConfiguration.WidgetLeft = 3200;
+            sourceConfig.CurrentConfiguration.WidgetTop = 1400;
+            string backup = Path.Combine(_directory, "backup.json");
+            source.Export(backup);
+
+            var (targetConfig, target) = CreateMachine("target");
+            target.Import(backup);
+
+            Assert.Null(targetConfig.CurrentConfiguration.WidgetLeft);
+            Assert.Null(targetConfig.CurrentConfiguration.WidgetTop);
+        }
+
+        [Fact]
+        public void Import_FindsTheStreamingModelInTheLocalModelsFolder()
+        {
+            var (sourceConfig, source) = CreateMachine("source");
+            sourceConfig.CurrentConfiguration.StreamingModelPath = CreateModelFolder("source", "streaming-zipformer");
+            string backup = Path.Combine(_directory, "backup.json");
+            source.Export(backup);
+
+            var (targetConfig, target) = CreateMachine("target");
+            string localModel = CreateModelFolder("target", "streaming-zipformer");
+            targetConfig.CurrentConfiguration.ModelsDirectory = Path.GetDirectoryName(localModel);
+            target.Import(backup);
+
+            Assert.Equal(localModel, targetConfig.CurrentConfiguration.StreamingModelPath);
+        }
+
+        [Fact]
+        public void Import_KeepsTheLocalStreamingModelWhenTheBackedUpOneIsMissing()
+        {
+            var (sourceConfig, source) = CreateMachine("source");
+            sourceConfig.CurrentConfiguration.StreamingModelPath = CreateModelFolder("source", "streaming-zipformer");
+            string backup = Path.Combine(_directory, "backup.json");
+            source.Export(backup);
+
+            var (targetConfig, target) = CreateMachine("target");
+            string localModel = CreateModelFolder("target", "other-model");
+            targetConfig.CurrentConfiguration.ModelsDirectory = Path.GetDirectoryName(localModel);
+            targetConfig.CurrentConfiguration.StreamingModelPath = localModel;
+            target.Import(backup);
+
+            Assert.Equal(localModel, targetConfig.CurrentConfiguration.StreamingModelPath);
+        }
+
+        [Fact]
+        public void Import_ResolvesDiarizationModelsLocallyOrKeepsTheLocalOnes()
+        {
+            var (sourceConfig, source) = CreateMachine("source");
+            sourceConfig.CurrentConfiguration.DiarizationSegmentationModel = CreateModelFile("source", "segmentation.onnx");
+            sourceConfig.CurrentConfiguration.DiarizationEmbeddingModel = CreateModelFile("source", "embedding.onnx");
+            string backup = Path.Combine(_directory, "backup.json");
+            source.Export(backup);
+
+            var (targetConfig, target) = CreateMachine("target");
+            string segmentation = CreateModelFile("target", "segmentation.onnx");
+            string localEmbedding = Path.Combine(_directory, "elsewhere", "titanet.onnx");
+            targetConfig.CurrentConfiguration.ModelsDirectory = Path.GetDirectoryName(segmentation);
+            targetConfig.CurrentConfiguration.DiarizationEmbeddingModel = localEmbedding;
+            target.Import(backup);
+
+            Assert.Equal(segmentation, targetConfig.CurrentConfiguration.DiarizationSegmentationModel);
+            Assert.Equal(localEmbedding, targetConfig.CurrentConfiguration.DiarizationEmbeddingModel);
+        }
+
+        private string CreateModelFile(string machine, string name)
+        {
+            string path = Path.Combine(_directory, machine, "models", name);
+            Directory.CreateDirectory(Path.GetDirectoryName(path)!);
+            File.WriteAllBytes(path, new byte[16]);
+            return path;
+        }
+
+        private string CreateModelFolder(string machine, string name)
+        {
+            string path = Path.Combine(_directory, machine, "models", name);
+            Directory.CreateDirectory(path);
+            return path;
+        }
+
+        private (WhisperConfigurationService Config, ConfigBackupService Backup) CreateMachine(string name)
+        {
+            string directory = Path.Combine(_directory, name);
+            var config = new WhisperConfigurationService(directory);
+            var backup = new ConfigBackupService(config, new HotwordsService(directory), new ReplacementsService(directory),
+                new ProfanityFilterService(directory), new HallucinationFilterService(directory));
+            return (config, backup);
+        }
+    }
+}
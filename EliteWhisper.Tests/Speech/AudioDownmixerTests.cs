@@ -0,0 +1,70 @@
+using EliteWhisper.Services.Speech;
+
+namespace EliteWhisper.Tests.Speech
+{
+    public class AudioDownmixerTests
+    {
+        [Fact]
+        public void FourChannels_AverageEachFrame()
+        {
+            float[] interleaved =
+            {
+                0.4f, 0.0f, 0.0f, 0.0f,
+                0.1f, 0.2f, 0.3f, 0.4f,
+                -1f, -1f, 1f, 1f
+            };
+
+            float[] mono = AudioDownmixer.ToMono(interleaved, 4);
+
+            Assert.Equal(3, mono.Length);
+            Assert.Equal(0.1f, mono[0], 5);
+            Assert.Equal(0.25f, mono[1], 5);
+            Assert.Equal(0f, mono[2], 5);
+        }
+
+        [Fact]
+        public void SixChannels_KeepSpeechOnlyInTheCenter()
+        {
+            // 5.1 with dialogue on the center channel only
+            float[] interleaved = { 0f, 0f, 0.6f, 0f, 0f, 0f };
+
+            float[] mono = AudioDownmixer.ToMono(interleaved, 6);
+
+            Assert.Equal(0.1f, Assert.Single(mono), 5);
+        }
+
+        [Fact]
+        public void OddLengthStereo_KeepsTheTrailingSample()
+        {
+            // A truncated stereo file: the last frame only has its left sample
+            float[] interleaved = { 0.2f, 0.4f, -0.6f, 0.0f, 0.8f };
+
+            float[] mono = AudioDownmixer.ToMono(interleaved, 2);
+
+            Assert.Equal(3, mono.Length);
+            Assert.Equal(0.3f, mono[0], 5);
+            Assert.Equal(-0.3f, mono[1], 5);
+            Assert.Equal(0.8f, mono[2], 5);
+        }
+
+        [Fact]
+        public void SingleSampleStereo_DoesNotThrow()
+        {
+            Assert.Equal(0.5f, Assert.Single(AudioDownmixer.ToMono(new[] { 0.5f }, 2)));
+        }
+
+        [Fact]
+        public void Mono_IsReturnedAsIs()
+        {
+            float[] samples = { 0.1f, 0.2f };
+
+            Assert.Same(samples, AudioDownmixer.ToMono(samples, 1));
+        }
+
+        [Fact]
+        public void ZeroChannels_Throws()
+        {
+            Assert.Throws<ArgumentOutOfRangeException>(() => AudioDownmixer.ToMono(new float[4], 0));
+        }
+    }
+}
@@ -0,0 +1,41 @@
+using EliteWhisper.Services.Speech;
+
+namespace EliteWhisper.Tests.Speech
+{
+    public class AudioResamplingTests
+    {
+        [Fact]
+        public void ResampleMono_48kHzSine_ComesOutAThirdAsLong()
+        {
+            float[] sine = Sine(48000, 440, seconds: 1.0);
+
+            float[] resampled = SpeechRecognitionService.ResampleMono(sine, 48000, 16000);
+
+            Assert.InRange(resampled.Length, 15800, 16200);
+
+            // The tone survives; a broken resampler tends to come out silent or clipped
+            double rms = Math.Sqrt(resampled.Skip(1000).Take(14000).Average(s => (double)s * s));
+            Assert.InRange(rms, 0.3, 0.42);
+        }
+
+        [Fact]
+        public void ResampleMono_AtTargetRate_KeepsLength()
+        {
+            float[] sine = Sine(16000, 440, seconds: 0.5);
+
+            float[] resampled = SpeechRecognitionService.ResampleMono(sine, 16000, 16000);
+
+            Assert.Equal(sine.Length, resampled.Length);
+        }
+
+        private static float[] Sine(int sampleRate, double frequency, double seconds)
+        {
+            var samples = new float[(int)(sampleRate * seconds)];
+            for (int i = 0; i < samples.Length; i++)
+            {
+                samples[i] = 0.5f * (float)Math.Sin(2 * Math.PI * frequency * i / sampleRate);
+            }
+            return samples;
+        }
+    }
+}
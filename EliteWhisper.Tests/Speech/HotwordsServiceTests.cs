@@ -0,0 +1,82 @@
+using EliteWhisper.Models;
+using EliteWhisper.Services.Speech;
+
+namespace EliteWhisper.Tests.Speech
+{
+    public class HotwordsServiceTests : IDisposable
+    {
+        private readonly string _directory = Path.Combine(Path.GetTempPath(), $"elitewhisper_tests_{Guid.NewGuid():N}");
+
+        public void Dispose()
+        {
+            try { Directory.Delete(_directory, recursive: true); } catch { }
+        }
+
+        [Fact]
+        public void SaveAndLoad_RoundTripsScores()
+        {
+            var service = new HotwordsService(_directory);
+
+            service.SaveHotwords(new[] { Hotword.Parse("Okonkwo :2.5"), Hotword.Parse("Kubernetes"), Hotword.Parse("kubernetes") });
+
+            var loaded = service.GetHotwords();
+            Assert.Equal(2, loaded.Count);
+            Assert.Equal("Okonkwo", loaded[0].Word);
+            Assert.Equal(2.5f, loaded[0].Score);
+            Assert.Null(loaded[1].Score);
+        }
+
+        [Fact]
+        public void Save_BumpsVersion()
+        {
+            var service = new HotwordsService(_directory);
+            int before = service.Version;
+
+            service.SaveHotwords(new[] { Hotword.Parse("alpha") });
+
+            Assert.Equal(before + 1, service.Version);
+        }
+
+        [Fact]
+        public void Save_RejectsNonPositiveScore()
+        {
+            var service = new HotwordsService(_directory);
+
+            Assert.Throws<ArgumentOutOfRangeException>(() => service.SaveHotwords(new[] { new Hotword { Word = "zero", Score = 0 } }));
+        }
+
+        [Fact]
+        public async Task ConcurrentSavesAndReads_NeverSeeAPartialList()
+        {
+            var service = new HotwordsService(_directory);
+            var small = Enumerable.Range(0, 5).Select(i => new Hotword { Word = $"small{i}" }).ToList();
+            var large = Enumerable.Range(0, 500).Select(i => new Hotword { Word = $"large{i}", Score = 1.5f }).ToList();
+            service.SaveHotwords(small);
+
+            using var cts = new CancellationTokenSource(TimeSpan.FromSeconds(2));
+            var writer = Task.Run(() =>
+            {
+                for (int i = 0; !cts.IsCancellationRequested; i++)
+                    service.SaveHotwords(i % 2 == 0 ? large : small);
+            });
+
+            var counts = new HashSet<int>();
+            var reader = Task.Run(() =>
+            {
+                while (!cts.IsCancellationRequested)
+                {
+                    counts.Add(service.GetHotwords().Count);
+
+                    string? snapshot = service.CreateSnapshot();
+                    if (snapshot == null) continue;
+                    counts.Add(File.ReadAllLines(snapshot).Length);
+                    File.Delete(snapshot);
+                }
+            });
+
+            await Task.WhenAll(writer, reader);
+
+            Assert.Subset(new HashSet<int> { small.Count, large.Count }, counts);
+        }
+    }
+}
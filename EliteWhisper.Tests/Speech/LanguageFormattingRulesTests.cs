@@ -0,0 +1,79 @@
+using EliteWhisper.Services.Speech;
+
+namespace EliteWhisper.Tests.Speech
+{
+    public class LanguageFormattingRulesTests
+    {
+        [Theory]
+        [InlineData("hello , world", "Hello, world")]
+        [InlineData("i think i'm  late", "I think I'm late")]
+        [InlineData("the value is 3.5 today", "The value is 3.5 today")]
+        public void English_TidiesSpacingAndCapitals(string input, string expected)
+        {
+            Assert.Equal(expected, LanguageFormattingRules.Apply(input, "en"));
+        }
+
+        [Theory]
+        [InlineData("we deploy on ASP.NET now", "We deploy on ASP.NET now")]
+        [InlineData("the server runs Node.JS here", "The server runs Node.JS here")]
+        public void English_LeavesDottedNamesAlone(string input, string expected)
+        {
+            Assert.Equal(expected, LanguageFormattingRules.Apply(input, "en"));
+        }
+
+        [Fact]
+        public void English_SplitsRunTogetherSentences()
+        {
+            Assert.Equal("That is done. Next step", LanguageFormattingRules.Apply("that is done.Next step", "en"));
+        }
+
+        [Theory]
+        [InlineData("42")]
+        [InlineData("search term")]
+        public void FinalPeriod_IsOffByDefault(string input)
+        {
+            Assert.False(LanguageFormattingRules.Apply(input, "en").EndsWith('.'));
+        }
+
+        [Fact]
+        public void FinalPeriod_AddedWhenRequested()
+        {
+            Assert.Equal("It works.", LanguageFormattingRules.Apply("it works", "en", addFinalPeriod: true));
+            Assert.Equal("Done!", LanguageFormattingRules.Apply("done!", "en", addFinalPeriod: true));
+        }
+
+        [Fact]
+        public void French_UsesNarrowSpaceBeforeHighPunctuation()
+        {
+            Assert.Equal("Bonjour\u202F! Ça va\u202F?", LanguageFormattingRules.Apply("bonjour! ça va?", "fr"));
+        }
+
+        [Fact]
+        public void French_KeepsTimesTogether()
+        {
+            Assert.Equal("Rendez-vous à 10:30", LanguageFormattingRules.Apply("rendez-vous à 10:30", "fr"));
+        }
+
+        [Fact]
+        public void Chinese_DropsSpacesAndUsesFullWidthPunctuation()
+        {
+            Assert.Equal("你好，世界。", LanguageFormattingRules.Apply("你好 , 世界 .", "zh"));
+        }
+
+        [Fact]
+        public void Japanese_DropsSpacesBetweenKana()
+        {
+            Assert.Equal("こんにちは世界！", LanguageFormattingRules.Apply("こんにちは 世界 !", "ja"));
+        }
+
+        [Theory]
+        [InlineData("これはテストです", "ja")]
+        [InlineData("这是测试", "zh")]
+        [InlineData("안녕하세요", "ko")]
+        [InlineData("plain text", "latin")]
+        public void DetectFromScript_GuessesFamily(string input, string expected)
+        {
+            Assert.Equal(expected, LanguageFormattingRules.DetectFromScript(input));
+        }
+    }
+}
@@ -0,0 +1,37 @@
+using EliteWhisper.Services.Speech;
+
+namespace EliteWhisper.Tests.Speech
+{
+    public class SpokenNumberFormatterTests
+    {
+        [Theory]
+        [InlineData("I counted one hundred and five apples", "I counted 105 apples")]
+        [InlineData("a discount of twenty percent", "a discount of 20%")]
+        [InlineData("that was twenty five dollars", "that was $25")]
+        [InlineData("five dollars and twenty cents", "$5.20")]
+        [InlineData("pi is three point one four", "pi is 3.14")]
+        [InlineData("two thousand and five", "2005")]
+        [InlineData("we met on March third", "we met on March 3rd")]
+        [InlineData("the twenty first of june", "June 21st")]
+        public void Apply_WritesNumbersAsDigits(string input, string expected)
+        {
+            Assert.Equal(expected, SpokenNumberFormatter.Apply(input, "en"));
+        }
+
+        [Theory]
+        [InlineData("one of them said no")]
+        [InlineData("you may first need to sign in")]
+        [InlineData("five five")]
+        public void Apply_LeavesWordsThatAreNotQuantities(string input)
+        {
+            Assert.Equal(input, SpokenNumberFormatter.Apply(input, "en"));
+        }
+
+        [Fact]
+        public void Apply_IgnoresUnsupportedLanguages()
+        {
+            Assert.Equal("zwanzig percent", SpokenNumberFormatter.Apply("zwanzig percent", "de"));
+            Assert.Equal("twenty percent", SpokenNumberFormatter.Apply("twenty percent", "de"));
+        }
+    }
+}
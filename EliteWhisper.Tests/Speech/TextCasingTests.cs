@@ -0,0 +1,40 @@
+using EliteWhisper.Models;
+using EliteWhisper.Services.Speech;
+
+namespace EliteWhisper.Tests.Speech
+{
+    public class TextCasingTests
+    {
+        [Theory]
+        [InlineData("the lord of the rings", "The Lord of the Rings")]
+        [InlineData("what to look for", "What to Look For")]
+        [InlineData("a tale of two cities: the beginning", "A Tale of Two Cities: The Beginning")]
+        [InlineData("a well-known fact", "A Well-Known Fact")]
+        [InlineData("using NASA data on the iPhone", "Using NASA Data on the iPhone")]
+        public void Title_LowercasesSmallWordsAndKeepsAcronyms(string input, string expected)
+        {
+            Assert.Equal(expected, TextCasing.Apply(input, OutputCasing.Title));
+        }
+
+        [Fact]
+        public void Sentence_CapitalizesSentenceStartsAndI()
+        {
+            string result = TextCasing.Apply("hello There. how are you? i'm fine, says NASA", OutputCasing.Sentence);
+
+            Assert.Equal("Hello there. How are you? I'm fine, says NASA", result);
+        }
+
+        [Fact]
+        public void LowerAndUpper_ChangeEverything()
+        {
+            Assert.Equal("send it to nasa", TextCasing.Apply("Send it to NASA", OutputCasing.Lower));
+            Assert.Equal("SEND IT TO NASA", TextCasing.Apply("Send it to NASA", OutputCasing.Upper));
+        }
+
+        [Fact]
+        public void Verbatim_LeavesTextAlone()
+        {
+            Assert.Equal("mIxEd Case", TextCasing.Apply("mIxEd Case", OutputCasing.Verbatim));
+        }
+    }
+}
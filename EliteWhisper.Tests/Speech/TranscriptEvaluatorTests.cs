@@ -0,0 +1,74 @@
+using EliteWhisper.Services.Speech;
+
+namespace EliteWhisper.Tests.Speech
+{
+    public class TranscriptEvaluatorTests
+    {
+        [Fact]
+        public void IdenticalText_HasNoErrors()
+        {
+            var result = TranscriptEvaluator.Evaluate("the quick brown fox", "the quick brown fox");
+
+            Assert.Equal(0, result.Wer);
+            Assert.Equal(0, result.Cer);
+            Assert.Equal(4, result.ReferenceWords);
+        }
+
+        [Fact]
+        public void CaseAndPunctuation_AreIgnored()
+        {
+            var result = TranscriptEvaluator.Evaluate("The quick, brown fox!", "the quick brown fox");
+
+            Assert.Equal(0, result.Wer);
+        }
+
+        [Fact]
+        public void Substitution_IsCounted()
+        {
+            var result = TranscriptEvaluator.Evaluate("the quick brown box", "the quick brown fox");
+
+            Assert.Equal(1, result.Substitutions);
+            Assert.Equal(0, result.Insertions);
+            Assert.Equal(0, result.Deletions);
+            Assert.Equal(0.25, result.Wer, 3);
+        }
+
+        [Fact]
+        public void InsertionAndDeletion_AreCounted()
+        {
+            // "very" inserted, "fox" deleted
+            var result = TranscriptEvaluator.Evaluate("the very quick brown", "the quick brown fox");
+
+            Assert.Equal(0, result.Substitutions);
+            Assert.Equal(1, result.Insertions);
+            Assert.Equal(1, result.Deletions);
+            Assert.Equal(0.5, result.Wer, 3);
+        }
+
+        [Fact]
+        public void Cer_CountsCharacterEdits()
+        {
+            // "kitten" -> "sitting": 2 substitutions and 1 insertion over 6 reference characters
+            var result = TranscriptEvaluator.Evaluate("sitting", "kitten");
+
+            Assert.Equal(0.5, result.Cer, 3);
+        }
+
+        [Fact]
+        public void EmptyHypothesis_DeletesEveryWord()
+        {
+            var result = TranscriptEvaluator.Evaluate("", "hello world");
+
+            Assert.Equal(2, result.Deletions);
+            Assert.Equal(1.0, result.Wer);
+        }
+
+        [Theory]
+        [InlineData("", 0.0)]
+        [InlineData("something", 1.0)]
+        public void EmptyReference_IsAllOrNothing(string hypothesis, double expectedWer)
+        {
+            Assert.Equal(expectedWer, TranscriptEvaluator.Evaluate(hypothesis, "").Wer);
+        }
+    }
+}
@@ -0,0 +1,68 @@
+using EliteWhisper.Services.Speech;
+using NAudio.Wave;
+
+namespace EliteWhisper.Tests.Speech
+{
+    public class WavSampleReaderTests : IDisposable
+    {
+        private readonly string _path = Path.Combine(Path.GetTempPath(), $"elitewhisper_tests_{Guid.NewGuid():N}.wav");
+
+        public void Dispose()
+        {
+            try { File.Delete(_path); } catch { }
+        }
+
+        [Fact]
+        public void EightBit_IsUnsignedAroundMidpoint()
+        {
+            WriteWav(new WaveFormat(16000, 8, 1), new byte[] { 128, 255, 0, 192 });
+
+            var (samples, format) = WavSampleReader.Read(_path, CancellationToken.None);
+
+            Assert.Equal(16000, format.SampleRate);
+            Assert.Equal(new[] { 0f, 127 / 128f, -1f, 0.5f }, samples);
+        }
+
+        [Fact]
+        public void TwentyFourBit_IsSignExtended()
+        {
+            WriteWav(new WaveFormat(44100, 24, 1), new byte[]
+            {
+                0xFF, 0xFF, 0x7F, // max
+                0x00, 0x00, 0x80, // min
+                0x00, 0x00, 0x40, // half scale
+                0x00, 0x00, 0xC0  // negative half scale
+            });
+
+            var (samples, format) = WavSampleReader.Read(_path, CancellationToken.None);
+
+            Assert.Equal(44100, format.SampleRate);
+            Assert.Equal(4, samples.Length);
+            Assert.Equal(1f, samples[0], 5);
+            Assert.Equal(-1f, samples[1]);
+            Assert.Equal(0.5f, samples[2]);
+            Assert.Equal(-0.5f, samples[3]);
+        }
+
+        [Fact]
+        public void TwentyFourBitStereo_KeepsChannelsInterleaved()
+        {
+            WriteWav(new WaveFormat(48000, 24, 2), new byte[]
+            {
+                0x00, 0x00, 0x40, 0x00, 0x00, 0xC0,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x40
+            });
+
+            var (samples, format) = WavSampleReader.Read(_path, CancellationToken.None);
+
+            Assert.Equal(2, format.Channels);
+            Assert.Equal(new[] { 0.5f, -0.5f, 0f, 0.5f }, samples);
+        }
+
+        private void WriteWav(WaveFormat format, byte[] data)
+        {
+            using var writer = new WaveFileWriter(_path, format);
+            writer.Write(data, 0, data.Length);
+        }
+    }
+}
@@ -49,5 +49,27 @@ namespace EliteWhisper.Models
         /// </summary>
         [JsonPropertyName("engineType")]
         public string? EngineType { get; set; }
+
+        /// <summary>
+        /// Individual files of a Sherpa model that isn't published as an archive.
+        /// When set, they are downloaded into a folder named <see cref="Filename"/> instead of <see cref="DownloadUrl"/>.
+        /// </summary>
+        [JsonPropertyName("files")]
+        public List<AIModelRegistryFile>? Files { get; set; }
+    }
+
+    public class AIModelRegistryFile
+    {
+        [JsonPropertyName("url")]
+        public string Url { get; set; } = string.Empty;
+
+        /// <summary>
+        /// Name inside the model folder, e.g. "tokens.txt". Chosen to match what Sherpa model detection expects.
+        /// </summary>
+        [JsonPropertyName("filename")]
+        public string Filename { get; set; } = string.Empty;
+
+        [JsonPropertyName("sha256")]
+        public string? Sha256 { get; set; }
     }
 }
@@ -0,0 +1,48 @@
+using System;
+using System.Collections.Generic;
+
+namespace EliteWhisper.Models
+{
+    /// <summary>
+    /// Progress and outcome of transcribing a folder of audio files.
+    /// </summary>
+    public class BatchTranscriptionStatus
+    {
+        public Guid Id { get; set; } = Guid.NewGuid();
+
+        public string Directory { get; set; } = string.Empty;
+
+        public int Total { get; set; }
+
+        /// <summary>
+        /// Files transcribed and saved to history.
+        /// </summary>
+        public int Succeeded { get; set; }
+
+        /// <summary>
+        /// Files that failed to decode or transcribe, or had no speech, with the reason.
+        /// </summary>
+        public List<BatchFileFailure> Failures { get; set; } = new();
+
+        /// <summary>
+        /// File being transcribed and its completion from 0 to 100.
+        /// </summary>
+        public string? CurrentFile { get; set; }
+        public int CurrentFilePercent { get; set; }
+
+        public int Processed => Succeeded + Failures.Count;
+
+        public bool IsFinished => Processed >= Total;
+
+        /// <summary>
+        /// Overall completion from 0 to 100, counting the running file's progress.
+        /// </summary>
+        public int Percent => Total == 0 ? 100 : (int)((Processed + (IsFinished ? 0 : CurrentFilePercent / 100.0)) * 100 / Total);
+    }
+
+    public class BatchFileFailure
+    {
+        public string FilePath { get; set; } = string.Empty;
+        public string Reason { get; set; } = string.Empty;
+    }
+}
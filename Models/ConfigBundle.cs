@@ -0,0 +1,65 @@
+using System;
+using System.Collections.Generic;
+
+namespace EliteWhisper.Models
+{
+    /// <summary>
+    /// Portable backup of the user's setup: settings, dictation modes, hotwords and text rules.
+    /// Machine-specific paths are stripped (model paths are kept as file names) and API keys
+    /// are never included because they are encrypted for the current Windows account.
+    /// </summary>
+    public class ConfigBundle
+    {
+        /// <summary>
+        /// Bumped whenever the bundle layout changes incompatibly.
+        /// </summary>
+        public int FormatVersion { get; set; }
+
+        public string? AppVersion { get; set; }
+        public DateTime ExportedAt { get; set; }
+
+        public WhisperConfiguration Settings { get; set; } = new();
+
+        /// <summary>
+        /// Hotwords in file format ("phrase" or "phrase :score").
+        /// </summary>
+        public List<string> Hotwords { get; set; } = new();
+
+        /// <summary>
+        /// Find-and-replace rules (replacements.json). Null in format 1 bundles, which leaves the local rules alone.
+        /// </summary>
+        public List<ReplacementRule>? Replacements { get; set; }
+
+        /// <summary>
+        /// User additions to the profanity lists (profanity.txt), as written in the file. Null in format 1 bundles.
+        /// </summary>
+        public List<string>? ProfanityWords { get; set; }
+
+        /// <summary>
+        /// Hallucination filters (hallucination_filters.json). Null in format 1 bundles.
+        /// </summary>
+        public List<HallucinationFilter>? HallucinationFilters { get; set; }
+
+        /// <summary>
+        /// File or folder name of the active model, resolved against the local models folder on import.
+        /// </summary>
+        public string? DefaultModelFileName { get; set; }
+
+        /// <summary>
+        /// File name of the Whisper fallback model, resolved the same way.
+        /// </summary>
+        public string? FallbackModelFileName { get; set; }
+
+        /// <summary>
+        /// Folder name of the streaming model, resolved the same way. Null when streaming had no model.
+        /// </summary>
+        public string? StreamingModelFolderName { get; set; }
+
+        /// <summary>
+        /// File names of the speaker diarization models, resolved the same way.
+        /// </summary>
+        public string? DiarizationSegmentationModelFileName { get; set; }
+
+        public string? DiarizationEmbeddingModelFileName { get; set; }
+    }
+}
@@ -0,0 +1,19 @@
+namespace EliteWhisper.Models
+{
+    /// <summary>
+    /// How the speech models search for the most likely transcript.
+    /// </summary>
+    public enum DecodingStrategy
+    {
+        /// <summary>
+        /// Take the most likely token at each step. Fastest (default).
+        /// </summary>
+        Greedy,
+
+        /// <summary>
+        /// Keep several candidate transcripts alive and pick the best one at the end.
+        /// More accurate on hard audio, but decoding takes roughly beam-size times longer.
+        /// </summary>
+        BeamSearch
+    }
+}
@@ -1,4 +1,5 @@
 using System;
+using System.Collections.Generic;
 
 namespace EliteWhisper.Models
 {
@@ -7,10 +8,70 @@ namespace EliteWhisper.Models
         public Guid Id { get; set; } = Guid.NewGuid();
         public DateTime Timestamp { get; set; } = DateTime.Now;
         public string Content { get; set; } = string.Empty;
+
+        /// <summary>
+        /// Optional title given by the user when editing the item.
+        /// </summary>
+        public string? Title { get; set; }
+
+        /// <summary>
+        /// User tags for organizing history. Older history files without tags load as an empty list.
+        /// </summary>
+        public List<string> Tags { get; set; } = new();
         public TimeSpan Duration { get; set; } = TimeSpan.Zero;
         public int WordCount { get; set; }
         public int DurationSeconds { get; set; }
         public string ModelUsed { get; set; } = string.Empty;
         public string? ApplicationName { get; set; }
+
+        /// <summary>
+        /// Spoken language detected by Whisper (ISO 639-1), if detection ran.
+        /// </summary>
+        public string? DetectedLanguage { get; set; }
+
+        /// <summary>
+        /// Whisper's probability for the detected language (0..1).
+        /// </summary>
+        public float? LanguageProbability { get; set; }
+
+        /// <summary>
+        /// Plain-text copy of the transcript on disk, if transcript files are enabled.
+        /// </summary>
+        public string? TranscriptFilePath { get; set; }
+
+        /// <summary>
+        /// Estimated transcript quality (0..1) at the time of dictation, if known.
+        /// </summary>
+        public double? QualityScore { get; set; }
+
+        /// <summary>
+        /// Probability (0..1) that the recording held no speech, if the engine reported it.
+        /// </summary>
+        public float? NoSpeechProbability { get; set; }
+
+        /// <summary>
+        /// True when NoSpeechProbability was above the warning threshold at the time of dictation.
+        /// </summary>
+        public bool LikelyNoSpeech { get; set; }
+
+        /// <summary>
+        /// Saved recording for this dictation, if recordings are kept.
+        /// </summary>
+        public string? AudioFilePath { get; set; }
+
+        /// <summary>
+        /// False once the recording was removed by the retention sweep; the transcript is kept.
+        /// </summary>
+        public bool AudioAvailable { get; set; }
+
+        /// <summary>
+        /// Versioned fingerprint of the source audio, used to find duplicate recordings.
+        /// </summary>
+        public string? AudioFingerprint { get; set; }
+
+        /// <summary>
+        /// Per-word timings, ordered by start time. Null when the engine didn't report timestamps.
+        /// </summary>
+        public List<WordTiming>? Words { get; set; }
     }
 }
@@ -0,0 +1,56 @@
+using System;
+using System.Collections.Generic;
+
+namespace EliteWhisper.Models
+{
+    /// <summary>
+    /// Dashboard metrics computed over a time window.
+    /// </summary>
+    public class DictationStats
+    {
+        public DateTime From { get; set; }
+        public DateTime To { get; set; }
+
+        public int TranscriptionCount { get; set; }
+        public int TotalWords { get; set; }
+        public int TotalDurationSeconds { get; set; }
+
+        /// <summary>
+        /// Words per minute over records that have a duration. 0 when there isn't enough audio.
+        /// </summary>
+        public int AverageWpm { get; set; }
+
+        public int AppsUsed { get; set; }
+
+        /// <summary>
+        /// Per-application totals, most words first. Records without a known app are grouped as "Unknown".
+        /// </summary>
+        public List<AppStats> Apps { get; set; } = new();
+
+        /// <summary>
+        /// Estimated minutes saved versus typing at 40 WPM.
+        /// </summary>
+        public int MinutesSaved { get; set; }
+
+        /// <summary>
+        /// One bucket per calendar day in the window, oldest first, including empty days.
+        /// </summary>
+        public List<DailyStats> Days { get; set; } = new();
+    }
+
+    public class AppStats
+    {
+        public string ApplicationName { get; set; } = string.Empty;
+        public int TranscriptionCount { get; set; }
+        public int Words { get; set; }
+        public int DurationSeconds { get; set; }
+    }
+
+    public class DailyStats
+    {
+        public DateTime Date { get; set; }
+        public int TranscriptionCount { get; set; }
+        public int Words { get; set; }
+        public int DurationSeconds { get; set; }
+    }
+}
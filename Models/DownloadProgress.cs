@@ -0,0 +1,52 @@
+using System;
+
+namespace EliteWhisper.Models
+{
+    /// <summary>
+    /// How far a running model download has got, with its current speed.
+    /// </summary>
+    public class DownloadProgress
+    {
+        /// <summary>
+        /// Completion from 0 to 100, or 0 while the total size is unknown.
+        /// </summary>
+        public double Percent { get; set; }
+
+        public long BytesDownloaded { get; set; }
+
+        /// <summary>
+        /// Total size in bytes, or -1 if the server didn't say.
+        /// </summary>
+        public long TotalBytes { get; set; }
+
+        /// <summary>
+        /// Moving-average throughput over the last few seconds. 0 until there is enough data.
+        /// </summary>
+        public double BytesPerSecond { get; set; }
+
+        /// <summary>
+        /// Estimated seconds left, or null when the speed or total size is not known yet.
+        /// </summary>
+        public double? EtaSeconds { get; set; }
+
+        /// <summary>
+        /// Speed and time left for display, e.g. "2.3 MB/s, 40s left". Empty until the speed is known.
+        /// </summary>
+        public string Describe()
+        {
+            if (BytesPerSecond <= 0) return string.Empty;
+
+            string speed = BytesPerSecond >= 1024 * 1024
+                ? $"{BytesPerSecond / (1024 * 1024):F1} MB/s"
+                : $"{BytesPerSecond / 1024:F0} KB/s";
+
+            if (EtaSeconds == null) return speed;
+
+            var eta = TimeSpan.FromSeconds(Math.Ceiling(EtaSeconds.Value));
+            string left = eta.TotalHours >= 1 ? $"{(int)eta.TotalHours}h {eta.Minutes}m"
+                : eta.TotalMinutes >= 1 ? $"{eta.Minutes}m {eta.Seconds}s"
+                : $"{eta.Seconds}s";
+            return $"{speed}, {left} left";
+        }
+    }
+}
@@ -0,0 +1,53 @@
+namespace EliteWhisper.Models
+{
+    /// <summary>
+    /// The speech engine the next dictation will use and which optional features it honours,
+    /// so settings it ignores can be shown as unavailable.
+    /// </summary>
+    public class EngineInfo
+    {
+        public string Name { get; set; } = string.Empty;
+
+        /// <summary>
+        /// Model file (Whisper) or folder (Sherpa) name; null when no model is set.
+        /// </summary>
+        public string? ModelName { get; set; }
+
+        public InstalledModelKind Kind { get; set; }
+
+        /// <summary>
+        /// Architecture of the Sherpa model; null for Whisper.
+        /// </summary>
+        public SherpaModelType? ModelType { get; set; }
+
+        /// <summary>
+        /// Segment and word timings (subtitles, speaker labels, word highlighting).
+        /// </summary>
+        public bool SupportsTimestamps { get; set; }
+
+        /// <summary>
+        /// Translating speech to English.
+        /// </summary>
+        public bool SupportsTranslate { get; set; }
+
+        /// <summary>
+        /// Biasing recognition towards the hotwords list.
+        /// </summary>
+        public bool SupportsHotwords { get; set; }
+
+        /// <summary>
+        /// Live transcription while recording. Runs on the separate streaming model.
+        /// </summary>
+        public bool SupportsStreaming { get; set; }
+
+        /// <summary>
+        /// Beam search decoding.
+        /// </summary>
+        public bool SupportsBeamSearch { get; set; }
+
+        /// <summary>
+        /// A context prompt of names and jargon.
+        /// </summary>
+        public bool SupportsPrompt { get; set; }
+    }
+}
@@ -0,0 +1,15 @@
+namespace EliteWhisper.Models
+{
+    /// <summary>
+    /// Text a model emits on silence or noise instead of speech (e.g. "[BLANK_AUDIO]"), removed from transcripts.
+    /// </summary>
+    public class HallucinationFilter
+    {
+        public string Pattern { get; set; } = string.Empty;
+
+        /// <summary>
+        /// Treat Pattern as a regular expression. Plain patterns match anywhere, ignoring case.
+        /// </summary>
+        public bool IsRegex { get; set; } = false;
+    }
+}
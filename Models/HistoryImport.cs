@@ -0,0 +1,48 @@
+namespace EliteWhisper.Models
+{
+    /// <summary>
+    /// Source formats accepted when importing history from another dictation tool.
+    /// </summary>
+    public enum ExternalHistoryFormat
+    {
+        /// <summary>Generic CSV file, columns mapped via <see cref="CsvColumnMapping"/>.</summary>
+        Csv,
+
+        /// <summary>Folder of .txt files, one transcript per file.</summary>
+        TextFolder
+    }
+
+    /// <summary>
+    /// Names of the CSV header columns to read. Only the content column is required.
+    /// </summary>
+    public class CsvColumnMapping
+    {
+        public string ContentColumn { get; set; } = "text";
+        public string? TimestampColumn { get; set; } = "date";
+        public string? DurationSecondsColumn { get; set; }
+        public string? ApplicationColumn { get; set; }
+    }
+
+    /// <summary>
+    /// How a JSON history backup is combined with the current history.
+    /// </summary>
+    public enum HistoryImportMode
+    {
+        /// <summary>Add items whose id isn't already present.</summary>
+        Merge,
+
+        /// <summary>Discard the current history and use the backup's items.</summary>
+        Replace
+    }
+
+    public class HistoryImportResult
+    {
+        public int Imported { get; set; }
+        public int Skipped { get; set; }
+
+        /// <summary>
+        /// Entries in the file that couldn't be read as a history item.
+        /// </summary>
+        public int Rejected { get; set; }
+    }
+}
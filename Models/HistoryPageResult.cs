@@ -0,0 +1,13 @@
+using System.Collections.Generic;
+
+namespace EliteWhisper.Models
+{
+    /// <summary>
+    /// One page of history, newest first, with the total number of records for paging controls.
+    /// </summary>
+    public class HistoryPageResult
+    {
+        public List<DictationRecord> Items { get; set; } = new();
+        public int TotalCount { get; set; }
+    }
+}
@@ -0,0 +1,23 @@
+namespace EliteWhisper.Models
+{
+    /// <summary>
+    /// Which history items are kept when old ones are pruned.
+    /// </summary>
+    public enum HistoryRetentionMode
+    {
+        /// <summary>
+        /// Keep everything (default).
+        /// </summary>
+        Unlimited,
+
+        /// <summary>
+        /// Keep the most recent HistoryRetentionLimit items.
+        /// </summary>
+        KeepCount,
+
+        /// <summary>
+        /// Keep items from the last HistoryRetentionLimit days.
+        /// </summary>
+        KeepDays
+    }
+}
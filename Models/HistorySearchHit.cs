@@ -0,0 +1,21 @@
+namespace EliteWhisper.Models
+{
+    /// <summary>
+    /// A history record matching a search, with the matched text in context for highlighting.
+    /// </summary>
+    public class HistorySearchHit
+    {
+        public DictationRecord Record { get; set; } = new();
+
+        /// <summary>
+        /// Excerpt around the first match, or the start of the transcript when the match was elsewhere.
+        /// </summary>
+        public string Snippet { get; set; } = string.Empty;
+
+        /// <summary>
+        /// Position and length of the match within Snippet. MatchStart is -1 when the snippet has no match.
+        /// </summary>
+        public int MatchStart { get; set; } = -1;
+        public int MatchLength { get; set; }
+    }
+}
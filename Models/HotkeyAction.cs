@@ -0,0 +1,48 @@
+namespace EliteWhisper.Models
+{
+    /// <summary>
+    /// Something a global shortcut can trigger. Each action has its own shortcut.
+    /// </summary>
+    public enum HotkeyAction
+    {
+        /// <summary>
+        /// Start or stop dictation (the main shortcut, also used for push-to-talk).
+        /// </summary>
+        ToggleRecording,
+
+        /// <summary>
+        /// Transcribe an audio file copied in Explorer, or audio on the clipboard.
+        /// </summary>
+        TranscribeClipboardAudio,
+
+        /// <summary>
+        /// Abort the recording or transcription in progress.
+        /// </summary>
+        Cancel,
+
+        /// <summary>
+        /// Insert the last transcript again into the focused app.
+        /// </summary>
+        RetypeLast,
+
+        /// <summary>
+        /// Copy the last transcript to the clipboard again.
+        /// </summary>
+        RecopyLast
+    }
+
+    /// <summary>
+    /// Shortcut assigned to an action.
+    /// </summary>
+    public class HotkeyBinding
+    {
+        public HotkeyAction Action { get; set; }
+
+        public uint VirtualKey { get; set; }
+
+        /// <summary>
+        /// Win32 modifier flags (MOD_ALT = 1, MOD_CONTROL = 2, MOD_SHIFT = 4).
+        /// </summary>
+        public uint Modifiers { get; set; }
+    }
+}
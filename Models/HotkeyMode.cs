@@ -0,0 +1,18 @@
+namespace EliteWhisper.Models
+{
+    /// <summary>
+    /// How the global shortcut controls recording.
+    /// </summary>
+    public enum HotkeyMode
+    {
+        /// <summary>
+        /// Press once to start, press again to stop (default).
+        /// </summary>
+        Toggle,
+
+        /// <summary>
+        /// Record while the shortcut is held down, stop on release.
+        /// </summary>
+        PushToTalk
+    }
+}
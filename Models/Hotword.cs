@@ -0,0 +1,37 @@
+using System.Globalization;
+using System.Text.RegularExpressions;
+
+namespace EliteWhisper.Models
+{
+    /// <summary>
+    /// A phrase to boost during Sherpa decoding, with an optional score of its own.
+    /// Stored one per line as "phrase" or "phrase :2.5", the format sherpa-onnx reads.
+    /// </summary>
+    public class Hotword
+    {
+        private static readonly Regex ScoredLine = new(@"^(.*\S)\s+:(\d+(?:\.\d+)?)$");
+
+        public string Word { get; set; } = string.Empty;
+
+        /// <summary>
+        /// Boost for this phrase. Null = the engine's default hotwords score.
+        /// </summary>
+        public float? Score { get; set; }
+
+        public static Hotword Parse(string line)
+        {
+            line = line.Trim();
+            var match = ScoredLine.Match(line);
+            if (match.Success && float.TryParse(match.Groups[2].Value, NumberStyles.Float, CultureInfo.InvariantCulture, out float score))
+            {
+                return new Hotword { Word = match.Groups[1].Value, Score = score };
+            }
+
+            return new Hotword { Word = line };
+        }
+
+        public string ToLine() => Score.HasValue
+            ? $"{Word.Trim()} :{Score.Value.ToString("0.0##", CultureInfo.InvariantCulture)}"
+            : Word.Trim();
+    }
+}
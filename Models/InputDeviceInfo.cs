@@ -0,0 +1,20 @@
+namespace EliteWhisper.Models
+{
+    /// <summary>
+    /// A microphone (audio input device) that can be selected for dictation.
+    /// </summary>
+    public class InputDeviceInfo
+    {
+        /// <summary>
+        /// Device name as reported by the driver. Windows truncates it to 31 characters.
+        /// </summary>
+        public string Name { get; set; } = string.Empty;
+
+        /// <summary>
+        /// True for the Windows default recording device.
+        /// </summary>
+        public bool IsDefault { get; set; }
+
+        public override string ToString() => IsDefault ? $"{Name} (Default)" : Name;
+    }
+}
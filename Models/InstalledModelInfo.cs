@@ -0,0 +1,32 @@
+namespace EliteWhisper.Models
+{
+    public enum InstalledModelKind
+    {
+        Whisper,
+        Sherpa
+    }
+
+    /// <summary>
+    /// A speech model found in the models folder, whether or not it came from the registry.
+    /// </summary>
+    public class InstalledModelInfo
+    {
+        public string Name { get; set; } = string.Empty;
+        public string Path { get; set; } = string.Empty;
+        public long SizeBytes { get; set; }
+        public InstalledModelKind Kind { get; set; }
+
+        /// <summary>
+        /// Detected architecture for Sherpa models; null for Whisper.
+        /// </summary>
+        public SherpaModelType? SherpaType { get; set; }
+
+        public bool IsActive { get; set; }
+
+        public string SizeDisplay => SizeBytes >= 1024L * 1024 * 1024
+            ? $"{SizeBytes / (1024.0 * 1024 * 1024):0.0} GB"
+            : $"{SizeBytes / (1024.0 * 1024):0} MB";
+
+        public string KindDisplay => Kind == InstalledModelKind.Whisper ? "Whisper" : $"Sherpa ({SherpaType})";
+    }
+}
@@ -0,0 +1,18 @@
+namespace EliteWhisper.Models
+{
+    /// <summary>
+    /// Most likely spoken language of a recording, as detected by Whisper.
+    /// </summary>
+    public class LanguageDetection
+    {
+        /// <summary>
+        /// ISO 639-1 code, e.g. "en".
+        /// </summary>
+        public string Language { get; set; } = string.Empty;
+
+        /// <summary>
+        /// Whisper's probability for that language (0..1).
+        /// </summary>
+        public float Probability { get; set; }
+    }
+}
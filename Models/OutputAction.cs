@@ -0,0 +1,33 @@
+namespace EliteWhisper.Models
+{
+    /// <summary>
+    /// What happens with the final transcript after a dictation.
+    /// </summary>
+    public enum OutputAction
+    {
+        /// <summary>
+        /// Type short text, paste long or special-character text (default).
+        /// </summary>
+        Auto,
+
+        /// <summary>
+        /// Always simulate keystrokes.
+        /// </summary>
+        Type,
+
+        /// <summary>
+        /// Always paste via the clipboard and Ctrl+V.
+        /// </summary>
+        Paste,
+
+        /// <summary>
+        /// Only copy to the clipboard, don't insert anything.
+        /// </summary>
+        Clipboard,
+
+        /// <summary>
+        /// Only save to history.
+        /// </summary>
+        None
+    }
+}
@@ -0,0 +1,33 @@
+namespace EliteWhisper.Models
+{
+    /// <summary>
+    /// Letter case applied to the final transcript.
+    /// </summary>
+    public enum OutputCasing
+    {
+        /// <summary>
+        /// Keep the case the model and formatting produced (default).
+        /// </summary>
+        Verbatim,
+
+        /// <summary>
+        /// Lowercase, with sentence starts, "I" and acronyms capitalized.
+        /// </summary>
+        Sentence,
+
+        /// <summary>
+        /// all lowercase.
+        /// </summary>
+        Lower,
+
+        /// <summary>
+        /// ALL UPPERCASE.
+        /// </summary>
+        Upper,
+
+        /// <summary>
+        /// Every Word Capitalized, except short articles, conjunctions and prepositions.
+        /// </summary>
+        Title
+    }
+}
@@ -0,0 +1,15 @@
+namespace EliteWhisper.Models
+{
+    /// <summary>
+    /// A segment reported while a transcription is still running.
+    /// </summary>
+    public class PartialTranscript
+    {
+        /// <summary>
+        /// Position of the segment in the transcript, starting at 0.
+        /// </summary>
+        public int Index { get; set; }
+
+        public string Text { get; set; } = string.Empty;
+    }
+}
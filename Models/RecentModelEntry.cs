@@ -0,0 +1,20 @@
+using System;
+
+namespace EliteWhisper.Models
+{
+    /// <summary>
+    /// A speech model the user activated recently, for quick switching.
+    /// </summary>
+    public class RecentModelEntry
+    {
+        public string Name { get; set; } = string.Empty;
+        public string Path { get; set; } = string.Empty;
+
+        /// <summary>
+        /// "Whisper" or "Sherpa".
+        /// </summary>
+        public string Engine { get; set; } = string.Empty;
+
+        public DateTime LastUsed { get; set; }
+    }
+}
@@ -0,0 +1,23 @@
+namespace EliteWhisper.Models
+{
+    /// <summary>
+    /// A find-and-replace applied to every final transcript, in list order.
+    /// </summary>
+    public class ReplacementRule
+    {
+        public string From { get; set; } = string.Empty;
+        public string To { get; set; } = string.Empty;
+
+        public bool CaseSensitive { get; set; } = false;
+
+        /// <summary>
+        /// Only match From as a whole word ("gmail" but not "gmails"). Ignored for regex rules.
+        /// </summary>
+        public bool WholeWord { get; set; } = true;
+
+        /// <summary>
+        /// Treat From as a regular expression; To may use $1-style group references.
+        /// </summary>
+        public bool IsRegex { get; set; } = false;
+    }
+}
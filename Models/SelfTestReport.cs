@@ -0,0 +1,49 @@
+using System.Collections.Generic;
+using System.Linq;
+using System.Text;
+
+namespace EliteWhisper.Models
+{
+    /// <summary>
+    /// Result of running a known clip through the whole speech pipeline.
+    /// </summary>
+    public class SelfTestReport
+    {
+        public List<SelfTestStage> Stages { get; set; } = new();
+        public string EngineName { get; set; } = string.Empty;
+        public string Transcript { get; set; } = string.Empty;
+
+        public bool Passed => Stages.Count > 0 && Stages.All(s => s.Passed);
+
+        public override string ToString()
+        {
+            var sb = new StringBuilder();
+            sb.AppendLine(Passed ? "Self-test PASSED" : "Self-test FAILED");
+            if (!string.IsNullOrEmpty(EngineName)) sb.AppendLine($"Engine: {EngineName}");
+            sb.AppendLine();
+
+            foreach (var stage in Stages)
+            {
+                sb.Append($"{(stage.Passed ? "OK  " : "FAIL")}  {stage.Name} ({stage.DurationMs}ms)");
+                if (!string.IsNullOrEmpty(stage.Message)) sb.Append($" - {stage.Message}");
+                sb.AppendLine();
+            }
+
+            if (!string.IsNullOrEmpty(Transcript))
+            {
+                sb.AppendLine();
+                sb.AppendLine($"Transcript: \"{Transcript}\"");
+            }
+
+            return sb.ToString().TrimEnd();
+        }
+    }
+
+    public class SelfTestStage
+    {
+        public string Name { get; set; } = string.Empty;
+        public bool Passed { get; set; }
+        public long DurationMs { get; set; }
+        public string? Message { get; set; }
+    }
+}
@@ -0,0 +1,38 @@
+namespace EliteWhisper.Models
+{
+    /// <summary>
+    /// Architecture of a Sherpa-ONNX model directory.
+    /// </summary>
+    public enum SherpaModelType
+    {
+        /// <summary>
+        /// Detect from the files in the model directory.
+        /// </summary>
+        Auto,
+
+        /// <summary>
+        /// encoder*.onnx + decoder*.onnx + joiner*.onnx + tokens.txt (Parakeet TDT, Zipformer).
+        /// </summary>
+        Transducer,
+
+        /// <summary>
+        /// Single model*.onnx + tokens.txt (NeMo CTC, also the fallback for unlabeled single-file models).
+        /// </summary>
+        NemoCtc,
+
+        /// <summary>
+        /// encoder*.onnx + decoder*.onnx + *tokens.txt without a joiner (Whisper exported for Sherpa).
+        /// </summary>
+        Whisper,
+
+        /// <summary>
+        /// Single model*.onnx + tokens.txt exported from FunASR Paraformer.
+        /// </summary>
+        Paraformer,
+
+        /// <summary>
+        /// Single model*.onnx + tokens.txt exported from FunASR SenseVoice.
+        /// </summary>
+        SenseVoice
+    }
+}
@@ -0,0 +1,34 @@
+using System.Collections.Generic;
+
+namespace EliteWhisper.Models
+{
+    /// <summary>
+    /// A timed piece of a transcript, in milliseconds from the start of the recording.
+    /// </summary>
+    public class TranscriptSegment
+    {
+        public long StartMs { get; set; }
+        public long EndMs { get; set; }
+        public string Text { get; set; } = string.Empty;
+
+        /// <summary>
+        /// Average token probability for the segment, 0..1. Null when not reported.
+        /// </summary>
+        public float? Confidence { get; set; }
+
+        /// <summary>
+        /// Whisper's probability that the segment holds no speech, 0..1. Null when not reported.
+        /// </summary>
+        public float? NoSpeechProbability { get; set; }
+
+        /// <summary>
+        /// Per-word timings within the segment. Null when the engine didn't report token timestamps.
+        /// </summary>
+        public List<WordTiming>? Words { get; set; }
+
+        /// <summary>
+        /// Speaker number (1-based) from diarization. Null when diarization didn't run.
+        /// </summary>
+        public int? Speaker { get; set; }
+    }
+}
@@ -0,0 +1,39 @@
+namespace EliteWhisper.Models
+{
+    /// <summary>
+    /// Why a transcription failed. Stable across releases, so callers can switch on it
+    /// instead of matching message text.
+    /// </summary>
+    public enum TranscriptionError
+    {
+        /// <summary>
+        /// whisper-cli hasn't been set up in settings.
+        /// </summary>
+        NotConfigured,
+
+        /// <summary>
+        /// No usable speech model is installed or the selected one is missing.
+        /// </summary>
+        NoModel,
+
+        /// <summary>
+        /// The recording or imported file doesn't exist.
+        /// </summary>
+        AudioNotFound,
+
+        /// <summary>
+        /// The audio exists but couldn't be decoded.
+        /// </summary>
+        DecodeFailed,
+
+        /// <summary>
+        /// The engine ran but failed or produced no usable output.
+        /// </summary>
+        EngineFailed,
+
+        /// <summary>
+        /// The engine took longer than the allowed time.
+        /// </summary>
+        Timeout
+    }
+}
@@ -0,0 +1,45 @@
+using System;
+
+namespace EliteWhisper.Models
+{
+    public enum TranscriptionJobStatus
+    {
+        Pending,
+        Running,
+        Completed,
+        Failed,
+        Cancelled
+    }
+
+    /// <summary>
+    /// An audio file waiting in, or processed by, the transcription queue.
+    /// </summary>
+    public class TranscriptionJob
+    {
+        public Guid Id { get; set; } = Guid.NewGuid();
+
+        public string AudioFilePath { get; set; } = string.Empty;
+
+        public TranscriptionJobStatus Status { get; set; } = TranscriptionJobStatus.Pending;
+
+        /// <summary>
+        /// Completion of the running job from 0 to 100.
+        /// </summary>
+        public int Percent { get; set; }
+
+        public DateTime EnqueuedAt { get; set; } = DateTime.Now;
+        public DateTime? StartedAt { get; set; }
+        public DateTime? FinishedAt { get; set; }
+
+        /// <summary>
+        /// Set when the job completed.
+        /// </summary>
+        public TranscriptionResult? Result { get; set; }
+
+        /// <summary>
+        /// Set when the job failed. ErrorCode is null for unexpected errors.
+        /// </summary>
+        public string? Error { get; set; }
+        public TranscriptionError? ErrorCode { get; set; }
+    }
+}
@@ -0,0 +1,20 @@
+using System;
+
+namespace EliteWhisper.Models
+{
+    /// <summary>
+    /// How far a running transcription has got.
+    /// </summary>
+    public class TranscriptionProgress
+    {
+        /// <summary>
+        /// Identifies the transcription, so concurrent jobs can tell their updates apart.
+        /// </summary>
+        public Guid JobId { get; set; }
+
+        /// <summary>
+        /// Completion from 0 to 100.
+        /// </summary>
+        public int Percent { get; set; }
+    }
+}
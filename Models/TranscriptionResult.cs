@@ -0,0 +1,91 @@
+using System.Collections.Generic;
+
+namespace EliteWhisper.Models
+{
+    /// <summary>
+    /// Transcript produced by the speech pipeline, tagged with the engine that produced it.
+    /// </summary>
+    public class TranscriptionResult
+    {
+        public string Text { get; set; } = string.Empty;
+
+        /// <summary>
+        /// Engine that actually produced the text (differs from the selected engine after a fallback).
+        /// </summary>
+        public string EngineName { get; set; } = string.Empty;
+
+        /// <summary>
+        /// Length of the input audio in seconds.
+        /// </summary>
+        public double AudioSeconds { get; set; }
+
+        public TranscriptionTimings Timings { get; set; } = new();
+
+        /// <summary>
+        /// Audio fingerprint of the input clip (see AudioFingerprint), null if too short.
+        /// </summary>
+        public string? AudioFingerprint { get; set; }
+
+        /// <summary>
+        /// Estimated transcript quality, 0 (garbage) to 1 (looks fine).
+        /// </summary>
+        public double QualityScore { get; set; }
+
+        /// <summary>
+        /// True when QualityScore is below the configured minimum.
+        /// </summary>
+        public bool IsLowQuality { get; set; }
+
+        /// <summary>
+        /// True when the audio was below the minimum length and the engine wasn't run.
+        /// </summary>
+        public bool IsTooShort { get; set; }
+
+        /// <summary>
+        /// Whisper's duration-weighted no-speech probability across segments, 0..1.
+        /// Null when the engine doesn't report it.
+        /// </summary>
+        public float? NoSpeechProbability { get; set; }
+
+        /// <summary>
+        /// Timed segments, when the transcription was run with timestamps. Null otherwise.
+        /// </summary>
+        public List<TranscriptSegment>? Segments { get; set; }
+
+        /// <summary>
+        /// Language Whisper detected for this run when set to "auto". Null for other engines.
+        /// </summary>
+        public LanguageDetection? DetectedLanguage { get; set; }
+    }
+
+    /// <summary>
+    /// Per-stage latency breakdown of a single transcription, in milliseconds.
+    /// </summary>
+    public class TranscriptionTimings
+    {
+        /// <summary>
+        /// Reading and decoding the audio file into raw samples.
+        /// </summary>
+        public long DecodeMs { get; set; }
+
+        /// <summary>
+        /// Resampling, channel reduction and level analysis.
+        /// </summary>
+        public long PreprocessMs { get; set; }
+
+        /// <summary>
+        /// Speech engine time, including any fallback retry.
+        /// </summary>
+        public long InferenceMs { get; set; }
+
+        /// <summary>
+        /// Text clean-up applied to the raw engine output.
+        /// </summary>
+        public long PostprocessMs { get; set; }
+
+        public long TotalMs { get; set; }
+
+        public override string ToString() =>
+            $"decode={DecodeMs}ms, preprocess={PreprocessMs}ms, inference={InferenceMs}ms, postprocess={PostprocessMs}ms, total={TotalMs}ms";
+    }
+}
@@ -4,6 +4,17 @@ namespace EliteWhisper.Models
 {
     public class WhisperConfiguration
     {
+        /// <summary>
+        /// Layout version written by this build. Bump it and add a step to the configuration
+        /// service's migration whenever a setting is renamed or changes meaning.
+        /// </summary>
+        public const int CurrentSchemaVersion = 1;
+
+        /// <summary>
+        /// Layout version of the saved file. Files from before versioning read as 0.
+        /// </summary>
+        public int SchemaVersion { get; set; }
+
         /// <summary>
         /// Path to the Whisper executable (whisper-cli.exe or whisper.exe)
         /// </summary>
@@ -34,6 +45,53 @@ namespace EliteWhisper.Models
         /// </summary>
         public string? HistoryStoragePath { get; set; }
 
+        /// <summary>
+        /// Also write each transcript to a plain .txt file
+        /// </summary>
+        public bool SaveTranscriptFiles { get; set; } = false;
+
+        /// <summary>
+        /// Folder for transcript .txt files. Null = "transcripts" next to the history file.
+        /// </summary>
+        public string? TranscriptsDirectory { get; set; }
+
+        /// <summary>
+        /// Encrypt history.json with a passphrase. The passphrase is never stored; history stays locked
+        /// until it is entered after each start.
+        /// </summary>
+        public bool EncryptHistory { get; set; } = false;
+
+        /// <summary>
+        /// Whether old history items are deleted, with their transcript files and recordings.
+        /// </summary>
+        public HistoryRetentionMode HistoryRetentionMode { get; set; } = HistoryRetentionMode.Unlimited;
+
+        /// <summary>
+        /// Number of items (KeepCount) or days (KeepDays) of history to keep.
+        /// </summary>
+        public int HistoryRetentionLimit { get; set; } = 0;
+
+        /// <summary>
+        /// Keep each dictation's recording in an "audio" folder next to the history file so it can be played back.
+        /// </summary>
+        public bool SaveRecordings { get; set; } = false;
+
+        /// <summary>
+        /// Total size (MB) saved recordings may take; the oldest are deleted beyond it, keeping their transcripts. 0 = no limit.
+        /// </summary>
+        public int RecordingStorageCapMB { get; set; } = 0;
+
+        /// <summary>
+        /// Delete saved recordings older than this many days, keeping their transcripts. 0 = keep forever.
+        /// </summary>
+        public int RecordingRetentionDays { get; set; } = 0;
+
+        /// <summary>
+        /// Keep the recording of a failed transcription in the temp folder for troubleshooting.
+        /// Kept files are deleted after a day like other stale temp files.
+        /// </summary>
+        public bool KeepFailedRecordings { get; set; } = false;
+
         /// <summary>
         /// Last validation timestamp
         /// </summary>
@@ -53,6 +111,72 @@ namespace EliteWhisper.Models
         /// Flag to indicate if the First-Run Wizard has been completed
         /// </summary>
         public bool HasCompletedFirstRun { get; set; } = false;
+
+        /// <summary>
+        /// Set once the user has been offered the default model download, so it is only asked once
+        /// </summary>
+        public bool HasOfferedDefaultModel { get; set; } = false;
+
+        /// <summary>
+        /// Registry id of the model offered on first run when no model is installed. Null/empty disables the offer.
+        /// </summary>
+        public string? DefaultDownloadModelId { get; set; } = "fast";
+        
+        /// <summary>
+        /// Microphone used for dictation, by device name. Null = the Windows default device.
+        /// Falls back to the default when the named device is unplugged.
+        /// </summary>
+        public string? InputDeviceName { get; set; }
+
+        /// <summary>
+        /// Milliseconds of audio from just before the shortcut added to the start of each recording,
+        /// so the first word isn't cut off. 0 = off. Above 0 the microphone stays open while idle;
+        /// the buffered audio is only kept in memory.
+        /// </summary>
+        public int PreRollMs { get; set; } = 0;
+        
+        // Global Shortcut
+
+        /// <summary>
+        /// Virtual-key code of the dictation shortcut. Default F2 (0x71).
+        /// </summary>
+        public uint HotkeyVirtualKey { get; set; } = 0x71;
+
+        /// <summary>
+        /// Win32 modifier flags for the shortcut (MOD_ALT = 1, MOD_CONTROL = 2, MOD_SHIFT = 4).
+        /// </summary>
+        public uint HotkeyModifiers { get; set; } = 0;
+
+        /// <summary>
+        /// Toggle recording on each press, or record only while the shortcut is held.
+        /// </summary>
+        public HotkeyMode HotkeyMode { get; set; } = HotkeyMode.Toggle;
+
+        /// <summary>
+        /// In toggle mode, stop and transcribe once the microphone has been silent for AutoStopSilenceSeconds.
+        /// Silence is judged against VoiceActivityThresholdDb.
+        /// </summary>
+        public bool AutoStopOnSilence { get; set; } = false;
+
+        /// <summary>
+        /// Seconds of continuous silence before a toggle-mode recording stops by itself.
+        /// </summary>
+        public int AutoStopSilenceSeconds { get; set; } = 3;
+
+        /// <summary>
+        /// Shortcuts for actions other than dictation, which uses HotkeyVirtualKey/HotkeyModifiers.
+        /// Actions without an entry have no shortcut.
+        /// </summary>
+        public List<HotkeyBinding> ActionHotkeys { get; set; } = new();
+        
+        // Widget
+
+        /// <summary>
+        /// Last position of the widget overlay (device-independent pixels). Null = centered above the taskbar.
+        /// </summary>
+        public double? WidgetLeft { get; set; }
+
+        public double? WidgetTop { get; set; }
         
         // Dictation Modes
         
@@ -66,6 +190,16 @@ namespace EliteWhisper.Models
         /// </summary>
         public string? ActiveModeId { get; set; }
         
+        /// <summary>
+        /// How the final transcript is delivered (typed, pasted, copied or only saved).
+        /// </summary>
+        public OutputAction OutputAction { get; set; } = OutputAction.Auto;
+
+        /// <summary>
+        /// Put the previous clipboard contents back after pasting a transcript
+        /// </summary>
+        public bool RestoreClipboardAfterPaste { get; set; } = true;
+        
         // LLM API Keys
         
         /// <summary>
@@ -95,11 +229,235 @@ namespace EliteWhisper.Models
         /// </summary>
         public bool AutoSelectSTT { get; set; } = true;
 
+        /// <summary>
+        /// Sherpa model architecture. Auto = detect from file names; set it for layouts the detection gets wrong.
+        /// </summary>
+        public SherpaModelType SherpaModelType { get; set; } = SherpaModelType.Auto;
+
         /// <summary>
         /// Allow GPU acceleration (DirectML/CUDA) for STT if available
         /// </summary>
         public bool EnableGPUAcceleration { get; set; } = true;
 
+        /// <summary>
+        /// Number of Sherpa models kept loaded, including the active one, so switching back to a recent
+        /// model is instant. Each costs its full size in RAM; 1 = only the active model.
+        /// </summary>
+        public int ModelCacheSize { get; set; } = 2;
+
+        /// <summary>
+        /// Run the engine once on a short silent clip after a model loads, so the first dictation
+        /// doesn't pay for lazy initialization. Turn off when switching models often.
+        /// </summary>
+        public bool WarmUpModelOnLoad { get; set; } = true;
+
+        /// <summary>
+        /// Transcribe live while recording with a streaming Sherpa model instead of transcribing the
+        /// finished file. Needs StreamingModelPath; the offline engines are used when it's off.
+        /// </summary>
+        public bool EnableStreaming { get; set; } = false;
+
+        /// <summary>
+        /// Folder of a Sherpa streaming (online) transducer model, e.g. a streaming Zipformer.
+        /// </summary>
+        public string? StreamingModelPath { get; set; }
+
+        /// <summary>
+        /// Label who said what ("Speaker 1: ...") using Sherpa speaker diarization. Needs Whisper for
+        /// timestamps plus the two diarization models below.
+        /// </summary>
+        public bool EnableDiarization { get; set; } = false;
+
+        /// <summary>
+        /// Pyannote speaker segmentation model (.onnx) for diarization.
+        /// </summary>
+        public string? DiarizationSegmentationModel { get; set; }
+
+        /// <summary>
+        /// Speaker embedding model (.onnx) for diarization, e.g. 3D-Speaker or NeMo TitaNet.
+        /// </summary>
+        public string? DiarizationEmbeddingModel { get; set; }
+
+        /// <summary>
+        /// Number of speakers in the recording. 0 = detect automatically.
+        /// </summary>
+        public int DiarizationSpeakerCount { get; set; } = 0;
+
+        /// <summary>
+        /// CPU threads used for transcription by both Whisper and Sherpa. Defaults to the logical core count, capped at 8.
+        /// </summary>
+        public int TranscriptionThreads { get; set; } = Math.Min(Environment.ProcessorCount, 8);
+
+        /// <summary>
+        /// Retry with Whisper once when the primary engine errors or returns nothing
+        /// </summary>
+        public bool EnableWhisperFallback { get; set; } = true;
+
+        /// <summary>
+        /// Whisper model (ggml-*.bin) used for fallback. Null = use the default model.
+        /// </summary>
+        public string? FallbackWhisperModelPath { get; set; }
+
+        /// <summary>
+        /// Spoken language as an ISO 639-1 code (e.g. "en", "es"). "auto" = let the model detect it,
+        /// which is slower and less reliable on short clips.
+        /// </summary>
+        public string TranscriptionLanguage { get; set; } = "auto";
+
+        /// <summary>
+        /// Transcribe in the spoken language or translate to English. Only Whisper models can translate.
+        /// </summary>
+        public WhisperTask WhisperTask { get; set; } = WhisperTask.Transcribe;
+
+        /// <summary>
+        /// Raise quiet recordings to NormalizationTargetDb before transcription. Near-silent clips are left alone.
+        /// </summary>
+        public bool EnableNormalization { get; set; } = false;
+
+        /// <summary>
+        /// Peak level (dBFS) quiet recordings are raised to.
+        /// </summary>
+        public double NormalizationTargetDb { get; set; } = -3.0;
+
+        /// <summary>
+        /// Audio shorter than this (ms) is returned as empty without running the model, since engines
+        /// tend to invent text for near-empty clips. 0 = off.
+        /// </summary>
+        public int MinAudioDurationMs { get; set; } = 300;
+
+        /// <summary>
+        /// Strip silence before transcription; a fully silent recording skips the model entirely
+        /// </summary>
+        public bool EnableVoiceActivityDetection { get; set; } = false;
+
+        /// <summary>
+        /// Level (dBFS) above which a 30ms frame counts as speech. Lower = more sensitive to quiet speech.
+        /// </summary>
+        public double VoiceActivityThresholdDb { get; set; } = -50.0;
+
+        // Whisper Decoding
+
+        /// <summary>
+        /// Greedy or beam search decoding, for Whisper and Sherpa transducer models.
+        /// Beam search is more accurate but slower, roughly in proportion to the beam size.
+        /// </summary>
+        public DecodingStrategy DecodingStrategy { get; set; } = DecodingStrategy.Greedy;
+
+        /// <summary>
+        /// Number of candidates kept per step in beam search (whisper-cli -bs, Sherpa max active paths). 1..16.
+        /// </summary>
+        public int BeamSize { get; set; } = 5;
+
+        /// <summary>
+        /// Number of candidates sampled per segment in greedy mode (whisper-cli -bo).
+        /// Higher values help on hard audio at roughly linear extra decode time. Must be >= 1.
+        /// </summary>
+        public int WhisperBestOf { get; set; } = 5;
+
+        /// <summary>
+        /// Initial sampling temperature (whisper-cli -tp). 0 = deterministic; best-of only
+        /// has an effect once the decoder falls back to a non-zero temperature.
+        /// </summary>
+        public double WhisperTemperature { get; set; } = 0.0;
+
+        /// <summary>
+        /// Temperature added on each fallback retry when a segment fails the thresholds below
+        /// (whisper-cli -tpi). Retrying at a higher temperature breaks repetition loops. 0 = no fallback.
+        /// </summary>
+        public double WhisperTemperatureIncrement { get; set; } = 0.2;
+
+        /// <summary>
+        /// Entropy threshold for decoder fallback (whisper-cli -et). Repetitive output has low entropy;
+        /// segments below this are retried. Equivalent to Whisper's compression-ratio check.
+        /// </summary>
+        public double WhisperEntropyThreshold { get; set; } = 2.4;
+
+        /// <summary>
+        /// Average log probability below which a segment is retried (whisper-cli -lpt).
+        /// </summary>
+        public double WhisperLogProbThreshold { get; set; } = -1.0;
+
+        /// <summary>
+        /// No-speech probability above which a segment counts as silence (whisper-cli -nth).
+        /// </summary>
+        public double WhisperNoSpeechThreshold { get; set; } = 0.6;
+
+        /// <summary>
+        /// Suppress non-speech tokens such as "(music)" or "[APPLAUSE]" while decoding (whisper-cli -sns).
+        /// </summary>
+        public bool WhisperSuppressNonSpeechTokens { get; set; } = false;
+
+        /// <summary>
+        /// Regular expression of token text that must never be emitted (whisper-cli --suppress-regex).
+        /// Null/empty = no custom suppression.
+        /// </summary>
+        public string? WhisperSuppressRegex { get; set; }
+
+        /// <summary>
+        /// Text that primes the Whisper decoder (whisper-cli --prompt): names, jargon or a glossary
+        /// written the way they should appear. Whisper reads at most ~224 tokens (roughly 150 words);
+        /// longer prompts are cut at a word boundary.
+        /// </summary>
+        public string? WhisperInitialPrompt { get; set; }
+
+        /// <summary>
+        /// Also prime Whisper with the end of the previous transcript, for continuity across takes.
+        /// </summary>
+        public bool WhisperPromptWithPreviousTranscript { get; set; } = false;
+
+        // Quality Gate
+
+        /// <summary>
+        /// Transcripts scoring below this estimated quality (0..1) are flagged as low quality. 0 = disabled.
+        /// </summary>
+        public double MinTranscriptQuality { get; set; } = 0.0;
+
+        /// <summary>
+        /// Recordings whose no-speech probability (0..1) reaches this are flagged as likely dead air
+        /// and handled like low-quality transcripts. 0 = disabled.
+        /// </summary>
+        public double NoSpeechWarningThreshold { get; set; } = 0.8;
+
+        /// <summary>
+        /// Don't type/paste low-quality transcripts; they are still saved to history
+        /// </summary>
+        public bool SuppressLowQualityOutput { get; set; } = true;
+
+        // Output Formatting
+
+        /// <summary>
+        /// Apply language-specific spacing, punctuation and capitalization rules to transcripts.
+        /// Turn off to get the model's raw output.
+        /// </summary>
+        public bool EnableLanguageFormatting { get; set; } = false;
+
+        /// <summary>
+        /// Language whose formatting rules are applied (e.g. "en", "fr", "zh").
+        /// Null = the transcription language, or a guess from the transcript's script when that is "auto".
+        /// </summary>
+        public string? FormattingLanguage { get; set; }
+
+        /// <summary>
+        /// End transcripts the model left unpunctuated with a period. Only applies with language formatting on.
+        /// </summary>
+        public bool AddFinalPunctuation { get; set; } = false;
+
+        /// <summary>
+        /// Write spoken numbers, percentages, amounts and dates as digits ("twenty percent" -> "20%").
+        /// Only runs when the formatting or transcription language has number rules.
+        /// </summary>
+        public bool EnableNumberFormatting { get; set; } = false;
+
+        /// <summary>
+        /// Replace profanity in transcripts with asterisks. Extra words go in profanity.txt.
+        /// </summary>
+        public bool EnableProfanityFilter { get; set; } = false;
+
+        /// <summary>
+        /// Letter case applied as the last formatting step.
+        /// </summary>
+        public OutputCasing OutputCasing { get; set; } = OutputCasing.Verbatim;
+
         /// <summary>
         /// Preference for Local AI Engine (Ollama or Built-in).
         /// </summary>
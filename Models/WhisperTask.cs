@@ -0,0 +1,18 @@
+namespace EliteWhisper.Models
+{
+    /// <summary>
+    /// What Whisper models do with the speech they recognize.
+    /// </summary>
+    public enum WhisperTask
+    {
+        /// <summary>
+        /// Write down what was said, in the spoken language (default).
+        /// </summary>
+        Transcribe,
+
+        /// <summary>
+        /// Translate the speech to English text, whatever language was spoken.
+        /// </summary>
+        Translate
+    }
+}
@@ -0,0 +1,17 @@
+namespace EliteWhisper.Models
+{
+    /// <summary>
+    /// A recognized word and where it sits in the recording, in milliseconds.
+    /// </summary>
+    public class WordTiming
+    {
+        public string Word { get; set; } = string.Empty;
+        public long StartMs { get; set; }
+        public long EndMs { get; set; }
+
+        /// <summary>
+        /// Model probability for the word, 0..1 (its least certain token). Null when not reported.
+        /// </summary>
+        public float? Confidence { get; set; }
+    }
+}
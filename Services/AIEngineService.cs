@@ -1,7 +1,10 @@
 using System;
+using System.Collections.Generic;
 using System.Diagnostics;
 using System.IO;
+using System.Linq;
 using System.Text;
+using System.Text.Json;
 using System.Threading;
 using System.Threading.Tasks;
 using EliteWhisper.Models;
@@ -28,7 +31,18 @@ namespace EliteWhisper.Services
     public class AIEngineService
     {
         private readonly WhisperConfigurationService _configService;
+        private readonly RecentModelsService _recentModels;
         private const int DEFAULT_TIMEOUT_MS = 120000; // 120 seconds
+
+        // whisper-cli -pp logs e.g. "whisper_print_progress_callback: progress =  45%"
+        private static readonly System.Text.RegularExpressions.Regex ProgressPattern =
+            new(@"progress\s*=\s*(\d+)%", System.Text.RegularExpressions.RegexOptions.Compiled);
+
+        // Whisper keeps at most n_text_ctx / 2 = 224 prompt tokens
+        private const int MAX_PROMPT_TOKENS = 224;
+
+        // End of the last Whisper transcript, for prompting the next take
+        private string? _previousTranscript;
         
         // State Management
         private EngineState _state = EngineState.Idle;
@@ -47,13 +61,47 @@ namespace EliteWhisper.Services
         }
         
         public event EventHandler<EngineState>? StateChanged;
+
+        /// <summary>
+        /// Whether the last Whisper run used the GPU. Null until something has been transcribed.
+        /// </summary>
+        public bool? IsGpuActive { get; private set; }
+
+        /// <summary>
+        /// Raised when GPU acceleration is enabled but whisper-cli could not use it.
+        /// Later runs stay on the CPU until another model is activated.
+        /// </summary>
+        public event EventHandler<string>? GpuUnavailable;
+
+        private bool _gpuFailed;
+
+        /// <summary>
+        /// Raised for each segment as whisper-cli decodes it, before TranscribeAsync returns.
+        /// Raised on a background thread.
+        /// </summary>
+        public event EventHandler<PartialTranscript>? SegmentTranscribed;
+
+        /// <summary>
+        /// Raised with the model path after ActivateModelAsync switched to a new Whisper model.
+        /// </summary>
+        public event EventHandler<string>? ModelActivated;
         
         // Activation Lock to prevent concurrent model switching
         private readonly SemaphoreSlim _activationLock = new SemaphoreSlim(1, 1);
 
-        public AIEngineService(WhisperConfigurationService configService)
+        // Replaces the whisper-cli test load during activation, so tests don't need a real model
+        private readonly Func<string, Task>? _probeOverride;
+
+        public AIEngineService(WhisperConfigurationService configService, RecentModelsService recentModels)
+            : this(configService, recentModels, null)
+        {
+        }
+
+        internal AIEngineService(WhisperConfigurationService configService, RecentModelsService recentModels, Func<string, Task>? probeModel)
         {
             _configService = configService;
+            _recentModels = recentModels;
+            _probeOverride = probeModel;
             
             // Initial state check
             if (IsConfigured())
@@ -85,8 +133,19 @@ namespace EliteWhisper.Services
             State = newState;
         }
 
+        /// <summary>
+        /// True while ActivateModelAsync is running.
+        /// </summary>
+        public bool IsActivating => _activationLock.CurrentCount == 0;
+
+        /// <summary>
+        /// Why the last ActivateModelAsync call failed. Null after a successful activation.
+        /// </summary>
+        public string? LastActivationError { get; private set; }
+
         /// <summary>
         /// Safely activates a new model with transactional rollback.
+        /// Throws InvalidOperationException if another activation is already running.
         /// </summary>
         public async Task<bool> ActivateModelAsync(string modelPath)
         {
@@ -105,11 +164,12 @@ namespace EliteWhisper.Services
                 return false;
             }
 
-            // 2. Acquire Lock (Wait max 1 sec to avoid UI freeze if jammed, but logic shouldn't jam)
-            if (!await _activationLock.WaitAsync(1000))
+            // 2. Acquire Lock without waiting: a second request (e.g. a double-click) must not
+            // queue up and repeat the load once the first one finishes.
+            if (!_activationLock.Wait(0))
             {
-                System.Diagnostics.Debug.WriteLine("[AIEngine] Activation rejected: Lock busy.");
-                return false;
+                System.Diagnostics.Debug.WriteLine("[AIEngine] Activation rejected: load already in progress.");
+                throw new InvalidOperationException("A model load is already in progress.");
             }
 
             // Capture previous state/model for rollback
@@ -128,14 +188,9 @@ namespace EliteWhisper.Services
                     throw new FileNotFoundException("Model file not found", modelPath);
                 }
 
-                // 5. "Warm Up" / Test Load (Transactional Phase 2)
-                // In a real compiled lib we would load it into memory here.
-                // Since we use CLI, we might run a quick dummy probe or just trust file existence + extension.
-                // For this implementation, we will trust validation + file existence.
-                // If we wanted to be super safe, we could run `whisper-cli --help` or similar to check binary?
-                // Let's assume validation passed.
-                
-                await Task.Delay(200); // Simulate brief warmup stabilization
+                // 5. Test Load (Transactional Phase 2)
+                // A corrupt or truncated model fails here with a readable reason instead of on the next dictation
+                await (_probeOverride ?? ProbeModelAsync)(modelPath);
                 
                 // 6. Commit Change (Transactional Phase 3)
                 // Only now do we update the persistence
@@ -144,14 +199,21 @@ namespace EliteWhisper.Services
                 config.PreferredSTTEngine = "Whisper"; // Force to whisper if manually selecting whisper model
                 config.AutoSelectSTT = false;
                 _configService.SaveConfiguration(config);
+                _recentModels.RecordUsage(modelPath, "Whisper");
+
+                // A different model may fit in video memory, give the GPU another chance
+                _gpuFailed = false;
                 
                 // 7. Transition to Ready
+                LastActivationError = null;
                 State = EngineState.Ready;
+                ModelActivated?.Invoke(this, modelPath);
                 return true;
             }
             catch (Exception ex)
             {
                 System.Diagnostics.Debug.WriteLine($"[AIEngine] Activation Failed: {ex.Message}");
+                LastActivationError = ex.Message;
                 
                 // Rollback
                 // Config remains as previous (since we didn't call SetDefaultModel)
@@ -171,11 +233,22 @@ namespace EliteWhisper.Services
         }
 
         /// <summary>
-        /// Check if Whisper is properly configured
+        /// Check if Whisper is properly configured. A dangling default model path is repaired
+        /// when another Whisper model is installed.
         /// </summary>
         public bool IsConfigured()
         {
-            return _configService.CurrentConfiguration.IsConfigured;
+            return _configService.CurrentConfiguration.IsConfigured ||
+                (IsExecutableConfigured() && ResolveDefaultModel() != null);
+        }
+
+        /// <summary>
+        /// Check if the Whisper executable is present, regardless of the selected model
+        /// </summary>
+        public bool IsExecutableConfigured()
+        {
+            var exe = _configService.CurrentConfiguration.ExecutablePath;
+            return !string.IsNullOrEmpty(exe) && File.Exists(exe);
         }
 
         /// <summary>
@@ -186,56 +259,579 @@ namespace EliteWhisper.Services
         /// <summary>
         /// Transcribes an audio file using Whisper
         /// </summary>
+        /// <param name="modelPathOverride">Model to use instead of the configured default model.</param>
+        /// <param name="progress">Receives whisper-cli's completion percentage, if given.</param>
         public async Task<string> TranscribeAsync(
             string audioFilePath, 
             TranscriptionModel model = TranscriptionModel.Balanced,
-            CancellationToken cancellationToken = default)
+            CancellationToken cancellationToken = default,
+            string? modelPathOverride = null,
+            IProgress<int>? progress = null)
+        {
+            var result = await TranscribeDetailedAsync(audioFilePath, cancellationToken, modelPathOverride, progress);
+            return result.Text;
+        }
+
+        /// <summary>
+        /// Same as TranscribeAsync, but also returns what whisper-cli reported about this run
+        /// (the no-speech probability, see AggregateNoSpeechProbability, and the auto-detected language).
+        /// </summary>
+        public async Task<TranscriptionResult> TranscribeDetailedAsync(
+            string audioFilePath,
+            CancellationToken cancellationToken = default,
+            string? modelPathOverride = null,
+            IProgress<int>? progress = null)
         {
             var config = _configService.CurrentConfiguration;
+            string modelPath = ValidateTranscription(audioFilePath, modelPathOverride);
+            string executablePath = config.ExecutablePath!;
+            string workingDirectory = Path.GetDirectoryName(executablePath)!;
 
-            if (!config.IsConfigured)
+            // Build arguments for whisper-cli
+            // whisper-cli -m model.bin -f audio.wav --output-txt
+            // The JSON output is only read for the per-segment no-speech probabilities
+            string outputBase = Path.Combine(Path.GetDirectoryName(audioFilePath)!, Path.GetFileNameWithoutExtension(audioFilePath));
+            string outputJsonPath = outputBase + ".json";
+            string arguments = $"-m \"{modelPath}\" -f \"{audioFilePath}\" --no-timestamps -otxt -oj -of \"{outputBase}\"" + BuildDecodingArguments(config, _previousTranscript);
+            if (progress != null) arguments += " -pp";
+            float? noSpeechProbability = null;
+
+            // whisper-cli prints each segment to stdout as soon as it is decoded
+            int segmentIndex = 0;
+            void OnOutputLine(string line)
             {
-                State = EngineState.Error;
-                throw new InvalidOperationException("Whisper is not configured. Please select a Whisper folder in settings.");
+                string trimmed = line.Trim();
+                if (!IsTranscriptLine(trimmed)) return;
+                SegmentTranscribed?.Invoke(this, new PartialTranscript { Index = segmentIndex++, Text = trimmed });
             }
 
-            if (!File.Exists(audioFilePath))
+            void OnErrorLine(string line)
             {
-                State = EngineState.Error;
-                throw new FileNotFoundException($"Audio file not found: {audioFilePath}");
+                var match = ProgressPattern.Match(line);
+                if (match.Success) progress!.Report(Math.Clamp(int.Parse(match.Groups[1].Value), 0, 100));
             }
 
-            // Note: We assume DictationService handles State = Processing wrapping this call
-            
-            string modelPath = config.DefaultModelPath!;
-            string executablePath = config.ExecutablePath!;
-            string workingDirectory = Path.GetDirectoryName(executablePath)!;
+            var result = await RunWhisperAsync(executablePath, arguments, workingDirectory, cancellationToken, OnOutputLine,
+                progress == null ? null : OnErrorLine);
+            var detectedLanguage = ParseDetectedLanguage(result.stderr + result.stdout);
 
-            // Build arguments for whisper-cli
-            // whisper-cli -m model.bin -f audio.wav --output-txt
-            string arguments = $"-m \"{modelPath}\" -f \"{audioFilePath}\" --no-timestamps -otxt";
-
-            var result = await RunProcessAsync(executablePath, arguments, workingDirectory, cancellationToken);
+            if (File.Exists(outputJsonPath))
+            {
+                try
+                {
+                    string json = await File.ReadAllTextAsync(outputJsonPath, cancellationToken);
+                    noSpeechProbability = AggregateNoSpeechProbability(ParseJsonSegments(json));
+                }
+                catch (JsonException ex)
+                {
+                    System.Diagnostics.Debug.WriteLine($"[AIEngine] Could not read Whisper JSON output: {ex.Message}");
+                }
+                finally
+                {
+                    try { File.Delete(outputJsonPath); } catch { }
+                }
+            }
             
             // Parse output - whisper outputs to a .txt file with same name
-            string outputTxtPath = Path.ChangeExtension(audioFilePath, ".txt");
+            string outputTxtPath = outputBase + ".txt";
             if (File.Exists(outputTxtPath))
             {
                 string transcription = await File.ReadAllTextAsync(outputTxtPath, cancellationToken);
                 // Cleanup
                 try { File.Delete(outputTxtPath); } catch { }
-                return transcription.Trim();
+                return new TranscriptionResult
+                {
+                    Text = RememberTranscript(transcription.Trim()),
+                    NoSpeechProbability = noSpeechProbability,
+                    DetectedLanguage = detectedLanguage
+                };
             }
 
             // Fallback: Parse STDOUT if file output failed
-            return ParseStdout(result.stdout);
+            return new TranscriptionResult
+            {
+                Text = RememberTranscript(ParseStdout(result.stdout)),
+                NoSpeechProbability = noSpeechProbability,
+                DetectedLanguage = detectedLanguage
+            };
+        }
+
+        /// <summary>
+        /// Transcribes an audio file and returns timed segments. Word timings come from
+        /// whisper-cli's full JSON output, which enables token timestamps.
+        /// </summary>
+        public async Task<List<TranscriptSegment>> TranscribeWithTimestampsAsync(
+            string audioFilePath,
+            CancellationToken cancellationToken = default,
+            string? modelPathOverride = null)
+        {
+            var config = _configService.CurrentConfiguration;
+            string modelPath = ValidateTranscription(audioFilePath, modelPathOverride);
+            string executablePath = config.ExecutablePath!;
+            string workingDirectory = Path.GetDirectoryName(executablePath)!;
+
+            // -of sets the output base name; whisper-cli appends ".json"
+            string outputBase = Path.Combine(Path.GetDirectoryName(audioFilePath)!, Path.GetFileNameWithoutExtension(audioFilePath));
+            string outputJsonPath = outputBase + ".json";
+            string arguments = $"-m \"{modelPath}\" -f \"{audioFilePath}\" -ojf -of \"{outputBase}\"" + BuildDecodingArguments(config, _previousTranscript);
+
+            var result = await RunWhisperAsync(executablePath, arguments, workingDirectory, cancellationToken, null);
+
+            if (!File.Exists(outputJsonPath))
+            {
+                throw new Speech.TranscriptionException(TranscriptionError.EngineFailed, $"Whisper produced no timestamp output (exit code {result.exitCode}).");
+            }
+
+            try
+            {
+                string json = await File.ReadAllTextAsync(outputJsonPath, cancellationToken);
+                return ParseJsonSegments(json);
+            }
+            finally
+            {
+                try { File.Delete(outputJsonPath); } catch { }
+            }
+        }
+
+        /// <summary>
+        /// Language whisper-cli picked for an "-l auto" run, read from its log output.
+        /// Null when the language was set explicitly or the line is missing.
+        /// </summary>
+        public static LanguageDetection? ParseDetectedLanguage(string output)
+        {
+            // whisper.cpp logs e.g. "auto-detected language: en (p = 0.973421)"
+            var match = System.Text.RegularExpressions.Regex.Match(output,
+                @"auto-detected language:\s*(\w+)\s*\(p\s*=\s*([0-9.]+)\)");
+            if (!match.Success) return null;
+
+            return new LanguageDetection
+            {
+                Language = match.Groups[1].Value,
+                Probability = float.Parse(match.Groups[2].Value, System.Globalization.CultureInfo.InvariantCulture)
+            };
+        }
+
+        /// <summary>
+        /// Checks that whisper-cli, the model and the input exist. Returns the model to use.
+        /// </summary>
+        private string ValidateTranscription(string audioFilePath, string? modelPathOverride)
+        {
+            var config = _configService.CurrentConfiguration;
+
+            if (modelPathOverride != null)
+            {
+                if (!IsExecutableConfigured())
+                {
+                    State = EngineState.Error;
+                    throw new Speech.TranscriptionException(TranscriptionError.NotConfigured, "Whisper is not configured. Please select a Whisper folder in settings.");
+                }
+
+                if (!File.Exists(modelPathOverride))
+                {
+                    throw new Speech.TranscriptionException(TranscriptionError.NoModel, $"Whisper model not found: {modelPathOverride}");
+                }
+            }
+            else if (!IsExecutableConfigured())
+            {
+                State = EngineState.Error;
+                throw new Speech.TranscriptionException(TranscriptionError.NotConfigured, "Whisper is not configured. Please select a Whisper folder in settings.");
+            }
+
+            string? modelPath = modelPathOverride ?? ResolveDefaultModel();
+            if (modelPath == null)
+            {
+                State = EngineState.Error;
+                throw new Speech.TranscriptionException(TranscriptionError.NoModel, "No Whisper model is installed. Download one from the Models page or select a model file in settings.");
+            }
+
+            if (!File.Exists(audioFilePath))
+            {
+                State = EngineState.Error;
+                throw new Speech.TranscriptionException(TranscriptionError.AudioNotFound, $"Audio file not found: {audioFilePath}");
+            }
+
+            // Note: We assume DictationService handles State = Processing wrapping this call
+            
+            return modelPath;
+        }
+
+        /// <summary>
+        /// Returns the default Whisper model. When the saved path is missing (moved install folder,
+        /// deleted model) the first installed ggml model is used instead, preferring the base model.
+        /// Null when no Whisper model is installed.
+        /// </summary>
+        private string? ResolveDefaultModel()
+        {
+            var config = _configService.CurrentConfiguration;
+            if (!string.IsNullOrEmpty(config.DefaultModelPath) && File.Exists(config.DefaultModelPath))
+                return config.DefaultModelPath;
+
+            string modelsDir = config.ModelsDirectory ?? Path.Combine(config.BaseDirectory ?? AppDomain.CurrentDomain.BaseDirectory, "models");
+            if (!Directory.Exists(modelsDir))
+                return null;
+
+            var installed = Directory.EnumerateFiles(modelsDir, "ggml-*.bin").OrderBy(f => f, StringComparer.OrdinalIgnoreCase).ToList();
+            string? found = installed.FirstOrDefault(f => Path.GetFileName(f).StartsWith("ggml-base", StringComparison.OrdinalIgnoreCase))
+                ?? installed.FirstOrDefault();
+            if (found == null)
+                return null;
+
+            // A Sherpa model directory is still the user's choice; only repair a dangling path
+            if (string.IsNullOrEmpty(config.DefaultModelPath) || !Directory.Exists(config.DefaultModelPath))
+            {
+                System.Diagnostics.Debug.WriteLine($"[AIEngine] Default model missing, switching to {Path.GetFileName(found)}");
+                _configService.SetDefaultModel(found);
+            }
+
+            return found;
+        }
+
+        /// <summary>
+        /// Reads segments and word timings from whisper-cli's --output-json-full file.
+        /// Tokens starting with a space begin a new word; special tokens ([_BEG_], [_TT_n]) are skipped.
+        /// Token probabilities ("p") become word and segment confidence.
+        /// </summary>
+        private static List<TranscriptSegment> ParseJsonSegments(string json)
+        {
+            var segments = new List<TranscriptSegment>();
+
+            using var doc = JsonDocument.Parse(json);
+            if (!doc.RootElement.TryGetProperty("transcription", out var transcription))
+                return segments;
+
+            foreach (var item in transcription.EnumerateArray())
+            {
+                var segment = new TranscriptSegment
+                {
+                    Text = item.GetProperty("text").GetString()?.Trim() ?? string.Empty
+                };
+
+                if (item.TryGetProperty("offsets", out var offsets))
+                {
+                    segment.StartMs = offsets.GetProperty("from").GetInt64();
+                    segment.EndMs = offsets.GetProperty("to").GetInt64();
+                }
+
+                // Only reported by whisper-cli builds that expose the per-segment no-speech probability
+                if (item.TryGetProperty("no_speech_prob", out var noSpeech) && noSpeech.ValueKind == JsonValueKind.Number)
+                {
+                    segment.NoSpeechProbability = Math.Clamp(noSpeech.GetSingle(), 0f, 1f);
+                }
+
+                if (item.TryGetProperty("tokens", out var tokens))
+                {
+                    var words = new List<WordTiming>();
+                    float probabilitySum = 0;
+                    int probabilityCount = 0;
+
+                    foreach (var token in tokens.EnumerateArray())
+                    {
+                        string text = token.GetProperty("text").GetString() ?? string.Empty;
+                        if (text.Length == 0 || text.StartsWith("[_")) continue;
+                        if (!token.TryGetProperty("offsets", out var tokenOffsets)) continue;
+
+                        long from = tokenOffsets.GetProperty("from").GetInt64();
+                        long to = tokenOffsets.GetProperty("to").GetInt64();
+
+                        float? probability = null;
+                        if (token.TryGetProperty("p", out var p) && p.ValueKind == JsonValueKind.Number)
+                        {
+                            probability = Math.Clamp(p.GetSingle(), 0f, 1f);
+                            probabilitySum += probability.Value;
+                            probabilityCount++;
+                        }
+
+                        if (words.Count == 0 || text.StartsWith(" "))
+                        {
+                            words.Add(new WordTiming { Word = text.Trim(), StartMs = from, EndMs = to, Confidence = probability });
+                        }
+                        else
+                        {
+                            // Continuation of the previous word (sub-word token or trailing punctuation)
+                            var last = words[^1];
+                            last.Word += text;
+                            last.EndMs = to;
+                            if (probability.HasValue)
+                                last.Confidence = Math.Min(last.Confidence ?? 1f, probability.Value);
+                        }
+                    }
+
+                    words.RemoveAll(w => w.Word.Length == 0);
+                    if (words.Count > 0) segment.Words = words;
+                    if (probabilityCount > 0) segment.Confidence = probabilitySum / probabilityCount;
+                }
+
+                segments.Add(segment);
+            }
+
+            return segments;
+        }
+
+        /// <summary>
+        /// Duration-weighted mean of the segments' no-speech probabilities, so one short silent segment
+        /// doesn't outweigh a long spoken one. Null when no segment reports it.
+        /// </summary>
+        public static float? AggregateNoSpeechProbability(IReadOnlyList<TranscriptSegment> segments)
+        {
+            double weighted = 0;
+            double totalWeight = 0;
+            foreach (var segment in segments)
+            {
+                if (segment.NoSpeechProbability is not float probability) continue;
+                double weight = Math.Max(1, segment.EndMs - segment.StartMs);
+                weighted += probability * weight;
+                totalWeight += weight;
+            }
+            return totalWeight > 0 ? (float)(weighted / totalWeight) : null;
+        }
+
+        /// <summary>
+        /// Builds the decoding flags (language, task, sampling strategy, best-of, temperature and its fallback, token suppression, prompt) from user settings.
+        /// suppress_blank is always on in whisper-cli, so it has no setting here.
+        /// </summary>
+        internal static string BuildDecodingArguments(WhisperConfiguration config, string? previousTranscript)
+        {
+            var args = new StringBuilder();
+
+            // whisper-cli defaults to English, so "auto" has to be passed explicitly
+            string language = string.IsNullOrWhiteSpace(config.TranscriptionLanguage) ? "auto" : config.TranscriptionLanguage;
+            args.Append($" -l {language}");
+
+            if (config.WhisperTask == WhisperTask.Translate)
+                args.Append(" -tr");
+
+            args.Append($" -t {Math.Max(1, config.TranscriptionThreads)}");
+
+            // whisper-cli switches to beam search whenever the beam size is above 1
+            int beamSize = config.DecodingStrategy == DecodingStrategy.BeamSearch ? Math.Clamp(config.BeamSize, 2, 16) : 1;
+            args.Append($" -bs {beamSize}");
+
+            // best_of < 1 is rejected by whisper.cpp, clamp instead of failing the transcription
+            int bestOf = Math.Max(1, config.WhisperBestOf);
+            args.Append($" -bo {bestOf}");
+
+            double temperature = Math.Clamp(config.WhisperTemperature, 0.0, 1.0);
+            args.Append(FormattableString.Invariant($" -tp {temperature:0.0#}"));
+
+            // Temperature fallback: segments that look looped (low entropy), unlikely (low log
+            // probability) or silent are decoded again at a higher temperature
+            double temperatureIncrement = Math.Clamp(config.WhisperTemperatureIncrement, 0.0, 1.0);
+            if (temperatureIncrement > 0.0)
+            {
+                args.Append(FormattableString.Invariant($" -tpi {temperatureIncrement:0.0#}"));
+                args.Append(FormattableString.Invariant($" -et {Math.Max(0.0, config.WhisperEntropyThreshold):0.0#}"));
+                args.Append(FormattableString.Invariant($" -lpt {Math.Min(0.0, config.WhisperLogProbThreshold):0.0#}"));
+            }
+            else
+            {
+                args.Append(" -nf");
+            }
+            args.Append(FormattableString.Invariant($" -nth {Math.Clamp(config.WhisperNoSpeechThreshold, 0.0, 1.0):0.0#}"));
+
+            if (config.WhisperSuppressNonSpeechTokens)
+                args.Append(" -sns");
+
+            if (!string.IsNullOrWhiteSpace(config.WhisperSuppressRegex))
+                args.Append($" --suppress-regex {QuoteArgument(config.WhisperSuppressRegex)}");
+
+            string? prompt = BuildInitialPrompt(config.WhisperInitialPrompt,
+                config.WhisperPromptWithPreviousTranscript ? previousTranscript : null);
+            if (prompt != null)
+                args.Append($" --prompt {QuoteArgument(prompt)}");
+
+            return args.ToString();
+        }
+
+        /// <summary>
+        /// Quotes one command-line argument the way the Windows C runtime splits them back apart:
+        /// backslashes are literal unless they precede a quote, so those runs (and a trailing run) are doubled.
+        /// </summary>
+        internal static string QuoteArgument(string value)
+        {
+            var quoted = new StringBuilder("\"");
+            int backslashes = 0;
+
+            foreach (char c in value)
+            {
+                if (c == '\\')
+                {
+                    backslashes++;
+                    continue;
+                }
+
+                if (c == '"')
+                {
+                    quoted.Append('\\', backslashes * 2 + 1);
+                }
+                else
+                {
+                    quoted.Append('\\', backslashes);
+                }
+                backslashes = 0;
+                quoted.Append(c);
+            }
+
+            quoted.Append('\\', backslashes * 2);
+            quoted.Append('"');
+            return quoted.ToString();
+        }
+
+        /// <summary>
+        /// Combines the user's prompt with the tail of the previous transcript, within Whisper's prompt limit.
+        /// The user's prompt keeps its beginning, the previous transcript its end (the part nearest the new take).
+        /// Tokens are estimated at 3 characters each, which errs on the short side for most languages.
+        /// </summary>
+        private static string? BuildInitialPrompt(string? initialPrompt, string? previousTranscript)
+        {
+            int budget = MAX_PROMPT_TOKENS * 3;
+            string glossary = Flatten(initialPrompt);
+            string previous = Flatten(previousTranscript);
+
+            if (glossary.Length > budget)
+            {
+                int cut = glossary.LastIndexOf(' ', budget);
+                glossary = glossary[..(cut > 0 ? cut : budget)];
+            }
+
+            int remaining = budget - glossary.Length - 1;
+            if (previous.Length > remaining)
+            {
+                if (remaining < 20)
+                {
+                    previous = string.Empty;
+                }
+                else
+                {
+                    int start = previous.Length - remaining;
+                    int space = previous.IndexOf(' ', start);
+                    previous = space > 0 ? previous[(space + 1)..] : previous[start..];
+                }
+            }
+
+            string prompt = $"{glossary} {previous}".Trim();
+            return prompt.Length > 0 ? prompt : null;
+        }
+
+        // Prompts go on the command line, so line breaks and runs of spaces become single spaces
+        private static string Flatten(string? text)
+        {
+            if (string.IsNullOrWhiteSpace(text)) return string.Empty;
+            return string.Join(' ', text.Split((char[]?)null, StringSplitOptions.RemoveEmptyEntries));
+        }
+
+        private string RememberTranscript(string transcript)
+        {
+            if (transcript.Length > 0) _previousTranscript = transcript;
+            return transcript;
+        }
+
+        /// <summary>
+        /// Runs whisper-cli on the GPU when enabled, retrying once on the CPU (-ng) if the GPU run fails.
+        /// </summary>
+        private async Task<(string stdout, string stderr, int exitCode)> RunWhisperAsync(
+            string executable,
+            string arguments,
+            string workingDirectory,
+            CancellationToken cancellationToken,
+            Action<string>? onOutputLine,
+            Action<string>? onErrorLine = null)
+        {
+            bool useGpu = _configService.CurrentConfiguration.EnableGPUAcceleration && !_gpuFailed;
+            if (!useGpu)
+            {
+                IsGpuActive = false;
+                return await RunProcessAsync(executable, arguments + " -ng", workingDirectory, cancellationToken, onOutputLine, onErrorLine);
+            }
+
+            var result = await RunProcessAsync(executable, arguments, workingDirectory, cancellationToken, onOutputLine, onErrorLine);
+            if (result.exitCode == 0)
+            {
+                IsGpuActive = UsedGpuBackend(result.stderr);
+                return result;
+            }
+
+            // Driver problems or running out of video memory make whisper-cli exit early
+            var cpuResult = await RunProcessAsync(executable, arguments + " -ng", workingDirectory, cancellationToken, onOutputLine, onErrorLine);
+            IsGpuActive = false;
+
+            if (cpuResult.exitCode == 0)
+            {
+                // Only blame the GPU when the CPU run succeeds, bad input fails both ways
+                _gpuFailed = true;
+                string reason = LastLine(result.stderr) ?? $"exit code {result.exitCode}";
+                System.Diagnostics.Debug.WriteLine($"[AIEngine] GPU run failed, falling back to CPU: {reason}");
+                GpuUnavailable?.Invoke(this, reason);
+            }
+
+            return cpuResult;
+        }
+
+        /// <summary>
+        /// Checks the model file's header and has whisper-cli load it once on a short silent clip.
+        /// Throws InvalidDataException with the reason if the model is unusable.
+        /// </summary>
+        private async Task ProbeModelAsync(string modelPath)
+        {
+            string? headerError = CheckModelHeader(modelPath);
+            if (headerError != null) throw new InvalidDataException(headerError);
+
+            string executablePath = _configService.CurrentConfiguration.ExecutablePath!;
+            string probePath = Path.Combine(Path.GetTempPath(), $"whisper_temp_{Guid.NewGuid():N}.wav");
+            try
+            {
+                using (var writer = new NAudio.Wave.WaveFileWriter(probePath, NAudio.Wave.WaveFormat.CreateIeeeFloatWaveFormat(16000, 1)))
+                {
+                    writer.WriteSamples(new float[8000], 0, 8000);
+                }
+
+                var result = await RunWhisperAsync(executablePath, $"-m \"{modelPath}\" -f \"{probePath}\" --no-timestamps",
+                    Path.GetDirectoryName(executablePath)!, CancellationToken.None, onOutputLine: null);
+                if (result.exitCode != 0)
+                {
+                    string reason = LastLine(result.stderr) ?? $"exit code {result.exitCode}";
+                    throw new InvalidDataException($"Whisper couldn't load {Path.GetFileName(modelPath)}: {reason}");
+                }
+            }
+            finally
+            {
+                try { File.Delete(probePath); } catch { }
+            }
+        }
+
+        // whisper.cpp model files start with the "ggml" magic (0x67676d6c) stored as a little-endian uint32
+        private static string? CheckModelHeader(string modelPath)
+        {
+            var info = new FileInfo(modelPath);
+            if (info.Length < 1024 * 1024)
+                return $"{info.Name} is too small to be a Whisper model ({info.Length:N0} bytes). The download may be incomplete.";
+
+            using var stream = File.OpenRead(modelPath);
+            var magic = new byte[4];
+            if (stream.Read(magic, 0, 4) < 4 || BitConverter.ToUInt32(magic, 0) != 0x67676d6c)
+                return $"{info.Name} is not a Whisper model (ggml) file.";
+
+            return null;
+        }
+
+        // whisper.cpp logs e.g. "whisper_backend_init_gpu: using CUDA0 backend" when a GPU backend is loaded
+        private static bool UsedGpuBackend(string stderr)
+        {
+            return System.Text.RegularExpressions.Regex.IsMatch(stderr, @"using (?!CPU)\w+ backend");
+        }
+
+        private static string? LastLine(string text)
+        {
+            var lines = text.Split('\n', StringSplitOptions.RemoveEmptyEntries | StringSplitOptions.TrimEntries);
+            return lines.Length > 0 ? lines[^1] : null;
         }
 
         private async Task<(string stdout, string stderr, int exitCode)> RunProcessAsync(
             string executable, 
             string arguments,
             string workingDirectory,
-            CancellationToken cancellationToken)
+            CancellationToken cancellationToken,
+            Action<string>? onOutputLine = null,
+            Action<string>? onErrorLine = null)
         {
             var stdout = new StringBuilder();
             var stderr = new StringBuilder();
@@ -254,9 +850,19 @@ namespace EliteWhisper.Services
                 StandardErrorEncoding = Encoding.UTF8
             };
 
-            process.OutputDataReceived += (s, e) => { if (e.Data != null) stdout.AppendLine(e.Data); };
+            process.OutputDataReceived += (s, e) =>
+            {
+                if (e.Data == null) return;
+                stdout.AppendLine(e.Data);
+                onOutputLine?.Invoke(e.Data);
+            };
 
-            process.ErrorDataReceived += (s, e) => { if (e.Data != null) stderr.AppendLine(e.Data); };
+            process.ErrorDataReceived += (s, e) =>
+            {
+                if (e.Data == null) return;
+                stderr.AppendLine(e.Data);
+                onErrorLine?.Invoke(e.Data);
+            };
 
             process.Start();
             process.BeginOutputReadLine();
@@ -277,8 +883,11 @@ namespace EliteWhisper.Services
                     process.Kill(entireProcessTree: true);
                 }
                 catch { }
+
+                // The caller canceled: report that, not a timeout, and never a partial result
+                cancellationToken.ThrowIfCancellationRequested();
                 
-                throw new TimeoutException($"Whisper process timed out");
+                throw new Speech.TranscriptionException(TranscriptionError.Timeout, "Whisper process timed out");
             }
 
             return (stdout.ToString(), stderr.ToString(), process.ExitCode);
@@ -292,12 +901,7 @@ namespace EliteWhisper.Services
             foreach (var line in lines)
             {
                 string trimmed = line.Trim();
-                // Skip progress lines, timestamps, etc.
-                if (trimmed.StartsWith("[") || 
-                    trimmed.StartsWith("whisper_") || 
-                    trimmed.StartsWith("main:") ||
-                    trimmed.StartsWith("system_info:") ||
-                    string.IsNullOrWhiteSpace(trimmed))
+                if (!IsTranscriptLine(trimmed))
                 {
                     continue;
                 }
@@ -306,5 +910,15 @@ namespace EliteWhisper.Services
 
             return result.ToString().Trim();
         }
+
+        // Skip progress lines, timestamps, etc.
+        private static bool IsTranscriptLine(string trimmed)
+        {
+            return !(trimmed.StartsWith("[") ||
+                trimmed.StartsWith("whisper_") ||
+                trimmed.StartsWith("main:") ||
+                trimmed.StartsWith("system_info:") ||
+                string.IsNullOrWhiteSpace(trimmed));
+        }
     }
 }
@@ -1,23 +1,46 @@
+using EliteWhisper.Models;
 using NAudio.Wave;
 using System;
+using System.Collections.Generic;
 using System.IO;
+using System.Linq;
 using System.Timers;
 
 namespace EliteWhisper.Services
 {
     public class AudioCaptureService : IDisposable
     {
+        private readonly WhisperConfigurationService _configService;
         private WaveInEvent? _waveIn;
         private WaveFileWriter? _writer;
         private string? _currentFilePath;
         private readonly object _lockObject = new object();
         private bool _isRecording;
+        private bool _isMonitoring;
         private System.Timers.Timer? _maxDurationTimer;
 
+        // Continuous silence at the end of the recording so far, for the auto-stop
+        private int _trailingSilenceMs;
+        private bool _silenceStopRequested;
+
         // Configuration
         public int MaxRecordingDurationSeconds { get; set; } = 300; // 5 minutes max
         public int MinRecordingDurationMs { get; set; } = 500; // Minimum 0.5 seconds
-        public int DeviceNumber { get; set; } = 0; // Default to 0
+        public int DeviceNumber { get; private set; } = 0; // Default to 0
+
+        // Selected device that was missing at the last start, so the fallback is only reported once
+        private string? _missingDeviceName;
+
+        // Pre-roll: the mic keeps running between recordings and the last few hundred ms are kept in
+        // memory, so a word spoken just before the shortcut isn't lost. Overwritten continuously and
+        // only ever written to disk as the start of a recording.
+        private WaveInEvent? _preRollWaveIn;
+        private int _preRollDevice = -1;
+        private byte[]? _preRollBuffer;
+        private int _preRollStart;
+        private int _preRollCount;
+        private readonly object _bufferLock = new object();
+        private const int BYTES_PER_MS = 32; // 16kHz, 16-bit, mono
 
         // Events
         public event EventHandler<float>? AudioLevelUpdated;
@@ -25,6 +48,151 @@ namespace EliteWhisper.Services
         public event EventHandler<Exception>? RecordingFailed;
         public event EventHandler? MaxDurationReached;
 
+        /// <summary>
+        /// Raised when a toggle-mode recording is stopped automatically after the configured stretch of silence.
+        /// RecordingEncoded follows as usual.
+        /// </summary>
+        public event EventHandler? SilenceTimeoutReached;
+
+        /// <summary>
+        /// Raised when the microphone disappears mid-recording (unplugged, driver reset) but audio was
+        /// already captured. RecordingEncoded follows with the partial recording.
+        /// </summary>
+        public event EventHandler<string>? DeviceLost;
+
+        /// <summary>
+        /// Raised with the device name when the microphone in use changes, either because the user
+        /// picked another one or because the selected one is gone and the default is used instead.
+        /// </summary>
+        public event EventHandler<string>? InputDeviceChanged;
+
+        /// <summary>
+        /// Raised with each recorded chunk as 16kHz mono float samples, for live transcription.
+        /// Raised on the capture thread while recording (not while only monitoring).
+        /// </summary>
+        public event EventHandler<float[]>? SamplesCaptured;
+
+        public AudioCaptureService(WhisperConfigurationService configService)
+        {
+            _configService = configService;
+            StartPreRoll();
+        }
+
+        /// <summary>
+        /// Milliseconds of pre-shortcut audio added to recordings. 0 = off.
+        /// </summary>
+        public int PreRollMs => _configService.CurrentConfiguration.PreRollMs;
+
+        /// <summary>
+        /// Set how much audio from before the shortcut is added to recordings (0 = off) and persist it.
+        /// Anything above 0 keeps the microphone open while idle.
+        /// </summary>
+        public void SetPreRoll(int milliseconds)
+        {
+            _configService.SetPreRoll(milliseconds);
+            lock (_lockObject)
+            {
+                StopPreRoll();
+                if (!_isRecording && _waveIn == null) StartPreRoll();
+            }
+        }
+
+        // Caller holds _lockObject, or runs before the service is shared
+        private void StartPreRoll()
+        {
+            int preRollMs = _configService.CurrentConfiguration.PreRollMs;
+            if (preRollMs <= 0 || _preRollWaveIn != null || !IsMicrophoneAvailable()) return;
+
+            try
+            {
+                SelectDevice();
+                lock (_bufferLock)
+                {
+                    _preRollBuffer = new byte[preRollMs * BYTES_PER_MS];
+                    _preRollStart = 0;
+                    _preRollCount = 0;
+                }
+
+                _preRollWaveIn = new WaveInEvent
+                {
+                    DeviceNumber = DeviceNumber,
+                    WaveFormat = new WaveFormat(16000, 16, 1),
+                    BufferMilliseconds = 20
+                };
+                _preRollWaveIn.DataAvailable += OnDataAvailable;
+                _preRollWaveIn.RecordingStopped += OnPreRollStopped;
+                _preRollDevice = DeviceNumber;
+                _preRollWaveIn.StartRecording();
+            }
+            catch (Exception ex)
+            {
+                System.Diagnostics.Debug.WriteLine($"Failed to start pre-roll: {ex.Message}");
+                StopPreRoll();
+            }
+        }
+
+        // Caller holds _lockObject
+        private void StopPreRoll()
+        {
+            if (_preRollWaveIn != null)
+            {
+                _preRollWaveIn.DataAvailable -= OnDataAvailable;
+                _preRollWaveIn.RecordingStopped -= OnPreRollStopped;
+                try { _preRollWaveIn.StopRecording(); } catch { }
+                _preRollWaveIn.Dispose();
+                _preRollWaveIn = null;
+            }
+            _preRollDevice = -1;
+
+            lock (_bufferLock)
+            {
+                if (_preRollBuffer != null) Array.Clear(_preRollBuffer);
+                _preRollBuffer = null;
+                _preRollCount = 0;
+            }
+        }
+
+        private void OnPreRollStopped(object? sender, StoppedEventArgs e)
+        {
+            // Device unplugged while idle; the next recording picks a device again
+            if (e.Exception == null) return;
+            System.Diagnostics.Debug.WriteLine($"Pre-roll stopped: {e.Exception.Message}");
+            lock (_lockObject)
+            {
+                if (sender == _preRollWaveIn) StopPreRoll();
+            }
+        }
+
+        // Caller holds _bufferLock
+        private void AppendPreRoll(byte[] data, int count)
+        {
+            var buffer = _preRollBuffer!;
+            for (int i = 0; i < count; i++)
+            {
+                buffer[(_preRollStart + _preRollCount) % buffer.Length] = data[i];
+                if (_preRollCount < buffer.Length) _preRollCount++;
+                else _preRollStart = (_preRollStart + 1) % buffer.Length;
+            }
+        }
+
+        // Caller holds _bufferLock. Returns the buffered audio in order and clears the buffer.
+        private byte[] TakePreRoll()
+        {
+            var buffer = _preRollBuffer;
+            if (buffer == null || _preRollCount == 0) return Array.Empty<byte>();
+
+            var result = new byte[_preRollCount - _preRollCount % 2];
+            for (int i = 0; i < result.Length; i++)
+            {
+                result[i] = buffer[(_preRollStart + i) % buffer.Length];
+            }
+
+            Array.Clear(buffer);
+            _preRollStart = 0;
+            _preRollCount = 0;
+            return result;
+        }
+
         public bool IsRecording => _isRecording;
         public DateTime? RecordingStartTime { get; private set; }
 
@@ -57,21 +225,69 @@ namespace EliteWhisper.Services
             }
         }
 
+
+        /// <summary>
+        /// Get list of available microphones
+        /// </summary>
+        public static string[] GetAvailableMicrophones()
+        {
+            var result = new string[WaveIn.DeviceCount];
+            for (int i = 0; i < WaveIn.DeviceCount; i++)
+            {
+                var caps = WaveIn.GetCapabilities(i);
+                result[i] = caps.ProductName;
+            }
+            return result;
+        }
+
+        /// <summary>
+        /// Available microphones in device order, with the Windows default flagged.
+        /// </summary>
+        public static List<InputDeviceInfo> GetInputDevices()
+        {
+            string? defaultName = null;
+            try
+            {
+                using var enumerator = new NAudio.CoreAudioApi.MMDeviceEnumerator();
+                if (enumerator.HasDefaultAudioEndpoint(NAudio.CoreAudioApi.DataFlow.Capture, NAudio.CoreAudioApi.Role.Console))
+                {
+                    defaultName = enumerator.GetDefaultAudioEndpoint(NAudio.CoreAudioApi.DataFlow.Capture, NAudio.CoreAudioApi.Role.Console).FriendlyName;
+                }
+            }
+            catch (Exception ex)
+            {
+                System.Diagnostics.Debug.WriteLine($"Failed to query default microphone: {ex.Message}");
+            }
+
+            var names = GetAvailableMicrophones();
+            bool defaultFound = false;
+            var devices = new List<InputDeviceInfo>();
+            foreach (var name in names)
+            {
+                // WaveIn names are cut to 31 characters, so compare against the start of the full name
+                bool isDefault = !defaultFound && defaultName != null && name.Length > 0 &&
+                    defaultName.StartsWith(name, StringComparison.OrdinalIgnoreCase);
+                defaultFound |= isDefault;
+                devices.Add(new InputDeviceInfo { Name = name, IsDefault = isDefault });
+            }
+
+            // Device 0 is what recording used before a device could be chosen
+            if (!defaultFound && devices.Count > 0) devices[0].IsDefault = true;
+            return devices;
+        }
+
         /// <summary>
-        /// Get the name of the default microphone (Device 0)
+        /// Name of the microphone recordings will use: the selected one if it's connected, otherwise the default.
         /// </summary>
-        public string DefaultMicName 
+        public string ActiveDeviceName
         {
             get
             {
                 try
                 {
-                    if (WaveIn.DeviceCount > 0)
-                    {
-                        var caps = WaveIn.GetCapabilities(DeviceNumber);
-                        return caps.ProductName;
-                    }
-                    return "No Microphone Found";
+                    var devices = GetInputDevices();
+                    if (devices.Count == 0) return "No Microphone Found";
+                    return devices[ResolveDeviceNumber(devices)].Name;
                 }
                 catch
                 {
@@ -81,17 +297,59 @@ namespace EliteWhisper.Services
         }
 
         /// <summary>
-        /// Get list of available microphones
+        /// Select the microphone by name (null = Windows default) and persist the choice.
+        /// Takes effect on the next recording or monitoring session.
         /// </summary>
-        public static string[] GetAvailableMicrophones()
+        public void SetInputDevice(string? deviceName)
         {
-            var result = new string[WaveIn.DeviceCount];
-            for (int i = 0; i < WaveIn.DeviceCount; i++)
+            _configService.SetInputDevice(deviceName);
+            _missingDeviceName = null;
+
+            lock (_lockObject)
             {
-                var caps = WaveIn.GetCapabilities(i);
-                result[i] = caps.ProductName;
+                if (_preRollWaveIn != null)
+                {
+                    StopPreRoll();
+                    StartPreRoll();
+                }
+            }
+            InputDeviceChanged?.Invoke(this, ActiveDeviceName);
+        }
+
+        // Index of the selected device, falling back to the default when it has been unplugged
+        private int ResolveDeviceNumber(List<InputDeviceInfo> devices)
+        {
+            string? selected = _configService.CurrentConfiguration.InputDeviceName;
+            int defaultIndex = Math.Max(0, devices.FindIndex(d => d.IsDefault));
+            if (string.IsNullOrEmpty(selected)) return defaultIndex;
+
+            int index = devices.FindIndex(d => string.Equals(d.Name, selected, StringComparison.OrdinalIgnoreCase));
+            return index >= 0 ? index : defaultIndex;
+        }
+
+        private void SelectDevice()
+        {
+            var devices = GetInputDevices();
+            if (devices.Count == 0) return;
+
+            DeviceNumber = ResolveDeviceNumber(devices);
+
+            string? selected = _configService.CurrentConfiguration.InputDeviceName;
+            bool missing = !string.IsNullOrEmpty(selected) &&
+                !devices.Any(d => string.Equals(d.Name, selected, StringComparison.OrdinalIgnoreCase));
+
+            if (missing && _missingDeviceName != selected)
+            {
+                _missingDeviceName = selected;
+                System.Diagnostics.Debug.WriteLine($"Microphone '{selected}' not found, using {devices[DeviceNumber].Name}");
+                InputDeviceChanged?.Invoke(this, devices[DeviceNumber].Name);
+            }
+            else if (!missing && _missingDeviceName != null)
+            {
+                // The selected device is back
+                _missingDeviceName = null;
+                InputDeviceChanged?.Invoke(this, devices[DeviceNumber].Name);
             }
-            return result;
         }
 
         public void StartRecording(string filePath)
@@ -111,20 +369,65 @@ namespace EliteWhisper.Services
                 {
                     _currentFilePath = filePath;
                     
+                    // A running level meter shares its capture with the recording instead of competing for the device
+                    WaveInEvent? monitorWaveIn = null;
+                    if (_isMonitoring && _waveIn != null)
+                    {
+                        monitorWaveIn = _waveIn;
+                        _waveIn = null;
+                    }
+
                     // Cleanup previous instance if dirty
                     CleanupResources();
+                    SelectDevice();
 
-                    _waveIn = new WaveInEvent
+                    bool adoptMonitor = monitorWaveIn != null && monitorWaveIn.DeviceNumber == DeviceNumber;
+                    if (monitorWaveIn != null && !adoptMonitor)
                     {
-                        DeviceNumber = DeviceNumber,
-                        WaveFormat = new WaveFormat(16000, 16, 1), // Whisper standard: 16kHz, 16-bit, Mono
-                        BufferMilliseconds = 20
-                    };
+                        monitorWaveIn.DataAvailable -= OnDataAvailable;
+                        try { monitorWaveIn.StopRecording(); } catch { }
+                        monitorWaveIn.Dispose();
+                    }
+
+                    // Keep the running pre-roll capture so nothing is lost between the buffer and the recording
+                    bool adoptPreRoll = !adoptMonitor && _preRollWaveIn != null && _preRollDevice == DeviceNumber;
+                    if (adoptMonitor)
+                    {
+                        _waveIn = monitorWaveIn!;
+                    }
+                    else if (adoptPreRoll)
+                    {
+                        _waveIn = _preRollWaveIn!;
+                        _waveIn.RecordingStopped -= OnPreRollStopped;
+                        _preRollWaveIn = null;
+                        _preRollDevice = -1;
+                    }
+                    else
+                    {
+                        StopPreRoll();
+                        _waveIn = new WaveInEvent
+                        {
+                            DeviceNumber = DeviceNumber,
+                            WaveFormat = new WaveFormat(16000, 16, 1), // Whisper standard: 16kHz, 16-bit, Mono
+                            BufferMilliseconds = 20
+                        };
+                        _waveIn.DataAvailable += OnDataAvailable;
+                    }
 
-                    _waveIn.DataAvailable += OnDataAvailable;
                     _waveIn.RecordingStopped += OnRecordingStopped;
 
-                    _writer = new WaveFileWriter(filePath, _waveIn.WaveFormat);
+                    byte[] preRoll;
+                    lock (_bufferLock)
+                    {
+                        _writer = new WaveFileWriter(filePath, _waveIn.WaveFormat);
+                        preRoll = TakePreRoll();
+                        _writer.Write(preRoll, 0, preRoll.Length);
+                        _preRollBuffer = null;
+                    }
+                    if (preRoll.Length > 0)
+                    {
+                        SamplesCaptured?.Invoke(this, ToFloatSamples(preRoll, preRoll.Length));
+                    }
 
                     // Start max duration timer
                     _maxDurationTimer = new System.Timers.Timer(MaxRecordingDurationSeconds * 1000);
@@ -132,7 +435,9 @@ namespace EliteWhisper.Services
                     _maxDurationTimer.AutoReset = false;
                     _maxDurationTimer.Start();
 
-                    _waveIn.StartRecording();
+                    if (!adoptPreRoll && !adoptMonitor) _waveIn.StartRecording();
+                    _trailingSilenceMs = 0;
+                    _silenceStopRequested = false;
                     _isRecording = true;
                     RecordingStartTime = DateTime.Now;
                 }
@@ -149,35 +454,26 @@ namespace EliteWhisper.Services
             }
         }
 
+        /// <summary>
+        /// True while the level meter is on, including during a recording that took over its capture.
+        /// </summary>
+        public bool IsMonitoring => _isMonitoring;
+
+        /// <summary>
+        /// Opens the selected device only to report levels through <see cref="AudioLevelUpdated"/>; nothing is buffered.
+        /// A recording started meanwhile takes over the capture and hands it back when it ends.
+        /// </summary>
         public void StartMonitoring()
         {
             lock (_lockObject)
             {
-                if (_isRecording || _waveIn != null) return;
-
-                if (!IsMicrophoneAvailable()) return;
+                if (_isMonitoring) return;
+                _isMonitoring = true;
 
-                try
-                {
-                    _waveIn = new WaveInEvent
-                    {
-                        DeviceNumber = DeviceNumber,
-                        WaveFormat = new WaveFormat(16000, 16, 1),
-                        BufferMilliseconds = 20
-                    };
+                // A running recording already reports levels; monitoring continues on its capture
+                if (_isRecording) return;
 
-                    _waveIn.DataAvailable += OnDataAvailable;
-                    // No writer, no timer, just monitoring
-                    
-                    _waveIn.StartRecording();
-                    // We don't set _isRecording = true because that implies saving to file in other logic
-                    // But we might need a flag _isMonitoring to distinguish? 
-                    // For now, let's rely on _writer being null to know we are not saving.
-                }
-                catch (Exception ex)
-                {
-                    System.Diagnostics.Debug.WriteLine($"Failed to start monitoring: {ex.Message}");
-                }
+                StartMonitoringCapture();
             }
         }
 
@@ -185,7 +481,10 @@ namespace EliteWhisper.Services
         {
             lock (_lockObject)
             {
-                if (_isRecording) return; // Don't interrupt actual recording
+                if (!_isMonitoring) return;
+                _isMonitoring = false;
+
+                if (_isRecording) return; // Don't interrupt actual recording; the pre-roll resumes after it
                 
                 if (_waveIn != null)
                 {
@@ -196,9 +495,48 @@ namespace EliteWhisper.Services
                     catch { }
                     CleanupResources();
                 }
+
+                StartPreRoll();
             }
         }
 
+        // Caller holds _lockObject
+        private void StartMonitoringCapture()
+        {
+            if (_waveIn != null || !IsMicrophoneAvailable()) return;
+
+            try
+            {
+                // Monitoring has its own capture; the pre-roll resumes when it stops
+                StopPreRoll();
+                SelectDevice();
+                _waveIn = new WaveInEvent
+                {
+                    DeviceNumber = DeviceNumber,
+                    WaveFormat = new WaveFormat(16000, 16, 1),
+                    BufferMilliseconds = 20
+                };
+
+                // No writer, no timer, just levels
+                _waveIn.DataAvailable += OnDataAvailable;
+                _waveIn.StartRecording();
+            }
+            catch (Exception ex)
+            {
+                System.Diagnostics.Debug.WriteLine($"Failed to start monitoring: {ex.Message}");
+                CleanupResources();
+            }
+        }
+
+        /// <summary>
+        /// Converts a 0-1 peak level to dBFS, floored at <paramref name="floorDb"/> for silence.
+        /// </summary>
+        public static double ToDecibels(float level, double floorDb = -60)
+        {
+            if (level <= 0) return floorDb;
+            return Math.Max(floorDb, 20 * Math.Log10(level));
+        }
+
         private void OnMaxDurationReached(object? sender, ElapsedEventArgs e)
         {
             MaxDurationReached?.Invoke(this, EventArgs.Empty);
@@ -237,8 +575,17 @@ namespace EliteWhisper.Services
 
         private void OnDataAvailable(object? sender, WaveInEventArgs e)
         {
-             // Write to file
-            _writer?.Write(e.Buffer, 0, e.BytesRecorded);
+            bool recording;
+            lock (_bufferLock)
+            {
+                // Write to file, or keep the latest audio for the next recording's pre-roll
+                _writer?.Write(e.Buffer, 0, e.BytesRecorded);
+                recording = _writer != null;
+                if (!recording && _preRollBuffer != null) AppendPreRoll(e.Buffer, e.BytesRecorded);
+            }
+
+            // The idle pre-roll capture is silent to the rest of the app
+            if (!recording && sender == _preRollWaveIn) return;
 
             // Calculate peak level for visualization
             // 16-bit PCM = 2 bytes per sample
@@ -252,6 +599,51 @@ namespace EliteWhisper.Services
             }
 
             AudioLevelUpdated?.Invoke(this, max);
+
+            if (recording) CheckSilenceTimeout(e.Buffer, e.BytesRecorded);
+
+            var samplesCaptured = SamplesCaptured;
+            if (samplesCaptured != null && recording)
+            {
+                samplesCaptured(this, ToFloatSamples(e.Buffer, e.BytesRecorded));
+            }
+        }
+
+        // Capture thread. Stops a toggle-mode recording once it has been quiet for the configured time.
+        private void CheckSilenceTimeout(byte[] buffer, int bytes)
+        {
+            var config = _configService.CurrentConfiguration;
+            if (!config.AutoStopOnSilence || config.HotkeyMode != HotkeyMode.Toggle || _silenceStopRequested || bytes < 2) return;
+
+            // Same energy gate as voice activity detection
+            double sum = 0;
+            for (int i = 0; i + 1 < bytes; i += 2)
+            {
+                float sample = (short)((buffer[i + 1] << 8) | buffer[i]) / 32768f;
+                sum += sample * sample;
+            }
+            double rms = Math.Sqrt(sum / (bytes / 2));
+            double threshold = Math.Pow(10, config.VoiceActivityThresholdDb / 20.0);
+
+            _trailingSilenceMs = rms < threshold ? _trailingSilenceMs + bytes / BYTES_PER_MS : 0;
+            if (_trailingSilenceMs < config.AutoStopSilenceSeconds * 1000) return;
+
+            _silenceStopRequested = true;
+            System.Diagnostics.Debug.WriteLine($"Auto-stopping after {_trailingSilenceMs}ms of silence");
+            SilenceTimeoutReached?.Invoke(this, EventArgs.Empty);
+
+            // Not from inside the capture callback
+            Task.Run(StopRecording);
+        }
+
+        private static float[] ToFloatSamples(byte[] buffer, int bytes)
+        {
+            var samples = new float[bytes / 2];
+            for (int i = 0; i < samples.Length; i++)
+            {
+                samples[i] = BitConverter.ToInt16(buffer, i * 2) / 32768f;
+            }
+            return samples;
         }
 
         private void OnRecordingStopped(object? sender, StoppedEventArgs e)
@@ -263,9 +655,21 @@ namespace EliteWhisper.Services
                 _isRecording = false;
                 filePath = _currentFilePath;
                 CleanupResources();
+
+                // Hand the device back to the level meter if it was on when the recording started
+                if (_isMonitoring) StartMonitoringCapture();
+                else StartPreRoll();
             }
 
-            if (e.Exception != null)
+            // An unplugged device stops the recording with an error; keep what was captured up to then
+            bool hasAudio = filePath != null && File.Exists(filePath) && new FileInfo(filePath).Length >= 1000;
+            if (e.Exception != null && hasAudio)
+            {
+                System.Diagnostics.Debug.WriteLine($"Microphone lost mid-recording: {e.Exception.Message}");
+                DeviceLost?.Invoke(this, e.Exception.Message);
+                RecordingEncoded?.Invoke(this, filePath!);
+            }
+            else if (e.Exception != null)
             {
                 RecordingFailed?.Invoke(this, e.Exception);
             }
@@ -287,8 +691,11 @@ namespace EliteWhisper.Services
 
         private void CleanupResources()
         {
-            _writer?.Dispose();
-            _writer = null;
+            lock (_bufferLock)
+            {
+                _writer?.Dispose();
+                _writer = null;
+            }
 
             if (_waveIn != null)
             {
@@ -306,6 +713,10 @@ namespace EliteWhisper.Services
         public void Dispose()
         {
             StopRecording();
+            lock (_lockObject)
+            {
+                StopPreRoll();
+            }
         }
     }
 }
@@ -13,6 +13,19 @@ namespace EliteWhisper.Services
 
         public bool IsPlaying => _waveOut?.PlaybackState == PlaybackState.Playing;
 
+        /// <summary>
+        /// How much of the current file has actually been heard (not just buffered). Zero when nothing is playing.
+        /// </summary>
+        public TimeSpan Position
+        {
+            get
+            {
+                var waveOut = _waveOut;
+                if (waveOut == null) return TimeSpan.Zero;
+                return TimeSpan.FromSeconds((double)waveOut.GetPosition() / waveOut.OutputWaveFormat.AverageBytesPerSecond);
+            }
+        }
+
         public void Play(string filePath)
         {
             Stop(); // Stop any current playback
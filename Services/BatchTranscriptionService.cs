@@ -0,0 +1,145 @@
+using System;
+using System.Collections.Generic;
+using System.IO;
+using System.Linq;
+using EliteWhisper.Models;
+using EliteWhisper.Services.Speech;
+
+namespace EliteWhisper.Services
+{
+    /// <summary>
+    /// Transcribes every audio file in a folder through the transcription queue and saves each
+    /// transcript to history, titled with the source file name.
+    /// </summary>
+    public class BatchTranscriptionService
+    {
+        private readonly TranscriptionQueueService _queue;
+        private readonly HistoryService _historyService;
+        private readonly Dictionary<Guid, BatchTranscriptionStatus> _jobBatches = new();
+        private readonly object _lock = new object();
+
+        // Formats the speech pipeline can decode (WAV/MP3 directly, the rest via Media Foundation)
+        public static readonly string[] SupportedExtensions = { ".wav", ".mp3", ".flac", ".m4a", ".aac", ".wma" };
+
+        /// <summary>
+        /// Raised on a background thread whenever a file of a batch progresses or finishes.
+        /// </summary>
+        public event EventHandler<BatchTranscriptionStatus>? BatchProgress;
+
+        /// <summary>
+        /// Raised on a background thread once every file of a batch has been processed.
+        /// </summary>
+        public event EventHandler<BatchTranscriptionStatus>? BatchFinished;
+
+        public BatchTranscriptionService(TranscriptionQueueService queue, HistoryService historyService)
+        {
+            _queue = queue;
+            _historyService = historyService;
+            _queue.JobProgress += OnJobProgress;
+            _queue.JobFinished += OnJobFinished;
+        }
+
+        /// <summary>
+        /// Queues all supported audio files in the folder, optionally including subfolders.
+        /// Returns immediately; follow the batch through BatchProgress and BatchFinished.
+        /// </summary>
+        public BatchTranscriptionStatus TranscribeFolder(string directory, bool recursive)
+        {
+            if (!Directory.Exists(directory))
+                throw new DirectoryNotFoundException($"Folder not found: {directory}");
+
+            var files = FindAudioFiles(directory, recursive);
+            var batch = new BatchTranscriptionStatus { Directory = directory, Total = files.Count };
+            System.Diagnostics.Debug.WriteLine($"[Batch] {files.Count} audio file(s) in {directory}");
+
+            // Held while enqueueing so a fast job can't finish before it is mapped to the batch
+            lock (_lock)
+            {
+                foreach (var file in files)
+                {
+                    _jobBatches[_queue.Enqueue(file)] = batch;
+                }
+            }
+
+            if (files.Count == 0) BatchFinished?.Invoke(this, batch);
+            return batch;
+        }
+
+        public static List<string> FindAudioFiles(string directory, bool recursive)
+        {
+            var options = new EnumerationOptions { RecurseSubdirectories = recursive, IgnoreInaccessible = true };
+            return Directory.EnumerateFiles(directory, "*", options)
+                .Where(f => SupportedExtensions.Contains(Path.GetExtension(f), StringComparer.OrdinalIgnoreCase))
+                .OrderBy(f => f, StringComparer.OrdinalIgnoreCase)
+                .ToList();
+        }
+
+        private void OnJobProgress(object? sender, TranscriptionJob job)
+        {
+            BatchTranscriptionStatus? batch;
+            lock (_lock)
+            {
+                if (!_jobBatches.TryGetValue(job.Id, out batch)) return;
+                batch.CurrentFile = job.AudioFilePath;
+                batch.CurrentFilePercent = job.Percent;
+            }
+
+            BatchProgress?.Invoke(this, batch);
+        }
+
+        private void OnJobFinished(object? sender, TranscriptionJob job)
+        {
+            BatchTranscriptionStatus? batch;
+            lock (_lock)
+            {
+                if (!_jobBatches.Remove(job.Id, out batch)) return;
+            }
+
+            string fileName = Path.GetFileName(job.AudioFilePath);
+            string? failure = job.Status switch
+            {
+                TranscriptionJobStatus.Completed when string.IsNullOrWhiteSpace(job.Result?.Text) => "No speech detected",
+                TranscriptionJobStatus.Completed => null,
+                TranscriptionJobStatus.Cancelled => "Cancelled",
+                _ => job.Error ?? "Transcription failed"
+            };
+
+            if (failure == null)
+            {
+                var result = job.Result!;
+                int seconds = (int)Math.Round(result.AudioSeconds);
+                _historyService.AddRecord(new DictationRecord
+                {
+                    Title = fileName,
+                    Content = result.Text,
+                    Timestamp = DateTime.Now,
+                    Duration = TimeSpan.FromSeconds(result.AudioSeconds),
+                    DurationSeconds = Math.Max(1, seconds),
+                    WordCount = result.Text.Split(new[] { ' ', '\r', '\n' }, StringSplitOptions.RemoveEmptyEntries).Length,
+                    ModelUsed = result.EngineName,
+                    AudioFingerprint = result.AudioFingerprint,
+                    QualityScore = result.QualityScore
+                });
+                CommunityToolkit.Mvvm.Messaging.WeakReferenceMessenger.Default.Send(new Messages.RecordAddedMessage());
+            }
+
+            bool finished;
+            lock (_lock)
+            {
+                if (failure == null)
+                    batch.Succeeded++;
+                else
+                    batch.Failures.Add(new BatchFileFailure { FilePath = job.AudioFilePath, Reason = failure });
+
+                batch.CurrentFilePercent = 0;
+                finished = batch.IsFinished;
+            }
+
+            if (failure != null)
+                System.Diagnostics.Debug.WriteLine($"[Batch] Skipped {fileName}: {failure}");
+
+            BatchProgress?.Invoke(this, batch);
+            if (finished) BatchFinished?.Invoke(this, batch);
+        }
+    }
+}
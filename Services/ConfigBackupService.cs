@@ -0,0 +1,173 @@
+using System;
+using System.IO;
+using System.Linq;
+using System.Reflection;
+using System.Text.Json;
+using EliteWhisper.Models;
+using EliteWhisper.Services.Speech;
+
+namespace EliteWhisper.Services
+{
+    /// <summary>
+    /// Exports and imports the user's setup as a single versioned .json file,
+    /// for moving to another machine or sharing with support.
+    /// </summary>
+    public class ConfigBackupService
+    {
+        // 2: adds replacements, profanity words and hallucination filters
+        public const int CURRENT_FORMAT_VERSION = 2;
+
+        private readonly WhisperConfigurationService _configService;
+        private readonly HotwordsService _hotwords;
+        private readonly ReplacementsService _replacements;
+        private readonly ProfanityFilterService _profanityFilter;
+        private readonly HallucinationFilterService _hallucinationFilter;
+
+        public ConfigBackupService(WhisperConfigurationService configService, HotwordsService hotwords,
+            ReplacementsService replacements, ProfanityFilterService profanityFilter, HallucinationFilterService hallucinationFilter)
+        {
+            _configService = configService;
+            _hotwords = hotwords;
+            _replacements = replacements;
+            _profanityFilter = profanityFilter;
+            _hallucinationFilter = hallucinationFilter;
+        }
+
+        public void Export(string destinationPath)
+        {
+            var current = _configService.CurrentConfiguration;
+
+            // Deep copy so stripping paths doesn't touch the live configuration
+            var settings = JsonSerializer.Deserialize<WhisperConfiguration>(JsonSerializer.Serialize(current))!;
+            StripMachineSpecific(settings);
+
+            var bundle = new ConfigBundle
+            {
+                FormatVersion = CURRENT_FORMAT_VERSION,
+                AppVersion = Assembly.GetExecutingAssembly().GetName().Version?.ToString(),
+                ExportedAt = DateTime.Now,
+                Settings = settings,
+                Hotwords = _hotwords.GetHotwords().Select(h => h.ToLine()).ToList(),
+                Replacements = _replacements.GetRules(),
+                ProfanityWords = _profanityFilter.GetCustomWords(),
+                HallucinationFilters = _hallucinationFilter.GetFilters(),
+                DefaultModelFileName = GetModelName(current.DefaultModelPath),
+                FallbackModelFileName = GetModelName(current.FallbackWhisperModelPath),
+                StreamingModelFolderName = GetModelName(current.StreamingModelPath),
+                DiarizationSegmentationModelFileName = GetModelName(current.DiarizationSegmentationModel),
+                DiarizationEmbeddingModelFileName = GetModelName(current.DiarizationEmbeddingModel)
+            };
+
+            var options = new JsonSerializerOptions { WriteIndented = true };
+            File.WriteAllText(destinationPath, JsonSerializer.Serialize(bundle, options));
+        }
+
+        /// <summary>
+        /// Restores settings, hotwords and text rules from a bundle. Local paths, API keys, first-run state, the microphone,
+        /// the widget position and history encryption are kept; model names are matched against the local models folder.
+        /// </summary>
+        public void Import(string sourcePath)
+        {
+            if (!File.Exists(sourcePath))
+                throw new FileNotFoundException("Backup file not found", sourcePath);
+
+            ConfigBundle? bundle;
+            try
+            {
+                bundle = JsonSerializer.Deserialize<ConfigBundle>(File.ReadAllText(sourcePath));
+            }
+            catch (JsonException ex)
+            {
+                throw new InvalidOperationException($"Not a valid Elite Whisper backup: {ex.Message}", ex);
+            }
+
+            if (bundle == null || bundle.FormatVersion < 1)
+                throw new InvalidOperationException("Not a valid Elite Whisper backup.");
+
+            if (bundle.FormatVersion > CURRENT_FORMAT_VERSION)
+                throw new InvalidOperationException($"This backup was made by a newer version of Elite Whisper (format {bundle.FormatVersion}). Please update first.");
+
+            var current = _configService.CurrentConfiguration;
+            var settings = bundle.Settings;
+
+            // Keep everything that belongs to this machine
+            settings.ExecutablePath = current.ExecutablePath;
+            settings.ModelsDirectory = current.ModelsDirectory;
+            settings.LocalModelsPath = current.LocalModelsPath;
+            settings.BaseDirectory = current.BaseDirectory;
+            settings.HistoryStoragePath = current.HistoryStoragePath;
+            settings.TranscriptsDirectory = current.TranscriptsDirectory;
+            settings.GeminiApiKey = current.GeminiApiKey;
+            settings.OpenRouterApiKey = current.OpenRouterApiKey;
+            settings.HasCompletedFirstRun = current.HasCompletedFirstRun;
+            settings.HasOfferedDefaultModel = current.HasOfferedDefaultModel;
+            settings.LastValidated = current.LastValidated;
+            settings.InputDeviceName = current.InputDeviceName;
+            settings.WidgetLeft = current.WidgetLeft;
+            settings.WidgetTop = current.WidgetTop;
+
+            // The passphrase key only exists on this machine; turning encryption on without it would stop
+            // history from being saved, and turning it off would write history.json in the clear
+            settings.EncryptHistory = current.EncryptHistory;
+
+            settings.DefaultModelPath = ResolveLocalModel(bundle.DefaultModelFileName) ?? current.DefaultModelPath;
+            settings.FallbackWhisperModelPath = ResolveLocalModel(bundle.FallbackModelFileName) ?? current.FallbackWhisperModelPath;
+            settings.StreamingModelPath = ResolveLocalModel(bundle.StreamingModelFolderName) ?? current.StreamingModelPath;
+            settings.DiarizationSegmentationModel = ResolveLocalModel(bundle.DiarizationSegmentationModelFileName) ?? current.DiarizationSegmentationModel;
+            settings.DiarizationEmbeddingModel = ResolveLocalModel(bundle.DiarizationEmbeddingModelFileName) ?? current.DiarizationEmbeddingModel;
+
+            _configService.ReplaceConfiguration(settings);
+            _hotwords.SaveHotwords(bundle.Hotwords.Select(Hotword.Parse));
+
+            // Older bundles don't carry these; keep the local files then
+            if (bundle.Replacements != null) _replacements.SaveRules(bundle.Replacements);
+            if (bundle.ProfanityWords != null) _profanityFilter.SaveCustomWords(bundle.ProfanityWords);
+            if (bundle.HallucinationFilters != null) _hallucinationFilter.SaveFilters(bundle.HallucinationFilters);
+        }
+
+        private static void StripMachineSpecific(WhisperConfiguration settings)
+        {
+            settings.ExecutablePath = null;
+            settings.ModelsDirectory = null;
+            settings.LocalModelsPath = null;
+            settings.DefaultModelPath = null;
+            settings.BaseDirectory = null;
+            settings.HistoryStoragePath = null;
+            settings.TranscriptsDirectory = null;
+            settings.FallbackWhisperModelPath = null;
+            settings.StreamingModelPath = null;
+            settings.DiarizationSegmentationModel = null;
+            settings.DiarizationEmbeddingModel = null;
+            settings.LastValidated = null;
+            settings.EncryptHistory = false;
+
+            // Microphones are named after this machine's hardware, and the widget position
+            // only makes sense on its monitor layout
+            settings.InputDeviceName = null;
+            settings.WidgetLeft = null;
+            settings.WidgetTop = null;
+
+            // DPAPI-encrypted for this Windows account, useless elsewhere
+            settings.GeminiApiKey = null;
+            settings.OpenRouterApiKey = null;
+        }
+
+        // Whisper models are files, Sherpa models are folders; both are stored by name only
+        private static string? GetModelName(string? modelPath)
+        {
+            if (string.IsNullOrEmpty(modelPath)) return null;
+            if (!File.Exists(modelPath) && !Directory.Exists(modelPath)) return null;
+
+            return Path.GetFileName(modelPath.TrimEnd(Path.DirectorySeparatorChar, Path.AltDirectorySeparatorChar));
+        }
+
+        private string? ResolveLocalModel(string? modelName)
+        {
+            string? modelsDir = _configService.CurrentConfiguration.ModelsDirectory;
+            if (string.IsNullOrEmpty(modelName) || string.IsNullOrEmpty(modelsDir)) return null;
+
+            string path = Path.Combine(modelsDir, Path.GetFileName(modelName));
+            return File.Exists(path) || Directory.Exists(path) ? path : null;
+        }
+    }
+}
@@ -0,0 +1,103 @@
+using System;
+using System.IO;
+using System.Linq;
+using System.Threading;
+using System.Threading.Tasks;
+
+namespace EliteWhisper.Services
+{
+    /// <summary>
+    /// First-run helper: offers a small Whisper model when the models directory is empty,
+    /// so new users don't start with "No model loaded".
+    /// </summary>
+    public class DefaultModelSetupService
+    {
+        private readonly WhisperConfigurationService _configService;
+        private readonly ModelRegistryService _registryService;
+        private readonly ModelDownloadService _downloadService;
+        private readonly AIEngineService _aiEngine;
+
+        public DefaultModelSetupService(
+            WhisperConfigurationService configService,
+            ModelRegistryService registryService,
+            ModelDownloadService downloadService,
+            AIEngineService aiEngine)
+        {
+            _configService = configService;
+            _registryService = registryService;
+            _downloadService = downloadService;
+            _aiEngine = aiEngine;
+        }
+
+        /// <summary>
+        /// True when the default model has never been offered and no speech model is installed.
+        /// </summary>
+        public bool ShouldOfferDefaultModel()
+        {
+            var config = _configService.CurrentConfiguration;
+            if (config.HasOfferedDefaultModel || string.IsNullOrEmpty(config.DefaultDownloadModelId)) return false;
+            if (_registryService.GetModelById(config.DefaultDownloadModelId) == null) return false;
+
+            string modelsDir = GetModelsDirectory();
+            if (!Directory.Exists(modelsDir)) return true;
+
+            bool hasWhisperModel = Directory.EnumerateFiles(modelsDir, "ggml-*.bin").Any();
+            bool hasSherpaModel = Directory.EnumerateDirectories(modelsDir, "sherpa-onnx*").Any();
+            return !hasWhisperModel && !hasSherpaModel;
+        }
+
+        /// <summary>
+        /// Records that the user has been asked, so the prompt only appears once.
+        /// </summary>
+        public void MarkOffered()
+        {
+            var config = _configService.CurrentConfiguration;
+            config.HasOfferedDefaultModel = true;
+            _configService.SaveConfiguration(config);
+        }
+
+        /// <summary>
+        /// Display name and size of the model that would be downloaded.
+        /// </summary>
+        public (string Name, int SizeMB)? GetDefaultModelInfo()
+        {
+            var entry = _registryService.GetModelById(_configService.CurrentConfiguration.DefaultDownloadModelId ?? string.Empty);
+            return entry == null ? null : (entry.Name, entry.SizeMB);
+        }
+
+        /// <summary>
+        /// Downloads the default model into the models directory and makes it active.
+        /// Returns the installed model path.
+        /// </summary>
+        public async Task<string> InstallDefaultModelAsync(IProgress<Models.DownloadProgress> progress, CancellationToken cancellationToken)
+        {
+            var config = _configService.CurrentConfiguration;
+            var entry = _registryService.GetModelById(config.DefaultDownloadModelId ?? string.Empty)
+                ?? throw new InvalidOperationException($"Default model '{config.DefaultDownloadModelId}' is not in the model registry.");
+
+            if (entry.EngineType == "sherpa")
+                throw new InvalidOperationException("The default model must be a Whisper model.");
+
+            string targetPath = Path.Combine(GetModelsDirectory(), entry.Filename);
+
+            // An interrupted download resumes from its .partial file on the next attempt
+            await _downloadService.DownloadModelAsync(entry.DownloadUrl, targetPath, progress, cancellationToken);
+
+            System.Diagnostics.Debug.WriteLine($"[Setup] Default model downloaded to {targetPath}");
+
+            // Activation needs whisper-cli; without it just remember the model for later
+            if (!await _aiEngine.ActivateModelAsync(targetPath))
+            {
+                _configService.SetDefaultModel(targetPath);
+            }
+
+            return targetPath;
+        }
+
+        private string GetModelsDirectory()
+        {
+            var config = _configService.CurrentConfiguration;
+            return config.ModelsDirectory ?? Path.Combine(config.BaseDirectory ?? AppDomain.CurrentDomain.BaseDirectory, "models");
+        }
+    }
+}
@@ -1,5 +1,7 @@
 using System;
+using System.Collections.Generic;
 using System.IO;
+using System.Linq;
 using System.Threading;
 using System.Threading.Tasks;
 using System.Windows;
@@ -32,9 +34,25 @@ namespace EliteWhisper.Services
         private const int MAX_RETRIES = 2;
         private TimeSpan _recordingDuration = TimeSpan.Zero;
         private DateTime _recordingStartTime;
+        private string _targetApplication = UNKNOWN_APP;
+        private const string UNKNOWN_APP = "Unknown";
+        private Speech.SherpaStreamingEngine? _streamingEngine;
+        private const int MAX_PREVIEW = 60;
+        private Guid _currentJobId;
+        private bool _showingSegmentPreview;
 
         public RecordingSource CurrentSource { get; private set; } = RecordingSource.None;
 
+        /// <summary>
+        /// Live text of the utterance being spoken, in streaming mode. Raised on a background thread.
+        /// </summary>
+        public event EventHandler<string>? LivePartialTranscript;
+
+        /// <summary>
+        /// An utterance closed by endpoint detection, in streaming mode. Raised on a background thread.
+        /// </summary>
+        public event EventHandler<string>? LiveFinalTranscript;
+
         public DictationService(
             AudioCaptureService audioService,
             TextInjectionService injectionService,
@@ -57,6 +75,9 @@ namespace EliteWhisper.Services
             _audioService.RecordingEncoded += OnRecordingComplete;
             _audioService.RecordingFailed += OnRecordingFailed;
             _audioService.MaxDurationReached += OnMaxDurationReached;
+            _audioService.SilenceTimeoutReached += OnSilenceTimeoutReached;
+            _audioService.DeviceLost += OnDeviceLost;
+            _aiEngine.SegmentTranscribed += OnSegmentTranscribed;
         }
 
         /// <summary>
@@ -91,8 +112,15 @@ namespace EliteWhisper.Services
             // Check if any speech engine is configured
             var engineSelector = (Application.Current as App)?.Services.GetService(typeof(Speech.SpeechEngineSelector)) as Speech.SpeechEngineSelector;
             bool engineConfigured = false;
+
+            // Streaming mode decodes while recording instead of transcribing the file afterwards
+            _streamingEngine = engineSelector?.GetStreamingEngine();
             
-            if (engineSelector != null)
+            if (_streamingEngine != null)
+            {
+                engineConfigured = true;
+            }
+            else if (engineSelector != null)
             {
                 var bestEngine = engineSelector.GetBestEngine();
                 engineConfigured = bestEngine != null && bestEngine.IsAvailable;
@@ -126,9 +154,81 @@ namespace EliteWhisper.Services
             _retryCount = 0;
             
             _recordingStartTime = DateTime.Now;
+            _targetApplication = GetForegroundApplication();
+            if (_streamingEngine != null) StartStreaming(_streamingEngine);
             _audioService.StartRecording(_currentAudioPath);
         }
 
+        private void StartStreaming(Speech.SherpaStreamingEngine engine)
+        {
+            engine.PartialTranscript += OnStreamingPartial;
+            engine.FinalTranscript += OnStreamingFinal;
+            engine.Begin();
+            _audioService.SamplesCaptured += OnSamplesCaptured;
+        }
+
+        /// <summary>
+        /// Ends the streaming session, if any, and returns its transcript. Null when not streaming.
+        /// </summary>
+        private async Task<string?> FinishStreamingAsync()
+        {
+            var engine = _streamingEngine;
+            if (engine == null) return null;
+            _streamingEngine = null;
+
+            _audioService.SamplesCaptured -= OnSamplesCaptured;
+            try
+            {
+                return await engine.FinishAsync();
+            }
+            finally
+            {
+                engine.PartialTranscript -= OnStreamingPartial;
+                engine.FinalTranscript -= OnStreamingFinal;
+            }
+        }
+
+        private void OnSamplesCaptured(object? sender, float[] samples) => _streamingEngine?.AcceptSamples(samples);
+
+        private void OnStreamingPartial(object? sender, string text)
+        {
+            LivePartialTranscript?.Invoke(this, text);
+
+            Application.Current.Dispatcher.BeginInvoke(() =>
+            {
+                if (CurrentSource != RecordingSource.Widget || _widgetViewModel.State != WidgetState.Listening) return;
+                if (text.Length == 0) return;
+                _widgetViewModel.StatusText = text.Length > MAX_PREVIEW ? "…" + text[^MAX_PREVIEW..] : text;
+            });
+        }
+
+        private void OnStreamingFinal(object? sender, string text) => LiveFinalTranscript?.Invoke(this, text);
+
+        /// <summary>
+        /// Process name of the window that has focus (e.g. "WINWORD"), i.e. where the text will go.
+        /// Captured when recording starts because by save time focus may have moved. Falls back to "Unknown".
+        /// </summary>
+        private static string GetForegroundApplication()
+        {
+            try
+            {
+                var handle = EliteWhisper.Native.Win32.GetForegroundWindow();
+                if (handle == IntPtr.Zero) return UNKNOWN_APP;
+
+                EliteWhisper.Native.Win32.GetWindowThreadProcessId(handle, out uint processId);
+                if (processId == 0) return UNKNOWN_APP;
+
+                using var process = System.Diagnostics.Process.GetProcessById((int)processId);
+                return string.IsNullOrEmpty(process.ProcessName) ? UNKNOWN_APP : process.ProcessName;
+            }
+            catch (Exception ex)
+            {
+                // Process may have exited, or be protected
+                System.Diagnostics.Debug.WriteLine($"Failed to detect foreground app: {ex.Message}");
+                return UNKNOWN_APP;
+            }
+        }
+
         /// <summary>
         /// Called when F2 is pressed in Listening state.
         /// Transitions: Listening -> Processing
@@ -166,11 +266,24 @@ namespace EliteWhisper.Services
         }
 
         /// <summary>
-        /// Cancel any ongoing operation
+        /// True from the moment recording stops until the transcript has been delivered.
+        /// </summary>
+        public bool IsTranscribing => _aiEngine.State == EngineState.Processing;
+
+        /// <summary>
+        /// Final text of the last transcription, kept until the next one so it can be re-typed or re-copied
+        /// when it didn't land where intended (e.g. focus moved during the paste).
+        /// </summary>
+        public string? LastTranscript { get; private set; }
+
+        /// <summary>
+        /// Cancel any ongoing operation. A running transcription is aborted (whisper-cli is killed)
+        /// and nothing is typed or saved; the recording's temp file is still cleaned up.
         /// </summary>
         public void Cancel()
         {
             _cts?.Cancel();
+            _ = FinishStreamingAsync();
             _audioService.StopRecording();
             if (CurrentSource == RecordingSource.Widget)
             {
@@ -181,6 +294,116 @@ namespace EliteWhisper.Services
             _aiEngine.SetState(EngineState.Ready);
         }
 
+        /// <summary>
+        /// Transcribes an audio file copied in Explorer, or WAV audio on the clipboard, as if it had just
+        /// been dictated: the transcript is delivered and saved to history. The copied file is left untouched.
+        /// </summary>
+        public async Task TranscribeClipboardAudioAsync()
+        {
+            if (_aiEngine.State == EngineState.Recording || _aiEngine.State == EngineState.Processing) return;
+
+            string? audioFilePath;
+            try
+            {
+                // Transcription deletes its input afterwards, so work on a temp copy
+                audioFilePath = CopyClipboardAudio();
+            }
+            catch (Exception ex)
+            {
+                System.Diagnostics.Debug.WriteLine($"Clipboard audio copy failed: {ex.Message}");
+                audioFilePath = null;
+            }
+
+            if (audioFilePath == null)
+            {
+                _widgetViewModel.StatusText = "No audio on the clipboard";
+                return;
+            }
+
+            CurrentSource = RecordingSource.Widget;
+            _widgetViewModel.State = WidgetState.Processing;
+            _widgetViewModel.StatusText = "Processing...";
+            _aiEngine.SetState(EngineState.Processing);
+
+            _cts = new CancellationTokenSource();
+            _retryCount = 0;
+            _streamingEngine = null;
+            _targetApplication = GetForegroundApplication();
+            _recordingDuration = await Task.Run(() => GetAudioDuration(audioFilePath));
+
+            OnRecordingComplete(this, audioFilePath);
+        }
+
+        /// <summary>
+        /// Inserts the last transcript again with the configured output method, typing or pasting even when
+        /// output is set to clipboard-only or history-only. False if there's nothing to insert or dictation is busy.
+        /// </summary>
+        public async Task<bool> RetypeLastAsync()
+        {
+            string? text = LastTranscript;
+            if (string.IsNullOrEmpty(text) || _aiEngine.State == EngineState.Recording || _aiEngine.State == EngineState.Processing)
+                return false;
+
+            var config = _aiEngine.GetConfiguration();
+            var outputAction = config.OutputAction is OutputAction.Clipboard or OutputAction.None ? OutputAction.Auto : config.OutputAction;
+
+            _injectionService.RestoreClipboard = config.RestoreClipboardAfterPaste;
+            await _injectionService.DeliverTextAsync(text, outputAction);
+            return true;
+        }
+
+        /// <summary>
+        /// Copies the last transcript to the clipboard again. False if there's nothing to copy.
+        /// </summary>
+        public async Task<bool> RecopyLastAsync()
+        {
+            string? text = LastTranscript;
+            if (string.IsNullOrEmpty(text)) return false;
+
+            await _injectionService.DeliverTextAsync(text, OutputAction.Clipboard);
+            return true;
+        }
+
+        private static string? CopyClipboardAudio()
+        {
+            string baseName = Path.Combine(Path.GetTempPath(), $"elitewhisper_{DateTime.Now:yyyyMMdd_HHmmss}");
+
+            if (Clipboard.ContainsFileDropList())
+            {
+                string? source = Clipboard.GetFileDropList().Cast<string>().FirstOrDefault(f =>
+                    BatchTranscriptionService.SupportedExtensions.Contains(Path.GetExtension(f), StringComparer.OrdinalIgnoreCase));
+                if (source == null) return null;
+
+                string copy = baseName + Path.GetExtension(source).ToLowerInvariant();
+                File.Copy(source, copy, overwrite: true);
+                return copy;
+            }
+
+            if (Clipboard.ContainsAudio())
+            {
+                string copy = baseName + ".wav";
+                using var audio = Clipboard.GetAudioStream();
+                using var file = File.Create(copy);
+                audio.CopyTo(file);
+                return copy;
+            }
+
+            return null;
+        }
+
+        private static TimeSpan GetAudioDuration(string audioFilePath)
+        {
+            try
+            {
+                using var reader = new NAudio.Wave.AudioFileReader(audioFilePath);
+                return reader.TotalTime;
+            }
+            catch
+            {
+                return TimeSpan.Zero;
+            }
+        }
+
         private void OnMaxDurationReached(object? sender, EventArgs e)
         {
             // Auto-stop when max duration reached
@@ -190,21 +413,115 @@ namespace EliteWhisper.Services
             });
         }
 
+        private void OnSilenceTimeoutReached(object? sender, EventArgs e)
+        {
+            // The recording stops by itself, so move on as if the user had pressed the shortcut
+            Application.Current.Dispatcher.Invoke(() =>
+            {
+                _recordingDuration = DateTime.Now - _recordingStartTime;
+                _aiEngine.SetState(EngineState.Processing);
+
+                if (CurrentSource == RecordingSource.Widget)
+                {
+                    _widgetViewModel.State = WidgetState.Processing;
+                    _widgetViewModel.StatusText = "Silence - processing...";
+                }
+            });
+        }
+
+        private void OnDeviceLost(object? sender, string reason)
+        {
+            // The partial recording is transcribed next, so move on as if the user had stopped
+            Application.Current.Dispatcher.Invoke(() =>
+            {
+                _recordingDuration = DateTime.Now - _recordingStartTime;
+                _aiEngine.SetState(EngineState.Processing);
+
+                if (CurrentSource == RecordingSource.Widget)
+                {
+                    _widgetViewModel.State = WidgetState.Processing;
+                    _widgetViewModel.StatusText = "Mic disconnected - processing...";
+                }
+            });
+        }
+
+        private void OnSegmentTranscribed(object? sender, PartialTranscript segment)
+        {
+            // Show the latest decoded segment so long recordings don't sit on "Transcribing..."
+            Application.Current.Dispatcher.BeginInvoke(() =>
+            {
+                if (CurrentSource != RecordingSource.Widget || _widgetViewModel.State != WidgetState.Processing) return;
+
+                string text = segment.Text;
+                _widgetViewModel.StatusText = text.Length > MAX_PREVIEW ? "…" + text[^MAX_PREVIEW..] : text;
+                _showingSegmentPreview = true;
+            });
+        }
+
+        private void OnTranscriptionProgress(object? sender, TranscriptionProgress progress)
+        {
+            Application.Current.Dispatcher.BeginInvoke(() =>
+            {
+                // Updates from an earlier or concurrent job are ignored; segment previews take priority
+                if (progress.JobId != _currentJobId || _showingSegmentPreview) return;
+                if (CurrentSource != RecordingSource.Widget || _widgetViewModel.State != WidgetState.Processing) return;
+
+                _widgetViewModel.StatusText = $"Transcribing... {progress.Percent}%";
+            });
+        }
+
         private async void OnRecordingComplete(object? sender, string audioFilePath)
         {
+            bool failed = false;
             try
             {
                 string transcription;
+                string? streamingEngineName = _streamingEngine?.Name;
+                List<WordTiming>? words = null;
+                // Per-run details from the speech pipeline; null for streaming and the plain Whisper path
+                Models.TranscriptionResult? result = null;
                 
                 // Try SpeechRecognitionService first (supports Parakeet + Whisper)
                 var speechService = (Application.Current as App)?.Services.GetService(typeof(Speech.SpeechRecognitionService)) as Speech.SpeechRecognitionService;
-                if (speechService != null)
+                if (streamingEngineName != null)
+                {
+                    // Already decoded while recording; only the last utterance is left
+                    if (CurrentSource == RecordingSource.Widget)
+                    {
+                        _widgetViewModel.StatusText = "Finishing...";
+                    }
+                    transcription = await FinishStreamingAsync() ?? string.Empty;
+
+                    // Streamed text skipped the file pipeline, so give it the same filters and formatting
+                    if (speechService != null)
+                    {
+                        transcription = speechService.FinalizeTranscript(transcription);
+                    }
+                }
+                else if (speechService != null && speechService.IsDiarizationAvailable)
+                {
+                    // Meeting mode: timestamped Whisper segments labeled "Speaker N"
+                    if (CurrentSource == RecordingSource.Widget)
+                    {
+                        _widgetViewModel.StatusText = "Transcribing speakers...";
+                    }
+                    result = await speechService.TranscribeWithSpeakersAsync(audioFilePath, _cts?.Token ?? CancellationToken.None);
+                    transcription = result.Text;
+                    words = result.Segments!.Where(s => s.Words != null).SelectMany(s => s.Words!).ToList();
+                    if (words.Count == 0) words = null;
+                }
+                else if (speechService != null)
                 {
                     if (CurrentSource == RecordingSource.Widget)
                     {
                         _widgetViewModel.StatusText = "Transcribing...";
                     }
-                    transcription = await speechService.TranscribeAsync(audioFilePath, _cts?.Token ?? CancellationToken.None);
+                    _currentJobId = Guid.NewGuid();
+                    _showingSegmentPreview = false;
+                    speechService.ProgressChanged -= OnTranscriptionProgress;
+                    speechService.ProgressChanged += OnTranscriptionProgress;
+                    result = await speechService.TranscribeTimedAsync(audioFilePath, _cts?.Token ?? CancellationToken.None, _currentJobId);
+                    transcription = result.Text;
                 }
                 else if (_aiEngine.IsConfigured())
                 {
@@ -212,10 +529,8 @@ namespace EliteWhisper.Services
                     {
                         _widgetViewModel.StatusText = "Transcribing...";
                     }
-                    transcription = await _aiEngine.TranscribeAsync(
-                        audioFilePath, 
-                        TranscriptionModel.Balanced, 
-                        _cts?.Token ?? CancellationToken.None);
+                    result = await _aiEngine.TranscribeDetailedAsync(audioFilePath, _cts?.Token ?? CancellationToken.None);
+                    transcription = result.Text;
                 }
                 else
                 {
@@ -229,12 +544,33 @@ namespace EliteWhisper.Services
                     // Post-process with LLM if enabled
                     var activeMode = _modeService.ActiveMode;
                     var finalText = await _postProcessingService.ProcessAsync(transcription, activeMode);
+                    LastTranscript = finalText;
                     
+                    var outputAction = _aiEngine.GetConfiguration().OutputAction;
+
+                    // Text the model made up over silence counts as low quality too
+                    double noSpeechThreshold = _aiEngine.GetConfiguration().NoSpeechWarningThreshold;
+                    float? noSpeechProbability = result?.NoSpeechProbability;
+                    bool likelyNoSpeech = noSpeechThreshold > 0 && noSpeechProbability >= noSpeechThreshold;
+
+                    // Don't insert a transcript that looks like garbage; it's still kept in history
+                    bool lowQuality = likelyNoSpeech || result?.IsLowQuality == true;
+                    if (lowQuality && _aiEngine.GetConfiguration().SuppressLowQualityOutput)
+                    {
+                        outputAction = OutputAction.None;
+                    }
+
                     if (CurrentSource == RecordingSource.Widget)
                     {
-                        _widgetViewModel.StatusText = "Typing...";
+                        _widgetViewModel.StatusText = outputAction switch
+                        {
+                            OutputAction.Clipboard => "Copying...",
+                            OutputAction.None => "Saving...",
+                            _ => "Typing..."
+                        };
                     }
-                    await _injectionService.InjectTextAsync(finalText, _cts?.Token ?? CancellationToken.None);
+                    _injectionService.RestoreClipboard = _aiEngine.GetConfiguration().RestoreClipboardAfterPaste;
+                    await _injectionService.DeliverTextAsync(finalText, outputAction, _cts?.Token ?? CancellationToken.None);
                     
                     // Capture metrics
                     int wordCount = finalText.Split(new[] { ' ', '\r', '\n' }, StringSplitOptions.RemoveEmptyEntries).Length;
@@ -244,47 +580,62 @@ namespace EliteWhisper.Services
                     int durationSec = (int)_recordingDuration.TotalSeconds;
                     if (durationSec < 1) durationSec = 1;
 
-                    // Capture Active Window
-                    string activeWindow = "Unknown";
-                    try
-                    {
-                        var handle = EliteWhisper.Native.Win32.GetForegroundWindow();
-                        if (handle != IntPtr.Zero)
-                        {
-                            var sb = new System.Text.StringBuilder(256);
-                            if (EliteWhisper.Native.Win32.GetWindowText(handle, sb, 256) > 0)
-                            {
-                                activeWindow = sb.ToString();
-                            }
-                        }
-                    }
-                    catch { }
+                    // Whisper reports it during the transcription itself; other engines don't detect a language
+                    var language = result?.DetectedLanguage;
 
                     // Save to History (save final text, not raw)
-                    _historyService.AddRecord(new Models.DictationRecord
+                    var record = new Models.DictationRecord
                     {
                         Content = finalText,
                         Timestamp = DateTime.Now,
                         Duration = _recordingDuration,
                         DurationSeconds = durationSec,
                         WordCount = wordCount,
-                        ModelUsed = _aiEngine.GetConfiguration()?.DefaultModelPath ?? "Unknown",
-                        ApplicationName = activeWindow
-                    });
+                        ModelUsed = streamingEngineName ?? (result?.EngineName is { Length: > 0 } engineName ? engineName : null)
+                            ?? _aiEngine.GetConfiguration()?.DefaultModelPath ?? "Unknown",
+                        ApplicationName = _targetApplication,
+                        Words = words,
+                        DetectedLanguage = language?.Language,
+                        LanguageProbability = language?.Probability,
+                        AudioFingerprint = result?.AudioFingerprint,
+                        QualityScore = result?.Segments == null ? result?.QualityScore : null,
+                        NoSpeechProbability = noSpeechProbability,
+                        LikelyNoSpeech = likelyNoSpeech
+                    };
+
+                    // The recording moves into history instead of being deleted below
+                    if (_aiEngine.GetConfiguration().SaveRecordings && File.Exists(audioFilePath))
+                    {
+                        try
+                        {
+                            _historyService.AttachRecording(record, audioFilePath);
+                        }
+                        catch (Exception ex)
+                        {
+                            System.Diagnostics.Debug.WriteLine($"Failed to save recording: {ex.Message}");
+                        }
+                    }
+
+                    _historyService.AddRecord(record);
                     
                     // Notify Dashboard to update (could effectively be done via HistoryService event or Messenger)
                     CommunityToolkit.Mvvm.Messaging.WeakReferenceMessenger.Default.Send(new Messages.RecordAddedMessage());
 
+                    // Same audio as an earlier item, e.g. a recording transcribed twice
+                    bool repeated = record.AudioFingerprint != null && _historyService.FindSimilar(record.Id).Count > 0;
+
                     if (CurrentSource == RecordingSource.Widget)
                     {
-                        _widgetViewModel.StatusText = "Done!";
+                        _widgetViewModel.StatusText = likelyNoSpeech ? "No speech? Check history"
+                            : lowQuality ? "Low quality - please re-record"
+                            : repeated ? "Done! Already transcribed before" : "Done!";
                     }
                 }
                 else
                 {
                     if (CurrentSource == RecordingSource.Widget)
                     {
-                        _widgetViewModel.StatusText = "No speech detected";
+                        _widgetViewModel.StatusText = result?.IsTooShort == true ? "Recording too short" : "No speech detected";
                     }
                 }
                 
@@ -298,23 +649,27 @@ namespace EliteWhisper.Services
                 }
                 await Task.Delay(500);
             }
-            catch (FileNotFoundException ex)
-            {
-                System.Diagnostics.Debug.WriteLine($"AI Engine Error: {ex.Message}");
-                ShowErrorAndReset("Whisper files missing");
-                return;
-            }
-            catch (TimeoutException)
+            catch (Speech.TranscriptionException ex) when (ex.Code == TranscriptionError.Timeout)
             {
+                failed = true;
                 if (CurrentSource == RecordingSource.Widget)
                 {
                     _widgetViewModel.StatusText = "Timeout - try shorter audio";
                 }
                 await Task.Delay(1500);
             }
-            catch (InvalidOperationException ex) when (ex.Message.Contains("not configured"))
+            catch (Speech.TranscriptionException ex) when (ex.Code != TranscriptionError.EngineFailed)
             {
-                ShowErrorAndReset("Configure Whisper first (S)");
+                // Engine failures fall through to the retry below; these won't fix themselves
+                failed = true;
+                System.Diagnostics.Debug.WriteLine($"Transcription Error ({ex.Code}): {ex.Message}");
+                ShowErrorAndReset(ex.Code switch
+                {
+                    TranscriptionError.NotConfigured => "Configure Whisper first (S)",
+                    TranscriptionError.NoModel => "No speech model installed",
+                    TranscriptionError.AudioNotFound => "Recording not found",
+                    _ => "Couldn't read the recording"
+                });
                 return;
             }
             catch (Exception ex)
@@ -334,7 +689,8 @@ namespace EliteWhisper.Services
                     OnRecordingComplete(sender, audioFilePath);
                     return;
                 }
-                
+
+                failed = true;
                 if (CurrentSource == RecordingSource.Widget)
                 {
                     _widgetViewModel.StatusText = "Error - try again";
@@ -343,8 +699,18 @@ namespace EliteWhisper.Services
             }
             finally
             {
-                // Cleanup audio file
-                try { if (File.Exists(audioFilePath)) File.Delete(audioFilePath); } catch { }
+                // Cleanup audio file, unless a failed one is kept for troubleshooting
+                if (failed && _aiEngine.GetConfiguration().KeepFailedRecordings)
+                {
+                    System.Diagnostics.Debug.WriteLine($"Kept failed recording: {audioFilePath}");
+                }
+                else
+                {
+                    try { if (File.Exists(audioFilePath)) File.Delete(audioFilePath); } catch { }
+                }
+
+                // Failed recordings kept earlier expire here too, not only at startup
+                if (failed) _ = Task.Run(TempFileCleanup.SweepStale);
                 
                 // Return to Ready (not Hidden) - only if still processing
                 if (_widgetViewModel.State == WidgetState.Processing)
@@ -368,6 +734,8 @@ namespace EliteWhisper.Services
 
         private void OnRecordingFailed(object? sender, Exception ex)
         {
+            _ = FinishStreamingAsync();
+
             Application.Current.Dispatcher.Invoke(() =>
             {
                 string errorMessage = ex.Message switch
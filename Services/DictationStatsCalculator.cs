@@ -0,0 +1,119 @@
+using System;
+using System.Collections.Generic;
+using System.Linq;
+using EliteWhisper.Models;
+
+namespace EliteWhisper.Services
+{
+    /// <summary>
+    /// Computes dashboard metrics (words, WPM, apps, time saved) over an arbitrary time window.
+    /// </summary>
+    public static class DictationStatsCalculator
+    {
+        private const double TYPING_WPM = 40.0;
+        private const int MAX_BUCKET_DAYS = 3660;
+        private const string UNKNOWN_APP = "Unknown";
+
+        // WPM guards: sub-second clips and implausible rates usually mean a bad duration,
+        // and a few seconds of audio in total is too little to report a rate
+        private const double MIN_WPM_RECORD_SECONDS = 1.0;
+        private const double MAX_PLAUSIBLE_WPM = 400.0;
+        private const double MIN_WPM_TOTAL_SECONDS = 10.0;
+
+        /// <summary>
+        /// Stats for records with From &lt;= Timestamp &lt; To. An empty or inverted window yields zeros.
+        /// </summary>
+        public static DictationStats Compute(IEnumerable<DictationRecord> records, DateTime from, DateTime to)
+        {
+            var stats = new DictationStats { From = from, To = to };
+            if (to <= from) return stats;
+
+            var inRange = records.Where(r => r.Timestamp >= from && r.Timestamp < to).ToList();
+
+            stats.TranscriptionCount = inRange.Count;
+            stats.TotalWords = inRange.Sum(r => r.WordCount);
+            stats.TotalDurationSeconds = (int)Math.Round(inRange.Sum(GetSeconds));
+            stats.AverageWpm = ComputeWpm(inRange);
+
+            stats.AppsUsed = inRange
+                .Where(r => !string.IsNullOrEmpty(r.ApplicationName) && r.ApplicationName != UNKNOWN_APP)
+                .Select(r => r.ApplicationName)
+                .Distinct()
+                .Count();
+
+            stats.Apps = inRange
+                .GroupBy(r => string.IsNullOrEmpty(r.ApplicationName) ? UNKNOWN_APP : r.ApplicationName)
+                .Select(g => new AppStats
+                {
+                    ApplicationName = g.Key,
+                    TranscriptionCount = g.Count(),
+                    Words = g.Sum(r => r.WordCount),
+                    DurationSeconds = (int)Math.Round(g.Sum(GetSeconds))
+                })
+                .OrderByDescending(a => a.Words)
+                .ToList();
+
+            // Saved = (Words / 40) - (Duration / 60)
+            double typingMinutes = stats.TotalWords / TYPING_WPM;
+            double dictationMinutes = stats.TotalDurationSeconds / 60.0;
+            stats.MinutesSaved = (int)Math.Max(0, typingMinutes - dictationMinutes);
+
+            stats.Days = BuildDailyBuckets(inRange, from, to);
+            return stats;
+        }
+
+        /// <summary>
+        /// Words per minute over records with usable durations. 0 when there isn't enough audio to say.
+        /// </summary>
+        public static int ComputeWpm(IEnumerable<DictationRecord> records)
+        {
+            double words = 0, seconds = 0;
+            foreach (var record in records)
+            {
+                double recordSeconds = GetSeconds(record);
+                if (record.WordCount <= 0 || recordSeconds < MIN_WPM_RECORD_SECONDS) continue;
+                if (record.WordCount / (recordSeconds / 60.0) > MAX_PLAUSIBLE_WPM) continue;
+
+                words += record.WordCount;
+                seconds += recordSeconds;
+            }
+
+            if (seconds < MIN_WPM_TOTAL_SECONDS) return 0;
+            return (int)Math.Round(words / (seconds / 60.0));
+        }
+
+        // Prefer the precise duration; older records only have whole seconds
+        private static double GetSeconds(DictationRecord record) =>
+            record.Duration > TimeSpan.Zero ? record.Duration.TotalSeconds : record.DurationSeconds;
+
+        private static List<DailyStats> BuildDailyBuckets(List<DictationRecord> records, DateTime from, DateTime to)
+        {
+            var buckets = new List<DailyStats>();
+            if (records.Count == 0 && (to - from).TotalDays > MAX_BUCKET_DAYS) return buckets;
+
+            // Open-ended windows ("all time") start at the first record instead of year 1
+            DateTime start = from.Date;
+            DateTime end = to.AddTicks(-1).Date;
+            if ((end - start).TotalDays > MAX_BUCKET_DAYS)
+            {
+                start = records.Min(r => r.Timestamp).Date;
+                end = records.Max(r => r.Timestamp).Date;
+            }
+
+            var byDay = records.GroupBy(r => r.Timestamp.Date).ToDictionary(g => g.Key, g => g.ToList());
+            for (var day = start; day <= end; day = day.AddDays(1))
+            {
+                var bucket = new DailyStats { Date = day };
+                if (byDay.TryGetValue(day, out var dayRecords))
+                {
+                    bucket.TranscriptionCount = dayRecords.Count;
+                    bucket.Words = dayRecords.Sum(r => r.WordCount);
+                    bucket.DurationSeconds = dayRecords.Sum(r => r.DurationSeconds);
+                }
+                buckets.Add(bucket);
+            }
+
+            return buckets;
+        }
+    }
+}
@@ -0,0 +1,193 @@
+using System;
+using System.Collections.Generic;
+using System.Globalization;
+using System.IO;
+using System.Linq;
+using System.Text;
+using EliteWhisper.Models;
+
+namespace EliteWhisper.Services
+{
+    /// <summary>
+    /// Converts history exported by other dictation tools into DictationRecords.
+    /// </summary>
+    public static class ExternalHistoryImporter
+    {
+        private const string IMPORTED_MODEL = "Imported";
+
+        /// <summary>
+        /// Reads records from a folder of .txt files. Timestamps come from the file modification time.
+        /// </summary>
+        public static List<DictationRecord> ReadTextFolder(string folder, out int skipped)
+        {
+            if (!Directory.Exists(folder))
+                throw new DirectoryNotFoundException($"Folder not found: {folder}");
+
+            var records = new List<DictationRecord>();
+            skipped = 0;
+
+            foreach (var file in Directory.EnumerateFiles(folder, "*.txt"))
+            {
+                try
+                {
+                    string content = File.ReadAllText(file).Trim();
+                    if (string.IsNullOrEmpty(content))
+                    {
+                        skipped++;
+                        continue;
+                    }
+
+                    records.Add(CreateRecord(content, File.GetLastWriteTime(file), 0, Path.GetFileNameWithoutExtension(file)));
+                }
+                catch (Exception ex)
+                {
+                    System.Diagnostics.Debug.WriteLine($"Failed to import {file}: {ex.Message}");
+                    skipped++;
+                }
+            }
+
+            return records;
+        }
+
+        /// <summary>
+        /// Reads records from a CSV file with a header row. Rows without content are skipped;
+        /// rows without a parseable timestamp fall back to the file modification time.
+        /// </summary>
+        public static List<DictationRecord> ReadCsv(string path, CsvColumnMapping mapping, out int skipped)
+        {
+            if (!File.Exists(path))
+                throw new FileNotFoundException("CSV file not found", path);
+
+            var rows = ParseCsv(File.ReadAllText(path));
+            if (rows.Count == 0)
+                throw new InvalidOperationException("The CSV file is empty.");
+
+            var header = rows[0];
+            int contentIndex = FindColumn(header, mapping.ContentColumn);
+            if (contentIndex < 0)
+                throw new InvalidOperationException($"Column '{mapping.ContentColumn}' not found in the CSV header.");
+
+            int timestampIndex = FindColumn(header, mapping.TimestampColumn);
+            int durationIndex = FindColumn(header, mapping.DurationSecondsColumn);
+            int appIndex = FindColumn(header, mapping.ApplicationColumn);
+            DateTime fileTime = File.GetLastWriteTime(path);
+
+            var records = new List<DictationRecord>();
+            skipped = 0;
+
+            foreach (var row in rows.Skip(1))
+            {
+                string content = GetField(row, contentIndex)?.Trim() ?? string.Empty;
+                if (string.IsNullOrEmpty(content))
+                {
+                    skipped++;
+                    continue;
+                }
+
+                DateTime timestamp = DateTime.TryParse(GetField(row, timestampIndex), CultureInfo.InvariantCulture,
+                    DateTimeStyles.AssumeLocal, out var parsed) ? parsed : fileTime;
+
+                int durationSeconds = int.TryParse(GetField(row, durationIndex), NumberStyles.Integer,
+                    CultureInfo.InvariantCulture, out var seconds) ? Math.Max(0, seconds) : 0;
+
+                records.Add(CreateRecord(content, timestamp, durationSeconds, GetField(row, appIndex)));
+            }
+
+            return records;
+        }
+
+        private static DictationRecord CreateRecord(string content, DateTime timestamp, int durationSeconds, string? applicationName)
+        {
+            return new DictationRecord
+            {
+                Timestamp = timestamp,
+                Content = content,
+                WordCount = content.Split((char[]?)null, StringSplitOptions.RemoveEmptyEntries).Length,
+                DurationSeconds = durationSeconds,
+                Duration = TimeSpan.FromSeconds(durationSeconds),
+                ModelUsed = IMPORTED_MODEL,
+                ApplicationName = string.IsNullOrWhiteSpace(applicationName) ? null : applicationName.Trim()
+            };
+        }
+
+        private static int FindColumn(List<string> header, string? name)
+        {
+            if (string.IsNullOrWhiteSpace(name)) return -1;
+            return header.FindIndex(h => string.Equals(h.Trim(), name.Trim(), StringComparison.OrdinalIgnoreCase));
+        }
+
+        private static string? GetField(List<string> row, int index)
+        {
+            return index >= 0 && index < row.Count ? row[index] : null;
+        }
+
+        /// <summary>
+        /// Minimal RFC 4180 parser: quoted fields, escaped quotes and newlines inside quotes.
+        /// </summary>
+        private static List<List<string>> ParseCsv(string text)
+        {
+            var rows = new List<List<string>>();
+            var row = new List<string>();
+            var field = new StringBuilder();
+            bool inQuotes = false;
+
+            for (int i = 0; i < text.Length; i++)
+            {
+                char c = text[i];
+
+                if (inQuotes)
+                {
+                    if (c == '"')
+                    {
+                        if (i + 1 < text.Length && text[i + 1] == '"')
+                        {
+                            field.Append('"');
+                            i++;
+                        }
+                        else
+                        {
+                            inQuotes = false;
+                        }
+                    }
+                    else
+                    {
+                        field.Append(c);
+                    }
+                    continue;
+                }
+
+                switch (c)
+                {
+                    case '"':
+                        inQuotes = true;
+                        break;
+                    case ',':
+                        row.Add(field.ToString());
+                        field.Clear();
+                        break;
+                    case '\r':
+                        break;
+                    case '\n':
+                        row.Add(field.ToString());
+                        field.Clear();
+                        rows.Add(row);
+                        row = new List<string>();
+                        break;
+                    default:
+                        field.Append(c);
+                        break;
+                }
+            }
+
+            if (field.Length > 0 || row.Count > 0)
+            {
+                row.Add(field.ToString());
+                rows.Add(row);
+            }
+
+            // Drop blank lines
+            rows.RemoveAll(r => r.Count == 1 && string.IsNullOrWhiteSpace(r[0]));
+            return rows;
+        }
+    }
+}
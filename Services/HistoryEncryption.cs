@@ -0,0 +1,83 @@
+using System;
+using System.Security.Cryptography;
+using System.Text;
+
+namespace EliteWhisper.Services
+{
+    /// <summary>
+    /// Passphrase-based encryption for history.json (AES-256-GCM, key from PBKDF2-SHA256).
+    /// Layout: "EWHE" magic, version, iteration count, salt, nonce, tag, ciphertext.
+    /// </summary>
+    public static class HistoryEncryption
+    {
+        private static readonly byte[] Magic = Encoding.ASCII.GetBytes("EWHE");
+        private const byte VERSION = 1;
+        private const int SALT_SIZE = 16;
+        private const int NONCE_SIZE = 12;
+        private const int TAG_SIZE = 16;
+        private const int KEY_SIZE = 32;
+        private const int HEADER_SIZE = 4 + 1 + 4 + SALT_SIZE + NONCE_SIZE + TAG_SIZE;
+
+        // OWASP recommendation for PBKDF2-HMAC-SHA256
+        public const int DEFAULT_ITERATIONS = 600_000;
+
+        public static bool IsEncrypted(byte[] data) =>
+            data.Length >= HEADER_SIZE && data.AsSpan(0, Magic.Length).SequenceEqual(Magic);
+
+        public static byte[] NewSalt() => RandomNumberGenerator.GetBytes(SALT_SIZE);
+
+        public static byte[] DeriveKey(string passphrase, byte[] salt, int iterations = DEFAULT_ITERATIONS) =>
+            Rfc2898DeriveBytes.Pbkdf2(passphrase, salt, iterations, HashAlgorithmName.SHA256, KEY_SIZE);
+
+        public static byte[] Encrypt(byte[] plaintext, byte[] key, byte[] salt, int iterations = DEFAULT_ITERATIONS)
+        {
+            var output = new byte[HEADER_SIZE + plaintext.Length];
+            var span = output.AsSpan();
+
+            Magic.CopyTo(span);
+            span[4] = VERSION;
+            BitConverter.TryWriteBytes(span.Slice(5, 4), iterations);
+            salt.CopyTo(span.Slice(9, SALT_SIZE));
+
+            var nonce = span.Slice(9 + SALT_SIZE, NONCE_SIZE);
+            RandomNumberGenerator.Fill(nonce);
+            var tag = span.Slice(9 + SALT_SIZE + NONCE_SIZE, TAG_SIZE);
+
+            using var aes = new AesGcm(key, TAG_SIZE);
+            aes.Encrypt(nonce, plaintext, span.Slice(HEADER_SIZE), tag);
+            return output;
+        }
+
+        /// <summary>
+        /// Reads the salt and iteration count from an encrypted file so the key can be derived.
+        /// </summary>
+        public static (byte[] Salt, int Iterations) ReadHeader(byte[] data)
+        {
+            if (!IsEncrypted(data))
+                throw new CryptographicException("The history file is not encrypted.");
+            if (data[4] != VERSION)
+                throw new CryptographicException($"Unsupported history encryption version {data[4]}.");
+
+            return (data.AsSpan(9, SALT_SIZE).ToArray(), BitConverter.ToInt32(data, 5));
+        }
+
+        /// <summary>
+        /// Decrypts a file written by <see cref="Encrypt"/>. Throws CryptographicException when the key is wrong
+        /// or the file was modified.
+        /// </summary>
+        public static byte[] Decrypt(byte[] data, byte[] key)
+        {
+            ReadHeader(data);
+
+            var span = data.AsSpan();
+            var nonce = span.Slice(9 + SALT_SIZE, NONCE_SIZE);
+            var tag = span.Slice(9 + SALT_SIZE + NONCE_SIZE, TAG_SIZE);
+            var ciphertext = span.Slice(HEADER_SIZE);
+            var plaintext = new byte[ciphertext.Length];
+
+            using var aes = new AesGcm(key, TAG_SIZE);
+            aes.Decrypt(nonce, ciphertext, tag, plaintext);
+            return plaintext;
+        }
+    }
+}
@@ -0,0 +1,136 @@
+using System;
+using System.Collections.Generic;
+using System.Globalization;
+using System.IO;
+using System.Linq;
+using System.Text;
+using System.Text.Json;
+using EliteWhisper.Models;
+
+namespace EliteWhisper.Services
+{
+    public enum HistoryExportFormat
+    {
+        Json,
+        Csv,
+        Markdown
+    }
+
+    /// <summary>
+    /// Writes the whole history to a single file for backup or migration.
+    /// JSON keeps every field and can be imported again; CSV and Markdown are for reading.
+    /// </summary>
+    public static class HistoryExporter
+    {
+        public static void Export(IEnumerable<DictationRecord> records, HistoryExportFormat format, string path)
+        {
+            var ordered = records.OrderByDescending(r => r.Timestamp).ToList();
+
+            string text = format switch
+            {
+                HistoryExportFormat.Csv => FormatCsv(ordered),
+                HistoryExportFormat.Markdown => FormatMarkdown(ordered),
+                _ => JsonSerializer.Serialize(ordered, new JsonSerializerOptions { WriteIndented = true })
+            };
+
+            // Write next to the target first so a failed export never leaves a half-written file
+            string tempPath = path + ".tmp";
+            File.WriteAllText(tempPath, text, new UTF8Encoding(false));
+            File.Move(tempPath, path, overwrite: true);
+        }
+
+        /// <summary>
+        /// Reads a JSON backup written by <see cref="Export"/>. Entries that aren't a valid
+        /// history item (bad field types, no id or no transcript) are counted and skipped.
+        /// </summary>
+        public static List<DictationRecord> ReadJson(string path, out int rejected)
+        {
+            if (!File.Exists(path))
+                throw new FileNotFoundException($"File not found: {path}");
+
+            using var document = JsonDocument.Parse(File.ReadAllText(path));
+            if (document.RootElement.ValueKind != JsonValueKind.Array)
+                throw new InvalidDataException("The file is not a history export (expected a list of items).");
+
+            var records = new List<DictationRecord>();
+            rejected = 0;
+
+            foreach (var element in document.RootElement.EnumerateArray())
+            {
+                DictationRecord? record = null;
+                try
+                {
+                    record = element.Deserialize<DictationRecord>();
+                }
+                catch (JsonException)
+                {
+                }
+
+                if (record == null || record.Id == Guid.Empty || string.IsNullOrWhiteSpace(record.Content))
+                {
+                    rejected++;
+                    continue;
+                }
+
+                records.Add(record);
+            }
+
+            return records;
+        }
+
+        private static string FormatCsv(List<DictationRecord> records)
+        {
+            var sb = new StringBuilder();
+            sb.Append("timestamp,title,transcript,duration,app_name\r\n");
+
+            foreach (var record in records)
+            {
+                sb.Append(EscapeCsv(record.Timestamp.ToString("yyyy-MM-dd HH:mm:ss", CultureInfo.InvariantCulture))).Append(',');
+                sb.Append(EscapeCsv(record.Title)).Append(',');
+                sb.Append(EscapeCsv(record.Content)).Append(',');
+                sb.Append(GetDurationSeconds(record).ToString(CultureInfo.InvariantCulture)).Append(',');
+                sb.Append(EscapeCsv(record.ApplicationName)).Append("\r\n");
+            }
+
+            return sb.ToString();
+        }
+
+        private static string FormatMarkdown(List<DictationRecord> records)
+        {
+            var sb = new StringBuilder();
+            sb.Append("# Dictation History\n\n");
+
+            foreach (var record in records)
+            {
+                sb.Append("## ").Append(record.Timestamp.ToString("dddd, MMMM d, yyyy HH:mm", CultureInfo.InvariantCulture));
+                if (!string.IsNullOrWhiteSpace(record.Title))
+                    sb.Append(" - ").Append(record.Title.Trim());
+                sb.Append("\n\n");
+
+                var details = new List<string>();
+                if (!string.IsNullOrWhiteSpace(record.ApplicationName)) details.Add(record.ApplicationName);
+                int seconds = GetDurationSeconds(record);
+                if (seconds > 0) details.Add($"{seconds}s");
+                if (details.Count > 0)
+                    sb.Append('*').Append(string.Join(" · ", details)).Append("*\n\n");
+
+                sb.Append(record.Content.Trim()).Append("\n\n");
+            }
+
+            return sb.ToString();
+        }
+
+        // RFC 4180: quote fields containing separators, quotes or line breaks and double inner quotes
+        private static string EscapeCsv(string? value)
+        {
+            if (string.IsNullOrEmpty(value)) return string.Empty;
+
+            if (value.IndexOfAny(new[] { ',', '"', '\r', '\n' }) < 0) return value;
+
+            return "\"" + value.Replace("\"", "\"\"") + "\"";
+        }
+
+        private static int GetDurationSeconds(DictationRecord record) =>
+            record.Duration > TimeSpan.Zero ? (int)Math.Round(record.Duration.TotalSeconds) : record.DurationSeconds;
+    }
+}
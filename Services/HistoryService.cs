@@ -14,13 +14,62 @@ namespace EliteWhisper.Services
         private readonly WhisperConfigurationService _configService;
         private ObservableCollection<DictationRecord> _history = new();
         private const string HISTORY_FILENAME = "history.json";
+        private const string AUDIO_FOLDER = "audio";
+        private const string TRANSCRIPTS_FOLDER = "transcripts";
+        private static readonly TimeSpan RetentionSweepInterval = TimeSpan.FromHours(6);
+        private const int SNIPPET_CONTEXT = 40;
+        private readonly System.Threading.Timer _retentionTimer;
+
+        // Key for an encrypted history file; only ever held in memory
+        private byte[]? _key;
+        private byte[]? _salt;
+        private int _iterations = HistoryEncryption.DEFAULT_ITERATIONS;
+        private string? _passphrase;
+        private readonly object _fileLock = new();
+
+        // Serializes retention and storage-cap passes, which run from the timer and after each new record
+        private readonly object _maintenanceLock = new();
 
         public ReadOnlyObservableCollection<DictationRecord> History => new(_history);
 
+        /// <summary>
+        /// Raised after a record's text, title or tags were edited.
+        /// </summary>
+        public event EventHandler<DictationRecord>? RecordUpdated;
+
+        /// <summary>
+        /// True while the history file is encrypted and no passphrase has been entered.
+        /// Nothing is read from or written to disk until <see cref="Unlock"/> succeeds.
+        /// </summary>
+        public bool IsLocked { get; private set; }
+
+        public event EventHandler? LockStateChanged;
+
+        /// <summary>
+        /// Raised with the number of items removed when the retention setting pruned history.
+        /// </summary>
+        public event EventHandler<int>? HistoryPruned;
+
         public HistoryService(WhisperConfigurationService configService)
         {
             _configService = configService;
             LoadHistory();
+
+            // First sweep shortly after startup, then periodically. An exception escaping a timer callback
+            // would end the process, e.g. when the app is shutting down mid-sweep.
+            _retentionTimer = new System.Threading.Timer(_ =>
+            {
+                try
+                {
+                    if (System.Windows.Application.Current == null) return;
+                    ApplyRetentionPolicy();
+                    SweepExpiredRecordings();
+                }
+                catch (Exception ex)
+                {
+                    System.Diagnostics.Debug.WriteLine($"History retention sweep failed: {ex.Message}");
+                }
+            }, null, TimeSpan.FromMinutes(1), RetentionSweepInterval);
         }
 
         public void AddRecord(DictationRecord record)
@@ -32,7 +81,133 @@ namespace EliteWhisper.Services
             });
             
             // Persist async
-            Task.Run(SaveHistory);
+            Task.Run(() =>
+            {
+                WriteTranscriptFile(record);
+                SaveHistory();
+                if (record.AudioAvailable) EnforceRecordingStorageCap();
+                ApplyRetentionPolicy();
+            });
+        }
+
+        /// <summary>
+        /// Deletes items the retention setting no longer keeps, with their transcript files and recordings.
+        /// Returns the number of items removed. Call off the UI thread.
+        /// </summary>
+        public int ApplyRetentionPolicy()
+        {
+            lock (_maintenanceLock)
+            {
+                return ApplyRetentionPolicyCore();
+            }
+        }
+
+        private int ApplyRetentionPolicyCore()
+        {
+            if (IsLocked) return 0;
+
+            var config = _configService.CurrentConfiguration;
+            if (config.HistoryRetentionMode == HistoryRetentionMode.Unlimited || config.HistoryRetentionLimit <= 0) return 0;
+
+            var newestFirst = SnapshotHistory().OrderByDescending(r => r.Timestamp).ToList();
+            var expired = config.HistoryRetentionMode == HistoryRetentionMode.KeepCount
+                ? newestFirst.Skip(config.HistoryRetentionLimit).ToList()
+                : newestFirst.Where(r => r.Timestamp < DateTime.Now.AddDays(-config.HistoryRetentionLimit)).ToList();
+
+            if (expired.Count == 0) return 0;
+
+            System.Windows.Application.Current.Dispatcher.Invoke(() =>
+            {
+                foreach (var record in expired)
+                {
+                    _history.Remove(record);
+                }
+            });
+
+            foreach (var record in expired)
+            {
+                DeleteTranscriptFile(record);
+                DeleteRecordingFile(record);
+            }
+
+            System.Diagnostics.Debug.WriteLine($"History retention: removed {expired.Count} item(s)");
+            SaveHistory();
+            HistoryPruned?.Invoke(this, expired.Count);
+            return expired.Count;
+        }
+
+        /// <summary>
+        /// Moves a finished recording into the audio folder next to the history file, named after the record's id,
+        /// and links it to the record. Call before <see cref="AddRecord"/>.
+        /// Does nothing while history encryption is on, since the audio would be stored in the clear.
+        /// </summary>
+        public void AttachRecording(DictationRecord record, string sourcePath)
+        {
+            if (_configService.CurrentConfiguration.EncryptHistory)
+            {
+                System.Diagnostics.Debug.WriteLine("[History] Encryption is on; not keeping the recording");
+                return;
+            }
+
+            string dir = GetAudioDirectory();
+            Directory.CreateDirectory(dir);
+
+            string target = Path.Combine(dir, $"{record.Id}{Path.GetExtension(sourcePath)}");
+            File.Move(sourcePath, target, overwrite: true);
+
+            record.AudioFilePath = target;
+            record.AudioAvailable = true;
+        }
+
+        /// <summary>
+        /// Path of a record's saved recording for playback, or null if it has none or the file is gone.
+        /// </summary>
+        public string? GetRecordingPath(Guid id)
+        {
+            var record = _history.FirstOrDefault(r => r.Id == id);
+            if (record == null || !record.AudioAvailable || string.IsNullOrEmpty(record.AudioFilePath)) return null;
+            return File.Exists(record.AudioFilePath) ? record.AudioFilePath : null;
+        }
+
+        /// <summary>
+        /// Deletes the oldest saved recordings until they fit the storage limit, keeping their transcripts.
+        /// Returns the number of bytes reclaimed. Call off the UI thread.
+        /// </summary>
+        public long EnforceRecordingStorageCap()
+        {
+            lock (_maintenanceLock)
+            {
+                return EnforceRecordingStorageCapCore();
+            }
+        }
+
+        private long EnforceRecordingStorageCapCore()
+        {
+            int capMB = _configService.CurrentConfiguration.RecordingStorageCapMB;
+            if (capMB <= 0) return 0;
+
+            var withAudio = SnapshotHistory()
+                .Where(r => r.AudioAvailable && !string.IsNullOrEmpty(r.AudioFilePath))
+                .OrderBy(r => r.Timestamp)
+                .Select(r => (Record: r, File: new FileInfo(r.AudioFilePath!)))
+                .ToList();
+
+            long cap = capMB * 1024L * 1024;
+            long total = withAudio.Where(a => a.File.Exists).Sum(a => a.File.Length);
+            if (total <= cap) return 0;
+
+            long reclaimed = 0;
+            int pruned = 0;
+            foreach (var (record, _) in withAudio)
+            {
+                if (total - reclaimed <= cap) break;
+                reclaimed += DeleteRecordingFile(record);
+                pruned++;
+            }
+
+            System.Diagnostics.Debug.WriteLine($"Recording storage limit: removed audio for {pruned} item(s), reclaimed {reclaimed / 1024} KB");
+            SaveHistory();
+            return reclaimed;
         }
 
         public void DeleteRecord(Guid id)
@@ -44,19 +219,678 @@ namespace EliteWhisper.Services
                 {
                     _history.Remove(record);
                 });
+                DeleteTranscriptFile(record);
+                DeleteRecordingFile(record);
                 Task.Run(SaveHistory);
             }
         }
         
+        /// <summary>
+        /// Replaces the transcript and title of a record, keeping its timestamp, duration and audio.
+        /// Word timings are dropped when the text changes because they no longer line up.
+        /// Throws KeyNotFoundException when the record doesn't exist.
+        /// </summary>
+        public DictationRecord UpdateRecord(Guid id, string content, string? title)
+        {
+            if (string.IsNullOrWhiteSpace(content))
+                throw new ArgumentException("The transcript can't be empty.", nameof(content));
+
+            var record = _history.FirstOrDefault(r => r.Id == id)
+                ?? throw new KeyNotFoundException($"History item {id} not found.");
+
+            content = content.Trim();
+            if (record.Content != content)
+            {
+                record.Content = content;
+                record.WordCount = content.Split((char[]?)null, StringSplitOptions.RemoveEmptyEntries).Length;
+                record.Words = null;
+            }
+            record.Title = string.IsNullOrWhiteSpace(title) ? null : title.Trim();
+
+            OnRecordChanged(record, rewriteTranscriptFile: true);
+            return record;
+        }
+
+        /// <summary>
+        /// Adds a tag to a record. Tags are trimmed and compared case-insensitively; adding one twice does nothing.
+        /// </summary>
+        public DictationRecord AddTag(Guid id, string tag)
+        {
+            string normalized = NormalizeTag(tag);
+            var record = _history.FirstOrDefault(r => r.Id == id)
+                ?? throw new KeyNotFoundException($"History item {id} not found.");
+
+            record.Tags ??= new List<string>();
+            if (record.Tags.Any(t => string.Equals(t, normalized, StringComparison.OrdinalIgnoreCase))) return record;
+
+            record.Tags.Add(normalized);
+            OnRecordChanged(record);
+            return record;
+        }
+
+        public DictationRecord RemoveTag(Guid id, string tag)
+        {
+            string normalized = NormalizeTag(tag);
+            var record = _history.FirstOrDefault(r => r.Id == id)
+                ?? throw new KeyNotFoundException($"History item {id} not found.");
+
+            if (record.Tags == null || record.Tags.RemoveAll(t => string.Equals(t, normalized, StringComparison.OrdinalIgnoreCase)) == 0)
+                return record;
+
+            OnRecordChanged(record);
+            return record;
+        }
+
+        /// <summary>
+        /// Records carrying the tag (case-insensitive), newest first.
+        /// </summary>
+        public List<DictationRecord> GetByTag(string tag)
+        {
+            EnsureUnlocked();
+            string normalized = NormalizeTag(tag);
+
+            return _history.ToList()
+                .Where(r => r.Tags?.Any(t => string.Equals(t, normalized, StringComparison.OrdinalIgnoreCase)) == true)
+                .OrderByDescending(r => r.Timestamp)
+                .ToList();
+        }
+
+        /// <summary>
+        /// Every tag in use with how many records carry it, most used first.
+        /// </summary>
+        public List<(string Tag, int Count)> GetTags()
+        {
+            return _history.ToList()
+                .SelectMany(r => r.Tags ?? Enumerable.Empty<string>())
+                .GroupBy(t => t, StringComparer.OrdinalIgnoreCase)
+                .Select(g => (g.First(), g.Count()))
+                .OrderByDescending(t => t.Item2)
+                .ThenBy(t => t.Item1, StringComparer.OrdinalIgnoreCase)
+                .ToList();
+        }
+
+        private static string NormalizeTag(string tag)
+        {
+            string normalized = (tag ?? string.Empty).Trim().TrimStart('#').Trim();
+            if (normalized.Length == 0)
+                throw new ArgumentException("Tag can't be empty.", nameof(tag));
+            return normalized;
+        }
+
+        private void OnRecordChanged(DictationRecord record, bool rewriteTranscriptFile = false)
+        {
+            // Replacing the item in place makes bound views re-render it
+            System.Windows.Application.Current.Dispatcher.Invoke(() =>
+            {
+                int index = _history.IndexOf(record);
+                if (index >= 0) _history[index] = record;
+            });
+
+            Task.Run(() =>
+            {
+                if (rewriteTranscriptFile) WriteTranscriptFile(record);
+                SaveHistory();
+            });
+            RecordUpdated?.Invoke(this, record);
+        }
+        
         public void ClearAll()
         {
+            var records = _history.ToList();
             System.Windows.Application.Current.Dispatcher.Invoke(() =>
             {
                 _history.Clear();
             });
+            foreach (var record in records)
+            {
+                DeleteTranscriptFile(record);
+                DeleteRecordingFile(record);
+            }
             Task.Run(SaveHistory);
         }
 
+        /// <summary>
+        /// Dashboard metrics, per-app totals and per-day buckets for records in [from, to).
+        /// </summary>
+        public DictationStats GetStats(DateTime from, DateTime to)
+        {
+            return DictationStatsCalculator.Compute(_history.ToList(), from, to);
+        }
+
+        /// <summary>
+        /// Records [offset, offset + limit) of the history, newest first. Offset 0 is the most recent record.
+        /// </summary>
+        public HistoryPageResult GetPage(int offset, int limit)
+        {
+            EnsureUnlocked();
+            var snapshot = _history.ToList();
+
+            return new HistoryPageResult
+            {
+                Items = snapshot
+                    .OrderByDescending(r => r.Timestamp)
+                    .Skip(Math.Max(0, offset))
+                    .Take(Math.Max(0, limit))
+                    .ToList(),
+                TotalCount = snapshot.Count
+            };
+        }
+
+        /// <summary>
+        /// Case-insensitive search over transcript and application name, newest first.
+        /// An empty query returns the most recent records.
+        /// </summary>
+        public List<HistorySearchHit> Search(string? query, int limit)
+        {
+            EnsureUnlocked();
+            var hits = new List<HistorySearchHit>();
+            if (limit <= 0) return hits;
+
+            foreach (var record in _history.ToList().OrderByDescending(r => r.Timestamp))
+            {
+                if (string.IsNullOrWhiteSpace(query))
+                {
+                    hits.Add(new HistorySearchHit { Record = record, Snippet = MakeSnippet(record.Content, 0, 0).Snippet });
+                }
+                else
+                {
+                    int index = record.Content.IndexOf(query, StringComparison.OrdinalIgnoreCase);
+                    bool appMatch = record.ApplicationName?.Contains(query, StringComparison.OrdinalIgnoreCase) == true;
+                    if (index < 0 && !appMatch) continue;
+
+                    var (snippet, matchStart) = MakeSnippet(record.Content, Math.Max(0, index), index < 0 ? 0 : query.Length);
+                    hits.Add(new HistorySearchHit
+                    {
+                        Record = record,
+                        Snippet = snippet,
+                        MatchStart = index < 0 ? -1 : matchStart,
+                        MatchLength = index < 0 ? 0 : query.Length
+                    });
+                }
+
+                if (hits.Count >= limit) break;
+            }
+
+            return hits;
+        }
+
+        // Cuts SNIPPET_CONTEXT characters either side of the match, marking cut ends with "..."
+        private static (string Snippet, int MatchStart) MakeSnippet(string text, int index, int length)
+        {
+            int start = Math.Max(0, index - SNIPPET_CONTEXT);
+            int end = Math.Min(text.Length, index + length + SNIPPET_CONTEXT);
+
+            // Same-length replacement keeps matchStart valid
+            string snippet = text.Substring(start, end - start).Replace('\r', ' ').Replace('\n', ' ');
+            int matchStart = index - start;
+
+            if (start > 0)
+            {
+                snippet = "..." + snippet;
+                matchStart += 3;
+            }
+            if (end < text.Length) snippet += "...";
+
+            return (snippet, matchStart);
+        }
+
+        /// <summary>
+        /// Word active at a playback position, for karaoke-style highlighting.
+        /// Returns null before the first word, after the last one, or when the record has no word timings.
+        /// A position in a gap between two words keeps the previous word highlighted.
+        /// </summary>
+        public (int Index, WordTiming Word)? WordAt(Guid id, long positionMs)
+        {
+            var words = _history.FirstOrDefault(r => r.Id == id)?.Words;
+            if (words == null || words.Count == 0) return null;
+            if (positionMs < words[0].StartMs || positionMs >= words[^1].EndMs) return null;
+
+            // Last word starting at or before the position
+            int lo = 0, hi = words.Count - 1;
+            while (lo < hi)
+            {
+                int mid = lo + (hi - lo + 1) / 2;
+                if (words[mid].StartMs <= positionMs) lo = mid;
+                else hi = mid - 1;
+            }
+
+            return (lo, words[lo]);
+        }
+
+        /// <summary>
+        /// Records whose audio fingerprint is close to the given record's, closest first.
+        /// </summary>
+        public List<DictationRecord> FindSimilar(Guid id, int maxDistance = 8)
+        {
+            var record = _history.FirstOrDefault(r => r.Id == id);
+            if (record?.AudioFingerprint == null) return new List<DictationRecord>();
+
+            return _history
+                .Where(r => r.Id != id)
+                .Select(r => (Record: r, Distance: Speech.AudioFingerprint.Distance(record.AudioFingerprint, r.AudioFingerprint)))
+                .Where(x => x.Distance.HasValue && x.Distance.Value <= maxDistance)
+                .OrderBy(x => x.Distance)
+                .Select(x => x.Record)
+                .ToList();
+        }
+
+        /// <summary>
+        /// Imports history exported by another dictation tool.
+        /// Records already present (same timestamp and text) are counted as skipped.
+        /// </summary>
+        public HistoryImportResult ImportExternal(string path, ExternalHistoryFormat format, CsvColumnMapping? mapping = null)
+        {
+            int skipped;
+            var records = format switch
+            {
+                ExternalHistoryFormat.Csv => ExternalHistoryImporter.ReadCsv(path, mapping ?? new CsvColumnMapping(), out skipped),
+                ExternalHistoryFormat.TextFolder => ExternalHistoryImporter.ReadTextFolder(path, out skipped),
+                _ => throw new ArgumentOutOfRangeException(nameof(format))
+            };
+
+            var result = MergeRecords(records);
+            result.Skipped += skipped;
+            return result;
+        }
+
+        /// <summary>
+        /// Restores a JSON backup made with the history export. Merge adds items whose id is new;
+        /// replace discards the current history first. Malformed entries are counted as rejected.
+        /// </summary>
+        public HistoryImportResult ImportBackup(string path, HistoryImportMode mode)
+        {
+            EnsureUnlocked();
+            var records = HistoryExporter.ReadJson(path, out int rejected);
+            var result = new HistoryImportResult { Rejected = rejected };
+
+            // Ids must be unique within the file too
+            var seen = mode == HistoryImportMode.Merge
+                ? new HashSet<Guid>(_history.Select(r => r.Id))
+                : new HashSet<Guid>();
+            var toAdd = new List<DictationRecord>();
+
+            foreach (var record in records)
+            {
+                if (!seen.Add(record.Id))
+                {
+                    result.Skipped++;
+                    continue;
+                }
+
+                // Backups often come from another machine; drop file links that don't resolve here,
+                // and links outside the transcripts folder so a crafted file can't point deletes elsewhere
+                if (!string.IsNullOrEmpty(record.TranscriptFilePath) &&
+                    (!File.Exists(record.TranscriptFilePath) || !IsManagedTranscript(record.TranscriptFilePath)))
+                    record.TranscriptFilePath = null;
+                if (string.IsNullOrEmpty(record.AudioFilePath) || !File.Exists(record.AudioFilePath))
+                    record.AudioAvailable = false;
+
+                if (record.WordCount == 0)
+                    record.WordCount = record.Content.Split((char[]?)null, StringSplitOptions.RemoveEmptyEntries).Length;
+
+                toAdd.Add(record);
+            }
+
+            if (mode == HistoryImportMode.Merge && toAdd.Count == 0) return result;
+
+            var removed = mode == HistoryImportMode.Replace ? _history.ToList() : new List<DictationRecord>();
+
+            System.Windows.Application.Current.Dispatcher.Invoke(() =>
+            {
+                var combined = mode == HistoryImportMode.Replace ? toAdd : _history.Concat(toAdd);
+                var merged = combined.OrderByDescending(r => r.Timestamp).ToList();
+                _history.Clear();
+                foreach (var record in merged)
+                {
+                    _history.Add(record);
+                }
+            });
+
+            var keptFiles = new HashSet<string?>(toAdd.Select(r => r.TranscriptFilePath), StringComparer.OrdinalIgnoreCase);
+            var keptAudio = new HashSet<string?>(toAdd.Where(r => r.AudioAvailable).Select(r => r.AudioFilePath), StringComparer.OrdinalIgnoreCase);
+            foreach (var record in removed)
+            {
+                if (!keptFiles.Contains(record.TranscriptFilePath))
+                    DeleteTranscriptFile(record);
+                if (!keptAudio.Contains(record.AudioFilePath))
+                    DeleteRecordingFile(record);
+            }
+
+            result.Imported = toAdd.Count;
+            Task.Run(SaveHistory);
+            return result;
+        }
+
+        /// <summary>
+        /// Adds records that are not already in history, keeping newest first.
+        /// </summary>
+        public HistoryImportResult MergeRecords(IEnumerable<DictationRecord> records)
+        {
+            EnsureUnlocked();
+            var result = new HistoryImportResult();
+            var existing = new HashSet<(DateTime, string)>(_history.Select(r => (r.Timestamp, r.Content)));
+            var toAdd = new List<DictationRecord>();
+
+            foreach (var record in records)
+            {
+                if (existing.Add((record.Timestamp, record.Content)))
+                {
+                    toAdd.Add(record);
+                }
+                else
+                {
+                    result.Skipped++;
+                }
+            }
+
+            if (toAdd.Count == 0) return result;
+
+            System.Windows.Application.Current.Dispatcher.Invoke(() =>
+            {
+                var merged = _history.Concat(toAdd).OrderByDescending(r => r.Timestamp).ToList();
+                _history.Clear();
+                foreach (var record in merged)
+                {
+                    _history.Add(record);
+                }
+            });
+
+            result.Imported = toAdd.Count;
+            Task.Run(SaveHistory);
+            return result;
+        }
+
+        /// <summary>
+        /// Deletes recordings older than the retention setting while keeping their transcripts.
+        /// Returns the number of bytes reclaimed. Call off the UI thread.
+        /// </summary>
+        public long SweepExpiredRecordings()
+        {
+            lock (_maintenanceLock)
+            {
+                return SweepExpiredRecordingsCore();
+            }
+        }
+
+        private long SweepExpiredRecordingsCore()
+        {
+            int retentionDays = _configService.CurrentConfiguration.RecordingRetentionDays;
+            if (retentionDays <= 0) return 0;
+
+            var cutoff = DateTime.Now.AddDays(-retentionDays);
+            var expired = SnapshotHistory()
+                .Where(r => r.AudioAvailable && r.Timestamp < cutoff && !string.IsNullOrEmpty(r.AudioFilePath))
+                .ToList();
+
+            if (expired.Count == 0) return 0;
+
+            long reclaimed = 0;
+            foreach (var record in expired)
+            {
+                reclaimed += DeleteRecordingFile(record);
+            }
+
+            System.Diagnostics.Debug.WriteLine($"Recording retention: removed audio for {expired.Count} item(s), reclaimed {reclaimed / 1024} KB");
+            SaveHistory();
+            return reclaimed;
+        }
+
+        // _history is only changed on the UI thread, so copy it there rather than enumerate it from a worker
+        private List<DictationRecord> SnapshotHistory() =>
+            System.Windows.Application.Current?.Dispatcher.Invoke(() => _history.ToList()) ?? new List<DictationRecord>();
+
+        /// <summary>
+        /// Writes (or rewrites) the plain-text copy of a record when transcript files are enabled.
+        /// Skipped while history encryption is on, so the text isn't left readable next to the encrypted file.
+        /// </summary>
+        public void WriteTranscriptFile(DictationRecord record)
+        {
+            var config = _configService.CurrentConfiguration;
+            if (!config.SaveTranscriptFiles || config.EncryptHistory) return;
+
+            try
+            {
+                // Reuse the existing file so edits stay in place
+                string? path = record.TranscriptFilePath;
+                if (string.IsNullOrEmpty(path))
+                {
+                    string dir = GetTranscriptsDirectory();
+                    Directory.CreateDirectory(dir);
+                    path = GetUniqueTranscriptPath(dir, $"{record.Timestamp:yyyyMMdd_HHmmss}");
+                }
+
+                File.WriteAllText(path, record.Content);
+                record.TranscriptFilePath = path;
+            }
+            catch (Exception ex)
+            {
+                System.Diagnostics.Debug.WriteLine($"Failed to write transcript file: {ex.Message}");
+            }
+        }
+
+        // Removes a record's saved recording and marks it unavailable. Returns the bytes freed.
+        // Only files in an audio folder this app manages are deleted; a path from an imported
+        // or hand-edited history file is never trusted to point somewhere safe.
+        private long DeleteRecordingFile(DictationRecord record)
+        {
+            if (string.IsNullOrEmpty(record.AudioFilePath)) return 0;
+
+            if (!IsManagedRecording(record.AudioFilePath))
+            {
+                System.Diagnostics.Debug.WriteLine($"Not deleting recording outside the audio folder: {record.AudioFilePath}");
+                MarkAudioUnavailable(record);
+                return 0;
+            }
+
+            try
+            {
+                var file = new FileInfo(record.AudioFilePath);
+                long size = 0;
+                if (file.Exists)
+                {
+                    size = file.Length;
+                    file.Delete();
+                }
+                MarkAudioUnavailable(record);
+                return size;
+            }
+            catch (Exception ex)
+            {
+                System.Diagnostics.Debug.WriteLine($"Failed to delete recording: {ex.Message}");
+                return 0;
+            }
+        }
+
+        // Records are shown by the History page, so their state changes on the UI thread like the collection
+        private static void MarkAudioUnavailable(DictationRecord record)
+        {
+            var dispatcher = System.Windows.Application.Current?.Dispatcher;
+            if (dispatcher == null) record.AudioAvailable = false;
+            else dispatcher.Invoke(() => record.AudioAvailable = false);
+        }
+
+        private string GetAudioDirectory() =>
+            Path.Combine(Path.GetDirectoryName(GetHistoryFilePath())!, AUDIO_FOLDER);
+
+        // True for files inside the current audio folder or the default one, which still holds
+        // recordings made before the history folder was moved
+        private bool IsManagedRecording(string path)
+        {
+            string defaultAudioDir = Path.Combine(
+                Environment.GetFolderPath(Environment.SpecialFolder.LocalApplicationData), "EliteWhisper", AUDIO_FOLDER);
+
+            return IsInsideAny(path, GetAudioDirectory(), defaultAudioDir);
+        }
+
+        // Same for transcript copies: the configured folder, the one next to the history file
+        // and the default one
+        private bool IsManagedTranscript(string path)
+        {
+            string defaultTranscriptsDir = Path.Combine(
+                Environment.GetFolderPath(Environment.SpecialFolder.LocalApplicationData), "EliteWhisper", TRANSCRIPTS_FOLDER);
+
+            return IsInsideAny(path, GetTranscriptsDirectory(),
+                Path.Combine(Path.GetDirectoryName(GetHistoryFilePath())!, TRANSCRIPTS_FOLDER), defaultTranscriptsDir);
+        }
+
+        private static bool IsInsideAny(string path, params string[] directories)
+        {
+            try
+            {
+                string fullPath = Path.GetFullPath(path);
+                return directories.Any(dir =>
+                    fullPath.StartsWith(Path.GetFullPath(dir).TrimEnd(Path.DirectorySeparatorChar) + Path.DirectorySeparatorChar,
+                        StringComparison.OrdinalIgnoreCase));
+            }
+            catch (Exception ex) when (ex is ArgumentException or NotSupportedException or PathTooLongException)
+            {
+                return false;
+            }
+        }
+
+        // Like recordings, only files in a transcripts folder this app manages are deleted
+        private void DeleteTranscriptFile(DictationRecord record)
+        {
+            if (string.IsNullOrEmpty(record.TranscriptFilePath)) return;
+
+            if (!IsManagedTranscript(record.TranscriptFilePath))
+            {
+                System.Diagnostics.Debug.WriteLine($"Not deleting transcript outside the transcripts folder: {record.TranscriptFilePath}");
+                return;
+            }
+
+            try
+            {
+                if (File.Exists(record.TranscriptFilePath))
+                {
+                    File.Delete(record.TranscriptFilePath);
+                }
+            }
+            catch (Exception ex)
+            {
+                System.Diagnostics.Debug.WriteLine($"Failed to delete transcript file: {ex.Message}");
+            }
+        }
+
+        private string GetTranscriptsDirectory()
+        {
+            string? customDir = _configService.CurrentConfiguration.TranscriptsDirectory;
+            if (!string.IsNullOrEmpty(customDir))
+            {
+                return customDir;
+            }
+
+            return Path.Combine(Path.GetDirectoryName(GetHistoryFilePath())!, TRANSCRIPTS_FOLDER);
+        }
+
+        private static string GetUniqueTranscriptPath(string dir, string baseName)
+        {
+            // Several dictations can land in the same second
+            string path = Path.Combine(dir, baseName + ".txt");
+            int suffix = 2;
+            while (File.Exists(path))
+            {
+                path = Path.Combine(dir, $"{baseName}_{suffix}.txt");
+                suffix++;
+            }
+            return path;
+        }
+
+        /// <summary>
+        /// Decrypts the history file with the passphrase and merges in anything recorded while locked.
+        /// Throws UnauthorizedAccessException when the passphrase is wrong.
+        /// </summary>
+        public void Unlock(string passphrase)
+        {
+            if (!IsLocked) return;
+
+            List<DictationRecord> records;
+            lock (_fileLock)
+            {
+                byte[] data = File.ReadAllBytes(GetHistoryFilePath());
+                var (salt, iterations) = HistoryEncryption.ReadHeader(data);
+                byte[] key = HistoryEncryption.DeriveKey(passphrase, salt, iterations);
+
+                byte[] plaintext;
+                try
+                {
+                    plaintext = HistoryEncryption.Decrypt(data, key);
+                }
+                catch (System.Security.Cryptography.CryptographicException)
+                {
+                    throw new UnauthorizedAccessException("Wrong passphrase.");
+                }
+
+                records = JsonSerializer.Deserialize<List<DictationRecord>>(plaintext) ?? new();
+                _key = key;
+                _salt = salt;
+                _iterations = iterations;
+                _passphrase = passphrase;
+            }
+
+            bool hadPending = _history.Count > 0;
+            System.Windows.Application.Current.Dispatcher.Invoke(() =>
+            {
+                var merged = _history.Concat(records).OrderByDescending(r => r.Timestamp).ToList();
+                _history.Clear();
+                foreach (var record in merged)
+                {
+                    _history.Add(record);
+                }
+            });
+
+            IsLocked = false;
+            LockStateChanged?.Invoke(this, EventArgs.Empty);
+
+            if (hadPending) Task.Run(SaveHistory);
+        }
+
+        /// <summary>
+        /// Encrypts history.json with a new passphrase, replacing the plaintext file.
+        /// Also used to change the passphrase while encryption is on.
+        /// </summary>
+        public void EnableEncryption(string passphrase)
+        {
+            if (string.IsNullOrEmpty(passphrase) || passphrase.Length < 8)
+                throw new ArgumentException("The passphrase must be at least 8 characters.", nameof(passphrase));
+            EnsureUnlocked();
+
+            lock (_fileLock)
+            {
+                _salt = HistoryEncryption.NewSalt();
+                _iterations = HistoryEncryption.DEFAULT_ITERATIONS;
+                _key = HistoryEncryption.DeriveKey(passphrase, _salt, _iterations);
+                _passphrase = passphrase;
+            }
+
+            _configService.SetHistoryEncryption(true);
+            SaveHistory();
+        }
+
+        /// <summary>
+        /// Writes history.json back as plain JSON and forgets the key.
+        /// </summary>
+        public void DisableEncryption()
+        {
+            EnsureUnlocked();
+
+            _configService.SetHistoryEncryption(false);
+            SaveHistory();
+
+            lock (_fileLock)
+            {
+                _key = null;
+                _salt = null;
+                _passphrase = null;
+            }
+        }
+
+        private void EnsureUnlocked()
+        {
+            if (IsLocked)
+                throw new InvalidOperationException("History is encrypted. Enter your passphrase to unlock it.");
+        }
+
         private void LoadHistory()
         {
             try
@@ -64,7 +898,19 @@ namespace EliteWhisper.Services
                 string path = GetHistoryFilePath();
                 if (File.Exists(path))
                 {
-                    string json = File.ReadAllText(path);
+                    byte[] data;
+                    lock (_fileLock)
+                    {
+                        data = File.ReadAllBytes(path);
+                    }
+
+                    if (HistoryEncryption.IsEncrypted(data))
+                    {
+                        data = TryDecrypt(data);
+                        if (IsLocked) return;
+                    }
+
+                    string json = System.Text.Encoding.UTF8.GetString(data).TrimStart('\uFEFF');
                     var records = JsonSerializer.Deserialize<List<DictationRecord>>(json);
                     
                     if (records != null)
@@ -82,6 +928,39 @@ namespace EliteWhisper.Services
             }
         }
 
+        // Uses the passphrase from this session if there is one; otherwise marks history as locked
+        private byte[] TryDecrypt(byte[] data)
+        {
+            lock (_fileLock)
+            {
+                if (_passphrase != null)
+                {
+                    try
+                    {
+                        var (salt, iterations) = HistoryEncryption.ReadHeader(data);
+                        byte[] key = HistoryEncryption.DeriveKey(_passphrase, salt, iterations);
+                        byte[] plaintext = HistoryEncryption.Decrypt(data, key);
+                        _key = key;
+                        _salt = salt;
+                        _iterations = iterations;
+                        IsLocked = false;
+                        return plaintext;
+                    }
+                    catch (System.Security.Cryptography.CryptographicException)
+                    {
+                        System.Diagnostics.Debug.WriteLine("[History] Cached passphrase doesn't open this history file");
+                    }
+                }
+
+                _key = null;
+                _salt = null;
+                IsLocked = true;
+            }
+
+            LockStateChanged?.Invoke(this, EventArgs.Empty);
+            return data;
+        }
+
         private void SaveHistory()
         {
             try
@@ -96,8 +975,32 @@ namespace EliteWhisper.Services
                 }
 
                 var options = new JsonSerializerOptions { WriteIndented = true };
-                string json = JsonSerializer.Serialize(_history.ToList(), options);
-                File.WriteAllText(path, json);
+                byte[] json = JsonSerializer.SerializeToUtf8Bytes(_history.ToList(), options);
+
+                lock (_fileLock)
+                {
+                    // Never overwrite a file we couldn't decrypt
+                    if (IsLocked)
+                    {
+                        System.Diagnostics.Debug.WriteLine("[History] History is locked; new items are kept in memory until unlock");
+                        return;
+                    }
+
+                    if (_configService.CurrentConfiguration.EncryptHistory)
+                    {
+                        if (_key == null || _salt == null)
+                        {
+                            System.Diagnostics.Debug.WriteLine("[History] Encryption is on but no passphrase was entered; not saving");
+                            return;
+                        }
+                        json = HistoryEncryption.Encrypt(json, _key, _salt, _iterations);
+                    }
+
+                    // Temp file + rename so a crash can't leave a half-written (or half-encrypted) history
+                    string tempPath = path + ".tmp";
+                    File.WriteAllBytes(tempPath, json);
+                    File.Move(tempPath, path, overwrite: true);
+                }
             }
             catch (Exception ex)
             {
@@ -130,6 +1033,7 @@ namespace EliteWhisper.Services
         {
              // Clear current and reload from new location
              System.Windows.Application.Current.Dispatcher.Invoke(() => _history.Clear());
+             IsLocked = false;
              LoadHistory();
         }
     }
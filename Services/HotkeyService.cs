@@ -1,6 +1,9 @@
 using System;
+using System.Collections.Generic;
+using System.Linq;
 using System.Runtime.InteropServices;
 using System.Windows.Interop;
+using EliteWhisper.Models;
 
 namespace EliteWhisper.Services
 {
@@ -12,17 +15,78 @@ namespace EliteWhisper.Services
         [DllImport("user32.dll")]
         private static extern bool UnregisterHotKey(IntPtr hWnd, int id);
 
+        [DllImport("user32.dll")]
+        private static extern short GetAsyncKeyState(int vKey);
+
+        // Dictation uses HOTKEY_ID; every other action registers HOTKEY_ID + (int)action
         private const int HOTKEY_ID = 9000;
         private const int WM_HOTKEY = 0x0312;
 
+        private const uint MOD_ALT = 0x0001;
+        private const uint MOD_CONTROL = 0x0002;
+        private const uint MOD_SHIFT = 0x0004;
+        private const uint MOD_WIN = 0x0008;
+
+        private readonly WhisperConfigurationService _configService;
+
         public event Action? OnHotkeyPressed;
+
+        /// <summary>
+        /// Push-to-talk only: raised once when the held shortcut is let go.
+        /// </summary>
+        public event Action? OnHotkeyReleased;
+
+        /// <summary>
+        /// Raised when the shortcut of an action other than dictation is pressed.
+        /// </summary>
+        public event Action<HotkeyAction>? ActionTriggered;
+
+        // Why an action's saved shortcut couldn't be registered at startup
+        private readonly Dictionary<HotkeyAction, string> _registrationErrors = new();
+
         private HwndSource? _source;
         private IntPtr _currentWindowHandle;
 
+        // Windows only reports hotkey presses, so the release is found by polling the key state.
+        // GetAsyncKeyState is global, so the release is seen even if another window has focus.
+        private System.Windows.Threading.DispatcherTimer? _releaseTimer;
+        private bool _isHeld;
+
+        public bool IsPushToTalk => _configService.CurrentConfiguration.HotkeyMode == Models.HotkeyMode.PushToTalk;
+
         // Default to F2 (VK_F2 = 0x71)
         public uint CurrentVirtualKey { get; set; } = 0x71;
         public uint CurrentModifiers { get; set; } = 0;
 
+        public HotkeyService(WhisperConfigurationService configService)
+        {
+            _configService = configService;
+            MigrateRegistryHotkey();
+
+            var config = _configService.CurrentConfiguration;
+            CurrentVirtualKey = config.HotkeyVirtualKey;
+            CurrentModifiers = config.HotkeyModifiers;
+        }
+
+        /// <summary>
+        /// Checks a shortcut before registering it. Returns an error message, or null if it is usable.
+        /// </summary>
+        public static string? ValidateHotkey(uint vk, uint modifiers)
+        {
+            if (vk == 0)
+                return "No key was pressed.";
+
+            if ((modifiers & ~(MOD_ALT | MOD_CONTROL | MOD_SHIFT | MOD_WIN)) != 0)
+                return "Unknown modifier keys.";
+
+            // Letters, digits and space alone would swallow normal typing
+            bool isTypingKey = vk == 0x20 || (vk >= 0x30 && vk <= 0x39) || (vk >= 0x41 && vk <= 0x5A);
+            if (isTypingKey && (modifiers & (MOD_ALT | MOD_CONTROL | MOD_WIN)) == 0)
+                return "Letters, digits and Space need Ctrl, Alt or Win as well.";
+
+            return null;
+        }
+
         public void Register(IntPtr windowHandle)
         {
             if (windowHandle == IntPtr.Zero)
@@ -46,13 +110,113 @@ namespace EliteWhisper.Services
             if (!success)
             {
                 System.Diagnostics.Debug.WriteLine($"Failed to register hotkey VK={CurrentVirtualKey:X} Modifiers={CurrentModifiers}");
+                _registrationErrors[HotkeyAction.ToggleRecording] = "In use by another application.";
+            }
+
+            foreach (var binding in _configService.CurrentConfiguration.ActionHotkeys)
+            {
+                if (binding.Action == HotkeyAction.ToggleRecording || !Enum.IsDefined(binding.Action)) continue;
+
+                if (!RegisterHotKey(windowHandle, HOTKEY_ID + (int)binding.Action, binding.Modifiers, binding.VirtualKey))
+                {
+                    System.Diagnostics.Debug.WriteLine($"Failed to register {binding.Action} hotkey VK={binding.VirtualKey:X} Modifiers={binding.Modifiers}");
+                    _registrationErrors[binding.Action] = "In use by another application.";
+                }
             }
         }
 
+        /// <summary>
+        /// Shortcut assigned to an action, or null if it has none.
+        /// </summary>
+        public HotkeyBinding? GetBinding(HotkeyAction action)
+        {
+            if (action == HotkeyAction.ToggleRecording)
+                return new HotkeyBinding { Action = action, VirtualKey = CurrentVirtualKey, Modifiers = CurrentModifiers };
+
+            return _configService.CurrentConfiguration.ActionHotkeys.FirstOrDefault(b => b.Action == action);
+        }
+
+        /// <summary>
+        /// Why the action's shortcut isn't active, or null if it registered fine (or has none).
+        /// </summary>
+        public string? GetRegistrationError(HotkeyAction action) =>
+            _registrationErrors.TryGetValue(action, out var error) ? error : null;
+
+        /// <summary>
+        /// Assigns a shortcut to one action and saves it. Returns an error message, or null on success;
+        /// on failure the action keeps its previous shortcut.
+        /// </summary>
+        public string? UpdateActionHotkey(HotkeyAction action, uint vk, uint modifiers)
+        {
+            string? error = ValidateHotkey(vk, modifiers);
+            if (error != null) return error;
+
+            foreach (HotkeyAction other in Enum.GetValues<HotkeyAction>())
+            {
+                if (other == action) continue;
+                var binding = GetBinding(other);
+                if (binding != null && binding.VirtualKey == vk && binding.Modifiers == modifiers)
+                    return $"Already used for \"{DescribeAction(other)}\".";
+            }
+
+            if (action == HotkeyAction.ToggleRecording)
+            {
+                if (!UpdateHotkey(vk, modifiers)) return "In use by another application.";
+                _registrationErrors.Remove(action);
+                return null;
+            }
+
+            if (_currentWindowHandle != IntPtr.Zero)
+            {
+                int id = HOTKEY_ID + (int)action;
+                var previous = GetBinding(action);
+                UnregisterHotKey(_currentWindowHandle, id);
+
+                if (!RegisterHotKey(_currentWindowHandle, id, modifiers, vk))
+                {
+                    System.Diagnostics.Debug.WriteLine($"Failed to update {action} hotkey to VK={vk:X} Modifiers={modifiers}");
+                    if (previous != null) RegisterHotKey(_currentWindowHandle, id, previous.Modifiers, previous.VirtualKey);
+                    return "In use by another application.";
+                }
+            }
+
+            _registrationErrors.Remove(action);
+            _configService.SetActionHotkey(action, vk, modifiers);
+            return null;
+        }
+
+        /// <summary>
+        /// Removes an action's shortcut. Dictation always keeps one.
+        /// </summary>
+        public void ClearActionHotkey(HotkeyAction action)
+        {
+            if (action == HotkeyAction.ToggleRecording) return;
+
+            if (_currentWindowHandle != IntPtr.Zero)
+            {
+                UnregisterHotKey(_currentWindowHandle, HOTKEY_ID + (int)action);
+            }
+            _registrationErrors.Remove(action);
+            _configService.SetActionHotkey(action, 0, 0);
+        }
+
+        public static string DescribeAction(HotkeyAction action) => action switch
+        {
+            HotkeyAction.ToggleRecording => "Start/stop dictation",
+            HotkeyAction.TranscribeClipboardAudio => "Transcribe clipboard audio",
+            HotkeyAction.Cancel => "Cancel",
+            HotkeyAction.RetypeLast => "Re-type last transcript",
+            HotkeyAction.RecopyLast => "Re-copy last transcript",
+            _ => action.ToString()
+        };
+
+        /// <summary>
+        /// Switches to a new shortcut and saves it. If the combination can't be registered
+        /// (usually because another application owns it) the previous shortcut stays active.
+        /// </summary>
         public bool UpdateHotkey(uint vk, uint modifiers)
         {
-            CurrentVirtualKey = vk;
-            CurrentModifiers = modifiers;
+            if (ValidateHotkey(vk, modifiers) != null) return false;
 
             if (_currentWindowHandle != IntPtr.Zero)
             {
@@ -60,15 +224,21 @@ namespace EliteWhisper.Services
                 UnregisterHotKey(_currentWindowHandle, HOTKEY_ID);
                 
                 // Register new hotkey
-                bool success = RegisterHotKey(_currentWindowHandle, HOTKEY_ID, CurrentModifiers, CurrentVirtualKey);
+                bool success = RegisterHotKey(_currentWindowHandle, HOTKEY_ID, modifiers, vk);
                 if (!success)
                 {
-                    System.Diagnostics.Debug.WriteLine($"Failed to update hotkey to VK={CurrentVirtualKey:X} Modifiers={CurrentModifiers}");
+                    System.Diagnostics.Debug.WriteLine($"Failed to update hotkey to VK={vk:X} Modifiers={modifiers}");
+
+                    // Put the old one back so the user isn't left without a shortcut
+                    RegisterHotKey(_currentWindowHandle, HOTKEY_ID, CurrentModifiers, CurrentVirtualKey);
                     return false;
                 }
-                return true;
             }
-            return false;
+
+            CurrentVirtualKey = vk;
+            CurrentModifiers = modifiers;
+            _configService.SetHotkey(vk, modifiers);
+            return true;
         }
 
         public void Unregister(IntPtr windowHandle)
@@ -78,19 +248,97 @@ namespace EliteWhisper.Services
                 _source.RemoveHook(HwndHook);
                 _source = null;
             }
-            UnregisterHotKey(windowHandle, HOTKEY_ID);
+            _releaseTimer?.Stop();
+            _isHeld = false;
+            foreach (HotkeyAction action in Enum.GetValues<HotkeyAction>())
+            {
+                UnregisterHotKey(windowHandle, HOTKEY_ID + (int)action);
+            }
             _currentWindowHandle = IntPtr.Zero;
         }
 
+        /// <summary>
+        /// Waits until Ctrl, Shift, Alt and Win are all up, or the timeout passes. Typing while the
+        /// shortcut's modifiers are still held would turn the text into key combinations.
+        /// </summary>
+        public static async System.Threading.Tasks.Task WaitForModifiersReleasedAsync(TimeSpan timeout)
+        {
+            var deadline = DateTime.UtcNow + timeout;
+            int[] modifierKeys = { 0x10, 0x11, 0x12, 0x5B, 0x5C }; // VK_SHIFT, VK_CONTROL, VK_MENU, VK_LWIN, VK_RWIN
+
+            while (DateTime.UtcNow < deadline && modifierKeys.Any(vk => (GetAsyncKeyState(vk) & 0x8000) != 0))
+            {
+                await System.Threading.Tasks.Task.Delay(30);
+            }
+        }
+
+        private void StartReleasePolling()
+        {
+            if (_releaseTimer == null)
+            {
+                _releaseTimer = new System.Windows.Threading.DispatcherTimer { Interval = TimeSpan.FromMilliseconds(30) };
+                _releaseTimer.Tick += (s, e) =>
+                {
+                    // High bit set = key currently down
+                    if ((GetAsyncKeyState((int)CurrentVirtualKey) & 0x8000) != 0) return;
+
+                    _releaseTimer.Stop();
+                    _isHeld = false;
+                    OnHotkeyReleased?.Invoke();
+                };
+            }
+
+            _releaseTimer.Start();
+        }
+
+        // Earlier versions stored the shortcut in the registry
+        private void MigrateRegistryHotkey()
+        {
+            try
+            {
+                using var regKey = Microsoft.Win32.Registry.CurrentUser.OpenSubKey(@"Software\EliteWhisper", writable: true);
+                var vk = regKey?.GetValue("HotkeyVK");
+                var modifiers = regKey?.GetValue("HotkeyModifiers");
+                if (regKey == null || vk == null || modifiers == null) return;
+
+                _configService.SetHotkey(Convert.ToUInt32(vk), Convert.ToUInt32(modifiers));
+                regKey.DeleteValue("HotkeyVK", false);
+                regKey.DeleteValue("HotkeyModifiers", false);
+            }
+            catch (Exception ex)
+            {
+                System.Diagnostics.Debug.WriteLine($"HotkeyService: Registry migration failed: {ex.Message}");
+            }
+        }
+
         private IntPtr HwndHook(IntPtr hwnd, int msg, IntPtr wParam, IntPtr lParam, ref bool handled)
         {
             if (msg == WM_HOTKEY)
             {
                 if (wParam.ToInt32() == HOTKEY_ID)
                 {
-                    OnHotkeyPressed?.Invoke();
+                    if (!IsPushToTalk)
+                    {
+                        OnHotkeyPressed?.Invoke();
+                    }
+                    else if (!_isHeld)
+                    {
+                        // Auto-repeat keeps sending WM_HOTKEY while held; only the first counts
+                        _isHeld = true;
+                        StartReleasePolling();
+                        OnHotkeyPressed?.Invoke();
+                    }
                     handled = true;
                 }
+                else
+                {
+                    var action = (HotkeyAction)(wParam.ToInt32() - HOTKEY_ID);
+                    if (action != HotkeyAction.ToggleRecording && Enum.IsDefined(action))
+                    {
+                        ActionTriggered?.Invoke(action);
+                        handled = true;
+                    }
+                }
             }
             return IntPtr.Zero;
         }
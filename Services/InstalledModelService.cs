@@ -0,0 +1,128 @@
+using System;
+using System.Collections.Generic;
+using System.IO;
+using System.Linq;
+using EliteWhisper.Models;
+using EliteWhisper.Services.Speech;
+
+namespace EliteWhisper.Services
+{
+    /// <summary>
+    /// Lists and deletes the speech models present in the models folder.
+    /// </summary>
+    public class InstalledModelService
+    {
+        private readonly WhisperConfigurationService _configService;
+
+        public InstalledModelService(WhisperConfigurationService configService)
+        {
+            _configService = configService;
+        }
+
+        public string ModelsDirectory
+        {
+            get
+            {
+                var config = _configService.CurrentConfiguration;
+                return config.ModelsDirectory ?? Path.Combine(config.BaseDirectory ?? AppDomain.CurrentDomain.BaseDirectory, "models");
+            }
+        }
+
+        /// <summary>
+        /// Whisper ggml .bin files and Sherpa model folders (detected the same way the engine loads them), largest first.
+        /// </summary>
+        public List<InstalledModelInfo> GetInstalledModels()
+        {
+            var models = new List<InstalledModelInfo>();
+            string dir = ModelsDirectory;
+            if (!Directory.Exists(dir)) return models;
+
+            string? activePath = _configService.CurrentConfiguration.DefaultModelPath;
+
+            foreach (var file in Directory.GetFiles(dir, "*.bin"))
+            {
+                models.Add(new InstalledModelInfo
+                {
+                    Name = Path.GetFileName(file),
+                    Path = file,
+                    SizeBytes = new FileInfo(file).Length,
+                    Kind = InstalledModelKind.Whisper,
+                    IsActive = IsSamePath(file, activePath)
+                });
+            }
+
+            foreach (var subDir in Directory.GetDirectories(dir))
+            {
+                // Staging folder of a multi-file download still in progress
+                if (subDir.EndsWith(ModelDownloadService.PARTIAL_EXTENSION, StringComparison.OrdinalIgnoreCase)) continue;
+
+                var type = SherpaOnnxEngine.DetectModelType(subDir);
+                if (type == null) continue;
+
+                models.Add(new InstalledModelInfo
+                {
+                    Name = Path.GetFileName(subDir),
+                    Path = subDir,
+                    SizeBytes = GetDirectorySize(subDir),
+                    Kind = InstalledModelKind.Sherpa,
+                    SherpaType = type,
+                    IsActive = IsSamePath(subDir, activePath)
+                });
+            }
+
+            return models.OrderByDescending(m => m.SizeBytes).ToList();
+        }
+
+        /// <summary>
+        /// Deletes a model file or folder. Refuses anything outside the models folder and the active model.
+        /// </summary>
+        public void DeleteModel(string path)
+        {
+            string modelsDir = Path.GetFullPath(ModelsDirectory).TrimEnd(Path.DirectorySeparatorChar) + Path.DirectorySeparatorChar;
+            string fullPath = Path.GetFullPath(path).TrimEnd(Path.DirectorySeparatorChar);
+
+            if (!fullPath.StartsWith(modelsDir, StringComparison.OrdinalIgnoreCase))
+                throw new InvalidOperationException("Only models inside the models folder can be deleted.");
+
+            if (IsSamePath(fullPath, _configService.CurrentConfiguration.DefaultModelPath))
+                throw new InvalidOperationException("The active model can't be deleted. Switch to another model first.");
+
+            if (Directory.Exists(fullPath))
+            {
+                Directory.Delete(fullPath, true);
+            }
+            else if (File.Exists(fullPath))
+            {
+                File.Delete(fullPath);
+            }
+            else
+            {
+                throw new FileNotFoundException("Model not found", fullPath);
+            }
+
+            System.Diagnostics.Debug.WriteLine($"[Models] Deleted {fullPath}");
+            _configService.RevalidateCurrentConfiguration();
+        }
+
+        private static long GetDirectorySize(string dir)
+        {
+            try
+            {
+                return Directory.EnumerateFiles(dir, "*", SearchOption.AllDirectories).Sum(f => new FileInfo(f).Length);
+            }
+            catch
+            {
+                return 0;
+            }
+        }
+
+        private static bool IsSamePath(string path, string? other)
+        {
+            if (string.IsNullOrEmpty(other)) return false;
+            return string.Equals(
+                Path.GetFullPath(path).TrimEnd(Path.DirectorySeparatorChar),
+                Path.GetFullPath(other).TrimEnd(Path.DirectorySeparatorChar),
+                StringComparison.OrdinalIgnoreCase);
+        }
+    }
+}
@@ -1,22 +1,74 @@
 using System;
+using System.Collections.Concurrent;
+using System.Collections.Generic;
 using System.IO;
+using System.Linq;
+using System.Net;
 using System.Net.Http;
+using System.Net.Http.Headers;
 using System.Threading;
 using System.Threading.Tasks;
+using EliteWhisper.Models;
 
 namespace EliteWhisper.Services
 {
     public class ModelDownloadService
     {
+        /// <summary>
+        /// Suffix of an unfinished download. It is kept when a download is canceled or fails so the next attempt can resume.
+        /// </summary>
+        public const string PARTIAL_EXTENSION = ".partial";
+
+        // Sidecar of a segmented .partial file listing how far each segment got
+        private const string SEGMENTS_EXTENSION = ".segments";
+
+        // Files at least this large are fetched over several connections when the server supports Range
+        private const long SEGMENTED_MIN_BYTES = 32L * 1024 * 1024;
+        private const int MAX_SEGMENTS = 4;
+
+        // How often each segment records its progress in the sidecar
+        private const long SEGMENT_SAVE_INTERVAL = 4L * 1024 * 1024;
+
+        // Download speed is averaged over this window and only reported once the download has run a moment
+        private static readonly TimeSpan SPEED_WINDOW = TimeSpan.FromSeconds(5);
+        private static readonly TimeSpan SPEED_MIN_ELAPSED = TimeSpan.FromSeconds(1);
+        private static readonly TimeSpan REPORT_INTERVAL = TimeSpan.FromMilliseconds(250);
+
         private readonly HttpClient _httpClient;
 
+        // Running downloads by destination file name, so they can be canceled from anywhere
+        private readonly ConcurrentDictionary<string, CancellationTokenSource> _activeDownloads = new(StringComparer.OrdinalIgnoreCase);
+
+        // Downloads stopped with PauseDownload, whose partial files are kept for resume
+        private readonly ConcurrentDictionary<string, byte> _pausedDownloads = new(StringComparer.OrdinalIgnoreCase);
+
+        /// <summary>
+        /// Raised with the destination file name when a download stops because it was canceled.
+        /// </summary>
+        public event EventHandler<string>? DownloadCanceled;
+
+        /// <summary>
+        /// Raised with the destination file name when a download stops because it was paused.
+        /// </summary>
+        public event EventHandler<string>? DownloadPaused;
+
+        /// <summary>
+        /// Raised with the destination file name when a paused download starts again.
+        /// </summary>
+        public event EventHandler<string>? DownloadResumed;
+
         public ModelDownloadService(IHttpClientFactory httpClientFactory)
         {
             _httpClient = httpClientFactory.CreateClient();
             _httpClient.Timeout = TimeSpan.FromHours(2); // Allow long downloads
         }
 
-        public async Task DownloadModelAsync(string url, string destinationPath, IProgress<double> progress, CancellationToken cancellationToken)
+        /// <summary>
+        /// Downloads to destinationPath via a .partial file, resuming an existing .partial with an HTTP Range request.
+        /// The file only gets its final name once complete. Throws <see cref="DownloadPausedException"/> when paused;
+        /// a canceled download throws OperationCanceledException and discards its partial file.
+        /// </summary>
+        public async Task DownloadModelAsync(string url, string destinationPath, IProgress<DownloadProgress> progress, CancellationToken cancellationToken)
         {
             // Ensure directory exists
             var dir = Path.GetDirectoryName(destinationPath);
@@ -25,29 +77,423 @@ namespace EliteWhisper.Services
                 Directory.CreateDirectory(dir);
             }
 
-            using var response = await _httpClient.GetAsync(url, HttpCompletionOption.ResponseHeadersRead, cancellationToken);
-            response.EnsureSuccessStatusCode();
+            string fileName = Path.GetFileName(destinationPath);
+            string partialPath = destinationPath + PARTIAL_EXTENSION;
+
+            using var cts = CancellationTokenSource.CreateLinkedTokenSource(cancellationToken);
+            if (!_activeDownloads.TryAdd(fileName, cts))
+            {
+                throw new InvalidOperationException($"{fileName} is already being downloaded.");
+            }
+
+            if (_pausedDownloads.TryRemove(fileName, out _))
+            {
+                System.Diagnostics.Debug.WriteLine($"[Download] Resuming paused {fileName}");
+                DownloadResumed?.Invoke(this, fileName);
+            }
+
+            try
+            {
+                string segmentsPath = partialPath + SEGMENTS_EXTENSION;
+                if (File.Exists(segmentsPath))
+                {
+                    // Resume an interrupted segmented download; if that no longer works, start over on one stream
+                    var segments = File.Exists(partialPath) ? LoadSegments(segmentsPath, new FileInfo(partialPath).Length) : null;
+                    if (segments != null && await TryDownloadSegmentedAsync(url, partialPath, segments, progress, cts.Token))
+                    {
+                        File.Move(partialPath, destinationPath, overwrite: true);
+                        return;
+                    }
+
+                    File.Delete(segmentsPath);
+                    if (File.Exists(partialPath)) File.Delete(partialPath);
+                }
+
+                long existing = File.Exists(partialPath) ? new FileInfo(partialPath).Length : 0;
+                var response = await SendAsync(url, existing, cts.Token);
+
+                // The partial file is no longer valid for this resource (e.g. it changed on the server)
+                if (response.StatusCode == HttpStatusCode.RequestedRangeNotSatisfiable)
+                {
+                    response.Dispose();
+                    File.Delete(partialPath);
+                    existing = 0;
+                    response = await SendAsync(url, 0, cts.Token);
+                }
+
+                // A fresh download of a large file is split across connections when the server allows it
+                if (existing == 0 && SupportsRanges(response) &&
+                    response.Content.Headers.ContentLength is long length && length >= SEGMENTED_MIN_BYTES)
+                {
+                    response.Dispose();
+                    var segments = CreateSegments(length);
+                    using (var fileStream = new FileStream(partialPath, FileMode.Create, FileAccess.Write, FileShare.None))
+                    {
+                        fileStream.SetLength(length);
+                    }
+                    SaveSegments(segmentsPath, segments);
+
+                    if (await TryDownloadSegmentedAsync(url, partialPath, segments, progress, cts.Token))
+                    {
+                        File.Move(partialPath, destinationPath, overwrite: true);
+                        return;
+                    }
+
+                    // The server refused a segment after all; fall back to a single stream
+                    File.Delete(segmentsPath);
+                    File.Delete(partialPath);
+                    response = await SendAsync(url, 0, cts.Token);
+                }
+
+                using (response)
+                {
+                    response.EnsureSuccessStatusCode();
+
+                    // A server that ignores Range answers 200 with the whole file; start over then
+                    bool resumed = existing > 0 && response.StatusCode == HttpStatusCode.PartialContent;
+                    if (!resumed) existing = 0;
+
+                    if (resumed)
+                    {
+                        System.Diagnostics.Debug.WriteLine($"[Download] Resuming {fileName} at {existing} bytes");
+                    }
+
+                    var contentLength = response.Content.Headers.ContentLength;
+                    var totalBytes = contentLength.HasValue ? contentLength.Value + existing : -1L;
+                    var meter = new ProgressMeter(progress, totalBytes, existing);
+
+                    using (var contentStream = await response.Content.ReadAsStreamAsync(cts.Token))
+                    using (var fileStream = new FileStream(partialPath, resumed ? FileMode.Append : FileMode.Create, FileAccess.Write, FileShare.None, 8192, true))
+                    {
+                        var buffer = new byte[8192];
+                        long totalRead = existing;
+                        int bytesRead;
+
+                        while ((bytesRead = await contentStream.ReadAsync(buffer, 0, buffer.Length, cts.Token)) > 0)
+                        {
+                            await fileStream.WriteAsync(buffer, 0, bytesRead, cts.Token);
+                            totalRead += bytesRead;
+
+                            meter.Update(totalRead);
+                        }
+                    }
+                }
+
+                File.Move(partialPath, destinationPath, overwrite: true);
+            }
+            catch (OperationCanceledException) when (_pausedDownloads.ContainsKey(fileName))
+            {
+                // Partial file and segment sidecar are already on disk; the next download picks them up
+                System.Diagnostics.Debug.WriteLine($"[Download] Paused {fileName}, keeping partial file for resume");
+                DownloadPaused?.Invoke(this, fileName);
+                throw new DownloadPausedException(fileName);
+            }
+            catch (OperationCanceledException)
+            {
+                System.Diagnostics.Debug.WriteLine($"[Download] Canceled {fileName}, discarding partial file");
+                DeletePartialDownload(destinationPath);
+                DownloadCanceled?.Invoke(this, fileName);
+                throw;
+            }
+            finally
+            {
+                _activeDownloads.TryRemove(fileName, out _);
+            }
+        }
+
+        /// <summary>
+        /// Stops a running download and discards its .partial file. Returns false if it wasn't running.
+        /// </summary>
+        public bool CancelDownload(string fileName)
+        {
+            if (_activeDownloads.TryGetValue(fileName, out var cts))
+            {
+                _pausedDownloads.TryRemove(fileName, out _);
+                cts.Cancel();
+                return true;
+            }
 
-            var totalBytes = response.Content.Headers.ContentLength ?? -1L;
-            var canReportProgress = totalBytes != -1;
+            return false;
+        }
 
-            using var contentStream = await response.Content.ReadAsStreamAsync(cancellationToken);
-            using var fileStream = new FileStream(destinationPath, FileMode.Create, FileAccess.Write, FileShare.None, 8192, true);
+        /// <summary>
+        /// Stops a running download but keeps its .partial file, so downloading it again resumes
+        /// with a Range request. Returns false if it wasn't running.
+        /// </summary>
+        public bool PauseDownload(string fileName)
+        {
+            if (_activeDownloads.TryGetValue(fileName, out var cts))
+            {
+                _pausedDownloads[fileName] = 0;
+                cts.Cancel();
+                return true;
+            }
 
-            var buffer = new byte[8192];
-            long totalRead = 0;
-            int bytesRead;
+            return false;
+        }
+
+        /// <summary>
+        /// True if the file was paused in this session and hasn't been resumed or canceled since.
+        /// </summary>
+        public bool IsPaused(string fileName) => _pausedDownloads.ContainsKey(fileName);
+
+        /// <summary>
+        /// True if an unfinished download of destinationPath is on disk, e.g. paused or interrupted in an earlier session.
+        /// </summary>
+        public static bool HasPartialDownload(string destinationPath) =>
+            File.Exists(destinationPath + PARTIAL_EXTENSION);
+
+        /// <summary>
+        /// Deletes the .partial file of a download that isn't running, e.g. one that was paused and is now abandoned.
+        /// </summary>
+        public void DeletePartialDownload(string destinationPath)
+        {
+            string partialPath = destinationPath + PARTIAL_EXTENSION;
+            _pausedDownloads.TryRemove(Path.GetFileName(destinationPath), out _);
+
+            try
+            {
+                if (File.Exists(partialPath)) File.Delete(partialPath);
+                if (File.Exists(partialPath + SEGMENTS_EXTENSION)) File.Delete(partialPath + SEGMENTS_EXTENSION);
+            }
+            catch (IOException ex)
+            {
+                System.Diagnostics.Debug.WriteLine($"[Download] Could not delete partial file {partialPath}: {ex.Message}");
+            }
+        }
 
-            while ((bytesRead = await contentStream.ReadAsync(buffer, 0, buffer.Length, cancellationToken)) > 0)
+        private async Task<HttpResponseMessage> SendAsync(string url, long rangeStart, CancellationToken cancellationToken, long? rangeEnd = null)
+        {
+            using var request = new HttpRequestMessage(HttpMethod.Get, url);
+            if (rangeStart > 0 || rangeEnd != null)
             {
-                await fileStream.WriteAsync(buffer, 0, bytesRead, cancellationToken);
-                totalRead += bytesRead;
+                request.Headers.Range = new RangeHeaderValue(rangeStart, rangeEnd);
+            }
+
+            return await _httpClient.SendAsync(request, HttpCompletionOption.ResponseHeadersRead, cancellationToken);
+        }
+
+        private static bool SupportsRanges(HttpResponseMessage response) =>
+            response.StatusCode == HttpStatusCode.OK &&
+            response.Headers.AcceptRanges.Contains("bytes", StringComparer.OrdinalIgnoreCase);
+
+        /// <summary>
+        /// A byte range of a segmented download. End is inclusive.
+        /// </summary>
+        private class Segment
+        {
+            public long Start { get; init; }
+            public long End { get; init; }
+            public long Downloaded;
 
-                if (canReportProgress)
+            public long Length => End - Start + 1;
+            public bool IsComplete => Downloaded >= Length;
+        }
+
+        private static List<Segment> CreateSegments(long totalBytes)
+        {
+            var segments = new List<Segment>();
+            long size = (totalBytes + MAX_SEGMENTS - 1) / MAX_SEGMENTS;
+            for (long start = 0; start < totalBytes; start += size)
+            {
+                segments.Add(new Segment { Start = start, End = Math.Min(start + size, totalBytes) - 1 });
+            }
+            return segments;
+        }
+
+        /// <summary>
+        /// Reads the sidecar written by <see cref="SaveSegments"/>. Returns null if it doesn't describe a file of the given length.
+        /// </summary>
+        private static List<Segment>? LoadSegments(string path, long fileLength)
+        {
+            try
+            {
+                var segments = new List<Segment>();
+                foreach (var line in File.ReadAllLines(path))
                 {
-                    progress?.Report((double)totalRead / totalBytes * 100);
+                    var parts = line.Split(' ');
+                    if (parts.Length != 3) return null;
+                    var segment = new Segment { Start = long.Parse(parts[0]), End = long.Parse(parts[1]) };
+                    segment.Downloaded = Math.Min(long.Parse(parts[2]), segment.Length);
+                    segments.Add(segment);
                 }
+
+                if (segments.Count == 0 || segments[^1].End + 1 != fileLength) return null;
+                return segments;
             }
+            catch (Exception ex) when (ex is IOException or FormatException or OverflowException)
+            {
+                return null;
+            }
+        }
+
+        private static void SaveSegments(string path, List<Segment> segments)
+        {
+            lock (segments)
+            {
+                File.WriteAllLines(path, segments.Select(s => $"{s.Start} {s.End} {Interlocked.Read(ref s.Downloaded)}"));
+            }
+        }
+
+        /// <summary>
+        /// Downloads the unfinished segments in parallel into the pre-allocated partial file.
+        /// Returns false if the server answered a segment without honoring its Range.
+        /// </summary>
+        private async Task<bool> TryDownloadSegmentedAsync(string url, string partialPath, List<Segment> segments, IProgress<DownloadProgress>? progress, CancellationToken cancellationToken)
+        {
+            string segmentsPath = partialPath + SEGMENTS_EXTENSION;
+            long totalBytes = segments[^1].End + 1;
+            long totalRead = segments.Sum(s => s.Downloaded);
+            System.Diagnostics.Debug.WriteLine($"[Download] {Path.GetFileName(partialPath)}: {segments.Count} segments, {totalRead}/{totalBytes} bytes done");
+            var meter = new ProgressMeter(progress, totalBytes, totalRead);
+
+            // One failing segment stops the others
+            using var segmentCts = CancellationTokenSource.CreateLinkedTokenSource(cancellationToken);
+
+            async Task DownloadSegmentAsync(Segment segment)
+            {
+                using var response = await SendAsync(url, segment.Start + segment.Downloaded, segmentCts.Token, segment.End);
+                response.EnsureSuccessStatusCode();
+                if (response.StatusCode != HttpStatusCode.PartialContent)
+                {
+                    throw new NotSupportedException("Server ignored the Range request.");
+                }
+
+                using var contentStream = await response.Content.ReadAsStreamAsync(segmentCts.Token);
+                using var fileStream = new FileStream(partialPath, FileMode.Open, FileAccess.Write, FileShare.Write, 8192, true);
+                fileStream.Seek(segment.Start + segment.Downloaded, SeekOrigin.Begin);
+
+                var buffer = new byte[8192];
+                long sinceSave = 0;
+                int bytesRead;
+
+                while (!segment.IsComplete &&
+                       (bytesRead = await contentStream.ReadAsync(buffer, 0, (int)Math.Min(buffer.Length, segment.Length - segment.Downloaded), segmentCts.Token)) > 0)
+                {
+                    await fileStream.WriteAsync(buffer, 0, bytesRead, segmentCts.Token);
+                    Interlocked.Add(ref segment.Downloaded, bytesRead);
+                    long read = Interlocked.Add(ref totalRead, bytesRead);
+                    meter.Update(read);
+
+                    // Only record bytes that reached the disk, so a crash at worst re-downloads a little
+                    sinceSave += bytesRead;
+                    if (sinceSave >= SEGMENT_SAVE_INTERVAL)
+                    {
+                        await fileStream.FlushAsync(segmentCts.Token);
+                        SaveSegments(segmentsPath, segments);
+                        sinceSave = 0;
+                    }
+                }
+
+                if (!segment.IsComplete)
+                {
+                    throw new IOException("Connection closed before the segment was complete.");
+                }
+            }
+
+            var tasks = segments.Where(s => !s.IsComplete).Select(async segment =>
+            {
+                try
+                {
+                    await DownloadSegmentAsync(segment);
+                }
+                catch
+                {
+                    segmentCts.Cancel();
+                    throw;
+                }
+            }).ToList();
+
+            try
+            {
+                await Task.WhenAll(tasks);
+            }
+            catch when (tasks.Any(t => t.Exception?.InnerException is NotSupportedException))
+            {
+                return false;
+            }
+            catch when (!cancellationToken.IsCancellationRequested)
+            {
+                // Report the segment that actually failed rather than the cancellation of its siblings
+                var failure = tasks.Select(t => t.Exception?.InnerException).FirstOrDefault(e => e is not null and not OperationCanceledException);
+                if (failure != null) System.Runtime.ExceptionServices.ExceptionDispatchInfo.Capture(failure).Throw();
+                throw;
+            }
+            finally
+            {
+                // Written data is already flushed when the streams close; keep the sidecar for resume
+                SaveSegments(segmentsPath, segments);
+            }
+
+            File.Delete(segmentsPath);
+            return true;
+        }
+
+        /// <summary>
+        /// Turns byte counts into throttled progress reports with a moving-average speed and ETA.
+        /// Thread-safe, since segments of one download report concurrently.
+        /// </summary>
+        private class ProgressMeter
+        {
+            private readonly IProgress<DownloadProgress>? _progress;
+            private readonly long _totalBytes;
+            private readonly System.Diagnostics.Stopwatch _clock = System.Diagnostics.Stopwatch.StartNew();
+            private readonly Queue<(TimeSpan Time, long Bytes)> _samples = new();
+            private TimeSpan _lastReport = TimeSpan.Zero;
+
+            public ProgressMeter(IProgress<DownloadProgress>? progress, long totalBytes, long startBytes)
+            {
+                _progress = progress;
+                _totalBytes = totalBytes;
+
+                // Bytes resumed from disk don't count towards the speed
+                _samples.Enqueue((TimeSpan.Zero, startBytes));
+            }
+
+            public void Update(long bytesDownloaded)
+            {
+                if (_progress == null) return;
+
+                lock (_samples)
+                {
+                    var now = _clock.Elapsed;
+                    bool finished = _totalBytes > 0 && bytesDownloaded >= _totalBytes;
+                    if (!finished && now - _lastReport < REPORT_INTERVAL) return;
+                    _lastReport = now;
+
+                    _samples.Enqueue((now, bytesDownloaded));
+                    while (_samples.Count > 2 && now - _samples.Peek().Time > SPEED_WINDOW)
+                    {
+                        _samples.Dequeue();
+                    }
+
+                    var oldest = _samples.Peek();
+                    double elapsed = (now - oldest.Time).TotalSeconds;
+                    double speed = now >= SPEED_MIN_ELAPSED && elapsed > 0 ? (bytesDownloaded - oldest.Bytes) / elapsed : 0;
+
+                    _progress.Report(new DownloadProgress
+                    {
+                        Percent = _totalBytes > 0 ? (double)bytesDownloaded / _totalBytes * 100 : 0,
+                        BytesDownloaded = bytesDownloaded,
+                        TotalBytes = _totalBytes,
+                        BytesPerSecond = speed,
+                        EtaSeconds = speed > 0 && _totalBytes > 0 ? Math.Max(0, _totalBytes - bytesDownloaded) / speed : null
+                    });
+                }
+            }
+        }
+    }
+
+    /// <summary>
+    /// Thrown by <see cref="ModelDownloadService.DownloadModelAsync"/> when the download was paused rather than canceled.
+    /// </summary>
+    public class DownloadPausedException : OperationCanceledException
+    {
+        public string FileName { get; }
+
+        public DownloadPausedException(string fileName) : base($"Download of {fileName} was paused.")
+        {
+            FileName = fileName;
         }
     }
 }
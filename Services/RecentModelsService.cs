@@ -0,0 +1,110 @@
+using System;
+using System.Collections.Generic;
+using System.IO;
+using System.Linq;
+using System.Text.Json;
+using EliteWhisper.Models;
+
+namespace EliteWhisper.Services
+{
+    /// <summary>
+    /// Tracks recently activated speech models (recent_models.json) so the tray can offer quick switching.
+    /// </summary>
+    public class RecentModelsService
+    {
+        private const string RECENT_FILENAME = "recent_models.json";
+        private const int MAX_ENTRIES = 10;
+
+        private readonly string _filePath;
+        private readonly object _lock = new object();
+        private List<RecentModelEntry> _entries = new();
+
+        public RecentModelsService()
+            : this(Path.Combine(Environment.GetFolderPath(Environment.SpecialFolder.LocalApplicationData), "EliteWhisper"))
+        {
+        }
+
+        internal RecentModelsService(string directory)
+        {
+            Directory.CreateDirectory(directory);
+            _filePath = Path.Combine(directory, RECENT_FILENAME);
+
+            Load();
+        }
+
+        /// <summary>
+        /// Most recently used models first, skipping models that were deleted from disk.
+        /// </summary>
+        public IReadOnlyList<RecentModelEntry> GetRecentModels(int count = MAX_ENTRIES)
+        {
+            lock (_lock)
+            {
+                int before = _entries.Count;
+                _entries.RemoveAll(e => !File.Exists(e.Path) && !Directory.Exists(e.Path));
+                if (_entries.Count != before) Save();
+
+                return _entries.Take(count).ToList();
+            }
+        }
+
+        /// <summary>
+        /// Records that a model was just activated.
+        /// </summary>
+        public void RecordUsage(string modelPath, string engine)
+        {
+            if (string.IsNullOrEmpty(modelPath)) return;
+
+            lock (_lock)
+            {
+                _entries.RemoveAll(e => string.Equals(e.Path, modelPath, StringComparison.OrdinalIgnoreCase));
+                _entries.Insert(0, new RecentModelEntry
+                {
+                    Name = WhisperConfigurationService.GetModelDisplayName(modelPath),
+                    Path = modelPath,
+                    Engine = engine,
+                    LastUsed = DateTime.Now
+                });
+
+                if (_entries.Count > MAX_ENTRIES)
+                {
+                    _entries.RemoveRange(MAX_ENTRIES, _entries.Count - MAX_ENTRIES);
+                }
+
+                Save();
+            }
+        }
+
+        private void Load()
+        {
+            try
+            {
+                if (File.Exists(_filePath))
+                {
+                    string json = File.ReadAllText(_filePath);
+                    var entries = JsonSerializer.Deserialize<List<RecentModelEntry>>(json);
+                    if (entries != null)
+                    {
+                        _entries = entries.OrderByDescending(e => e.LastUsed).ToList();
+                    }
+                }
+            }
+            catch (Exception ex)
+            {
+                System.Diagnostics.Debug.WriteLine($"Failed to load recent models: {ex.Message}");
+            }
+        }
+
+        private void Save()
+        {
+            try
+            {
+                var options = new JsonSerializerOptions { WriteIndented = true };
+                File.WriteAllText(_filePath, JsonSerializer.Serialize(_entries, options));
+            }
+            catch (Exception ex)
+            {
+                System.Diagnostics.Debug.WriteLine($"Failed to save recent models: {ex.Message}");
+            }
+        }
+    }
+}
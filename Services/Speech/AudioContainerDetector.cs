@@ -0,0 +1,92 @@
+using System;
+using System.IO;
+using NAudio.Wave;
+
+namespace EliteWhisper.Services.Speech
+{
+    public enum AudioContainer
+    {
+        Unknown,
+        Wav,
+        Mp3,
+        Flac,
+        Ogg
+    }
+
+    /// <summary>
+    /// Identifies an audio file by its leading bytes rather than its extension and opens the matching decoder.
+    /// </summary>
+    public static class AudioContainerDetector
+    {
+        public static AudioContainer Detect(string filePath)
+        {
+            var header = new byte[12];
+            int read;
+            using (var stream = File.OpenRead(filePath))
+            {
+                read = stream.Read(header, 0, header.Length);
+            }
+
+            return Detect(header.AsSpan(0, read));
+        }
+
+        public static AudioContainer Detect(ReadOnlySpan<byte> header)
+        {
+            if (header.Length >= 12 && StartsWith(header, "RIFF") && StartsWith(header.Slice(8), "WAVE"))
+                return AudioContainer.Wav;
+
+            if (StartsWith(header, "fLaC"))
+                return AudioContainer.Flac;
+
+            if (StartsWith(header, "OggS"))
+                return AudioContainer.Ogg;
+
+            // ID3v2 tag, or a bare MPEG audio frame (11 sync bits set)
+            if (StartsWith(header, "ID3"))
+                return AudioContainer.Mp3;
+            if (header.Length >= 2 && header[0] == 0xFF && (header[1] & 0xE0) == 0xE0)
+                return AudioContainer.Mp3;
+
+            return AudioContainer.Unknown;
+        }
+
+        /// <summary>
+        /// Opens a decoder for the detected container. Unknown files are handed to Media Foundation,
+        /// which covers formats such as M4A/AAC and WMA.
+        /// </summary>
+        public static WaveStream OpenReader(string filePath, AudioContainer container)
+        {
+            try
+            {
+                return container switch
+                {
+                    AudioContainer.Wav => new WaveFileReader(filePath),
+                    AudioContainer.Mp3 => new Mp3FileReader(filePath),
+                    // Media Foundation ships a FLAC decoder since Windows 10
+                    AudioContainer.Flac => new MediaFoundationReader(filePath),
+                    AudioContainer.Ogg => throw new NotSupportedException("Ogg audio isn't supported. Please convert it to WAV, MP3 or FLAC."),
+                    _ => new MediaFoundationReader(filePath)
+                };
+            }
+            catch (NotSupportedException)
+            {
+                throw;
+            }
+            catch (Exception ex)
+            {
+                string name = container == AudioContainer.Unknown ? "audio" : container.ToString().ToUpperInvariant();
+                throw new InvalidDataException($"Could not decode {name} file: {ex.Message}", ex);
+            }
+        }
+
+        private static bool StartsWith(ReadOnlySpan<byte> data, string magic)
+        {
+            if (data.Length < magic.Length) return false;
+            for (int i = 0; i < magic.Length; i++)
+            {
+                if (data[i] != (byte)magic[i]) return false;
+            }
+            return true;
+        }
+    }
+}
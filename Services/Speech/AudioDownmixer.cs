@@ -0,0 +1,52 @@
+using System;
+
+namespace EliteWhisper.Services.Speech
+{
+    /// <summary>
+    /// Mixes interleaved multichannel samples (stereo, quad, 5.1, ...) down to mono by averaging
+    /// each frame, so no channel's speech is lost the way it is when only the first channel is kept.
+    /// </summary>
+    public static class AudioDownmixer
+    {
+        /// <summary>
+        /// Returns one sample per frame. Mono input is returned as is. An incomplete trailing frame
+        /// (a truncated or malformed file) is averaged over the samples it has.
+        /// </summary>
+        public static float[] ToMono(float[] samples, int channels)
+        {
+            if (channels < 1)
+                throw new ArgumentOutOfRangeException(nameof(channels), "Channel count must be at least 1.");
+            if (channels == 1) return samples;
+
+            int frames = samples.Length / channels;
+            int remainder = samples.Length % channels;
+            var mono = new float[frames + (remainder > 0 ? 1 : 0)];
+
+            if (channels == 2)
+            {
+                for (int i = 0; i < frames; i++)
+                    mono[i] = (samples[2 * i] + samples[2 * i + 1]) * 0.5f;
+            }
+            else
+            {
+                for (int i = 0, s = 0; i < frames; i++)
+                {
+                    float sum = 0;
+                    for (int c = 0; c < channels; c++, s++)
+                        sum += samples[s];
+                    mono[i] = sum / channels;
+                }
+            }
+
+            if (remainder > 0)
+            {
+                float sum = 0;
+                for (int s = frames * channels; s < samples.Length; s++)
+                    sum += samples[s];
+                mono[frames] = sum / remainder;
+            }
+
+            return mono;
+        }
+    }
+}
@@ -0,0 +1,74 @@
+using System;
+using System.Globalization;
+
+namespace EliteWhisper.Services.Speech
+{
+    /// <summary>
+    /// Tiny audio fingerprint for spotting duplicate recordings independently of the transcript.
+    /// The clip is split into 65 equal segments; each bit records whether the log energy rises
+    /// from one segment to the next. The result is stored as "v1:&lt;16 hex digits&gt;:&lt;duration ms&gt;".
+    /// </summary>
+    public static class AudioFingerprint
+    {
+        private const string VERSION = "v1";
+        private const int BITS = 64;
+
+        /// <summary>
+        /// Fingerprints 16kHz mono samples. Returns null for clips too short to be meaningful.
+        /// </summary>
+        public static string? Compute(float[] samples, int sampleRate)
+        {
+            int segmentLength = samples.Length / (BITS + 1);
+            if (segmentLength < sampleRate / 100) return null; // under 10ms per segment
+
+            var energies = new double[BITS + 1];
+            for (int s = 0; s <= BITS; s++)
+            {
+                double sum = 0;
+                int start = s * segmentLength;
+                for (int i = start; i < start + segmentLength; i++)
+                    sum += samples[i] * samples[i];
+                energies[s] = Math.Log10(sum / segmentLength + 1e-10);
+            }
+
+            ulong hash = 0;
+            for (int b = 0; b < BITS; b++)
+            {
+                if (energies[b + 1] > energies[b])
+                    hash |= 1UL << b;
+            }
+
+            long durationMs = (long)samples.Length * 1000 / sampleRate;
+            return $"{VERSION}:{hash:x16}:{durationMs}";
+        }
+
+        /// <summary>
+        /// Number of differing bits between two fingerprints, or null if they can't be compared
+        /// (different versions, malformed, or durations more than 20% apart).
+        /// </summary>
+        public static int? Distance(string? a, string? b)
+        {
+            if (!TryParse(a, out ulong hashA, out long durationA) || !TryParse(b, out ulong hashB, out long durationB))
+                return null;
+
+            long longer = Math.Max(durationA, durationB);
+            if (longer == 0 || Math.Abs(durationA - durationB) > longer / 5)
+                return null;
+
+            return System.Numerics.BitOperations.PopCount(hashA ^ hashB);
+        }
+
+        private static bool TryParse(string? fingerprint, out ulong hash, out long durationMs)
+        {
+            hash = 0;
+            durationMs = 0;
+            if (string.IsNullOrEmpty(fingerprint)) return false;
+
+            var parts = fingerprint.Split(':');
+            return parts.Length == 3 &&
+                   parts[0] == VERSION &&
+                   ulong.TryParse(parts[1], NumberStyles.HexNumber, CultureInfo.InvariantCulture, out hash) &&
+                   long.TryParse(parts[2], NumberStyles.Integer, CultureInfo.InvariantCulture, out durationMs);
+        }
+    }
+}
@@ -0,0 +1,63 @@
+using System;
+
+namespace EliteWhisper.Services.Speech
+{
+    /// <summary>
+    /// Peak normalization for quiet recordings (e.g. laptop microphones), so the loudest sample
+    /// reaches a target level before transcription. Clips whose peak is below a noise floor are
+    /// left alone so silence isn't amplified into loud noise.
+    /// </summary>
+    public static class AudioNormalizer
+    {
+        // Below this peak a clip is treated as silence or noise, not quiet speech
+        private const double NOISE_FLOOR_DB = -45.0;
+
+        // Upper bound on the gain, so a clip just above the floor isn't boosted beyond recognition
+        private const double MAX_GAIN_DB = 30.0;
+
+        /// <summary>
+        /// Replaces NaN and infinite samples with silence. Returns how many were replaced.
+        /// </summary>
+        public static int Sanitize(float[] samples)
+        {
+            int replaced = 0;
+            for (int i = 0; i < samples.Length; i++)
+            {
+                if (!float.IsFinite(samples[i]))
+                {
+                    samples[i] = 0f;
+                    replaced++;
+                }
+            }
+            return replaced;
+        }
+
+        /// <summary>
+        /// Scales the samples in place so the peak reaches targetDb (dBFS). Returns the gain applied in dB,
+        /// 0 when the clip is already at or above the target, or below the noise floor.
+        /// </summary>
+        public static double Normalize(float[] samples, double targetDb)
+        {
+            float peak = 0f;
+            for (int i = 0; i < samples.Length; i++)
+            {
+                float abs = Math.Abs(samples[i]);
+                if (abs > peak) peak = abs;
+            }
+
+            if (peak <= 0f) return 0;
+
+            double peakDb = 20 * Math.Log10(peak);
+            if (peakDb < NOISE_FLOOR_DB || peakDb >= targetDb) return 0;
+
+            double gainDb = Math.Min(targetDb - peakDb, MAX_GAIN_DB);
+            float gain = (float)Math.Pow(10, gainDb / 20);
+            for (int i = 0; i < samples.Length; i++)
+            {
+                samples[i] = Math.Clamp(samples[i] * gain, -1f, 1f);
+            }
+
+            return gainDb;
+        }
+    }
+}
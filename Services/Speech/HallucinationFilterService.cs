@@ -0,0 +1,197 @@
+using System;
+using System.Collections.Generic;
+using System.IO;
+using System.Linq;
+using System.Text.Json;
+using System.Text.RegularExpressions;
+using EliteWhisper.Models;
+
+namespace EliteWhisper.Services.Speech
+{
+    /// <summary>
+    /// Owns hallucination_filters.json, the user-editable list of artifacts models produce on
+    /// silence or noise ("[BLANK_AUDIO]", "Thanks for watching!"). Matches are removed from
+    /// transcripts before formatting. Seeded with defaults on first run.
+    /// </summary>
+    public class HallucinationFilterService
+    {
+        private const string FILTERS_FILENAME = "hallucination_filters.json";
+        private static readonly TimeSpan RegexTimeout = TimeSpan.FromMilliseconds(200);
+        private static readonly Regex ExtraSpaces = new(@"[ \t]{2,}", RegexOptions.Compiled);
+
+        private readonly string _filePath;
+        private readonly object _lock = new object();
+        private List<HallucinationFilter> _filters = new();
+        private List<Regex> _compiled = new();
+
+        /// <summary>
+        /// Markers whisper.cpp writes for non-speech, plus phrases models learned from YouTube
+        /// subtitles. The phrases are anchored so they're only dropped when they are the whole output.
+        /// </summary>
+        public static IReadOnlyList<HallucinationFilter> Defaults { get; } = new List<HallucinationFilter>
+        {
+            new() { Pattern = "[BLANK_AUDIO]" },
+            new() { Pattern = "[silence]" },
+            new() { Pattern = "(silence)" },
+            new() { Pattern = "[MUSIC]" },
+            new() { Pattern = "(music)" },
+            new() { Pattern = @"\[\s*(silence|music|applause|laughter|noise|inaudible)\s*\]", IsRegex = true },
+            new() { Pattern = @"^\s*(\[[^\]]*\]|\([^)]*\))\s*$", IsRegex = true },
+            new() { Pattern = @"^\s*(thanks|thank you)( so much)? for watching[.!]*\s*$", IsRegex = true },
+            new() { Pattern = @"^\s*(please )?(like and )?subscribe( to (my|the|our) channel)?[.!]*\s*$", IsRegex = true },
+            new() { Pattern = @"^\s*subtitles by the amara\.org community\s*$", IsRegex = true },
+            new() { Pattern = @"^\s*see you (in the )?next (time|video)[.!]*\s*$", IsRegex = true }
+        };
+
+        public HallucinationFilterService()
+            : this(Path.Combine(Environment.GetFolderPath(Environment.SpecialFolder.LocalApplicationData), "EliteWhisper"))
+        {
+        }
+
+        internal HallucinationFilterService(string directory)
+        {
+            Directory.CreateDirectory(directory);
+            _filePath = Path.Combine(directory, FILTERS_FILENAME);
+
+            Load();
+        }
+
+        /// <summary>
+        /// Current filters, in the order they are applied.
+        /// </summary>
+        public List<HallucinationFilter> GetFilters()
+        {
+            lock (_lock)
+            {
+                return _filters.Select(Clone).ToList();
+            }
+        }
+
+        /// <summary>
+        /// Replaces the filter list. Empty patterns are dropped.
+        /// Throws ArgumentException when a regex filter doesn't compile.
+        /// </summary>
+        public void SaveFilters(IEnumerable<HallucinationFilter> filters)
+        {
+            var list = filters.Where(f => !string.IsNullOrWhiteSpace(f.Pattern)).Select(Clone).ToList();
+            var compiled = Compile(list);
+
+            lock (_lock)
+            {
+                Write(list);
+                _filters = list;
+                _compiled = compiled;
+            }
+
+            SttLogger.Log($"[STT] Saved {list.Count} hallucination filters");
+        }
+
+        /// <summary>
+        /// Removes every match from the text. Returns an empty string when nothing but artifacts was left.
+        /// </summary>
+        public string Apply(string text)
+        {
+            if (string.IsNullOrEmpty(text)) return text;
+
+            List<Regex> compiled;
+            lock (_lock)
+            {
+                compiled = _compiled;
+            }
+
+            string original = text;
+            foreach (var pattern in compiled)
+            {
+                try
+                {
+                    text = pattern.Replace(text, string.Empty);
+                }
+                catch (RegexMatchTimeoutException)
+                {
+                    SttLogger.Log($"[STT] Hallucination filter '{pattern}' timed out, skipped");
+                }
+            }
+
+            if (text.Length == original.Length) return original;
+
+            text = ExtraSpaces.Replace(text, " ").Trim();
+            SttLogger.Log($"[STT] Hallucination filters removed {original.Length - text.Length} characters");
+            return text;
+        }
+
+        private static List<Regex> Compile(List<HallucinationFilter> filters)
+        {
+            var compiled = new List<Regex>(filters.Count);
+            foreach (var filter in filters)
+            {
+                string pattern = filter.IsRegex ? filter.Pattern : Regex.Escape(filter.Pattern);
+                try
+                {
+                    compiled.Add(new Regex(pattern, RegexOptions.IgnoreCase | RegexOptions.Multiline, RegexTimeout));
+                }
+                catch (ArgumentException ex)
+                {
+                    throw new ArgumentException($"Invalid regular expression '{filter.Pattern}': {ex.Message}", nameof(filters), ex);
+                }
+            }
+
+            return compiled;
+        }
+
+        private void Write(List<HallucinationFilter> filters)
+        {
+            string tempPath = _filePath + ".tmp";
+            var options = new JsonSerializerOptions { WriteIndented = true };
+            File.WriteAllText(tempPath, JsonSerializer.Serialize(filters, options));
+            File.Move(tempPath, _filePath, overwrite: true);
+        }
+
+        private void Load()
+        {
+            try
+            {
+                if (!File.Exists(_filePath))
+                {
+                    // First run: write the defaults so users have something to edit
+                    _filters = Defaults.Select(Clone).ToList();
+                    _compiled = Compile(_filters);
+                    Write(_filters);
+                    return;
+                }
+
+                var filters = JsonSerializer.Deserialize<List<HallucinationFilter>>(File.ReadAllText(_filePath)) ?? new();
+                filters.RemoveAll(f => string.IsNullOrWhiteSpace(f.Pattern));
+
+                // A hand-edited file with a bad pattern shouldn't disable every other filter
+                var valid = new List<HallucinationFilter>();
+                foreach (var filter in filters)
+                {
+                    try
+                    {
+                        Compile(new List<HallucinationFilter> { filter });
+                        valid.Add(filter);
+                    }
+                    catch (ArgumentException ex)
+                    {
+                        SttLogger.Log($"[STT] Skipping hallucination filter: {ex.Message}");
+                    }
+                }
+
+                _filters = valid;
+                _compiled = Compile(valid);
+            }
+            catch (Exception ex)
+            {
+                SttLogger.Log($"[STT] Failed to load hallucination filters: {ex.Message}");
+                _filters = Defaults.Select(Clone).ToList();
+                _compiled = Compile(_filters);
+            }
+        }
+
+        private static HallucinationFilter Clone(HallucinationFilter filter) => new()
+        {
+            Pattern = filter.Pattern,
+            IsRegex = filter.IsRegex
+        };
+    }
+}
@@ -0,0 +1,100 @@
+using System;
+using System.Collections.Generic;
+using System.IO;
+using System.Linq;
+using EliteWhisper.Models;
+
+namespace EliteWhisper.Services.Speech
+{
+    /// <summary>
+    /// Owns hotwords.txt (one phrase per line, optionally "phrase :score") used to bias Sherpa decoding.
+    /// All access goes through a lock and writes are atomic (temp file + rename), so an
+    /// engine reading the file never sees a half-written list.
+    /// </summary>
+    public class HotwordsService
+    {
+        private const string HOTWORDS_FILENAME = "hotwords.txt";
+
+        private readonly string _filePath;
+        private readonly object _lock = new object();
+
+        public HotwordsService()
+            : this(Path.Combine(Environment.GetFolderPath(Environment.SpecialFolder.LocalApplicationData), "EliteWhisper"))
+        {
+        }
+
+        internal HotwordsService(string directory)
+        {
+            Directory.CreateDirectory(directory);
+            _filePath = Path.Combine(directory, HOTWORDS_FILENAME);
+        }
+
+        public string FilePath => _filePath;
+
+        /// <summary>
+        /// Bumped on every save, so engines built from an older list can be recognised and rebuilt.
+        /// </summary>
+        public int Version { get; private set; }
+
+        /// <summary>
+        /// Current hotwords with their scores, blank lines removed.
+        /// </summary>
+        public List<Hotword> GetHotwords()
+        {
+            lock (_lock)
+            {
+                if (!File.Exists(_filePath)) return new List<Hotword>();
+
+                return File.ReadAllLines(_filePath)
+                    .Select(l => l.Trim())
+                    .Where(l => l.Length > 0)
+                    .Select(Hotword.Parse)
+                    .ToList();
+            }
+        }
+
+        /// <summary>
+        /// Replaces the hotwords list. Scores must be positive; null keeps the engine default.
+        /// </summary>
+        public void SaveHotwords(IEnumerable<Hotword> hotwords)
+        {
+            var list = hotwords
+                .Where(h => !string.IsNullOrWhiteSpace(h.Word))
+                .GroupBy(h => h.Word.Trim(), StringComparer.OrdinalIgnoreCase)
+                .Select(g => g.First())
+                .ToList();
+
+            var invalid = list.FirstOrDefault(h => h.Score.HasValue && !(h.Score.Value > 0));
+            if (invalid != null)
+                throw new ArgumentOutOfRangeException(nameof(hotwords), $"Score for '{invalid.Word}' must be greater than 0.");
+
+            var lines = list.Select(h => h.ToLine()).ToList();
+
+            lock (_lock)
+            {
+                string tempPath = _filePath + ".tmp";
+                File.WriteAllLines(tempPath, lines);
+                File.Move(tempPath, _filePath, overwrite: true);
+                Version++;
+            }
+
+            SttLogger.Log($"[STT] Saved {lines.Count} hotwords");
+        }
+
+        /// <summary>
+        /// Copies the current hotwords to a private temp file for an engine to load.
+        /// Returns null when there are no hotwords. The caller deletes the snapshot.
+        /// </summary>
+        public string? CreateSnapshot()
+        {
+            lock (_lock)
+            {
+                if (!File.Exists(_filePath) || new FileInfo(_filePath).Length == 0) return null;
+
+                string snapshotPath = Path.Combine(Path.GetTempPath(), $"elitewhisper_hotwords_{Guid.NewGuid():N}.txt");
+                File.Copy(_filePath, snapshotPath);
+                return snapshotPath;
+            }
+        }
+    }
+}
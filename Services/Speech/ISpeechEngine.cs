@@ -1,3 +1,4 @@
+using System;
 using System.Threading;
 using System.Threading.Tasks;
 
@@ -7,6 +8,8 @@ namespace EliteWhisper.Services.Speech
     {
         string Name { get; }
         bool IsAvailable { get; }
-        Task<string> TranscribeAsync(float[] audioSamples, CancellationToken ct);
+
+        /// <param name="progress">Receives completion from 0 to 100 while decoding, if given.</param>
+        Task<string> TranscribeAsync(float[] audioSamples, CancellationToken ct, IProgress<int>? progress = null);
     }
 }
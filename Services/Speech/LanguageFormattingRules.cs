@@ -0,0 +1,189 @@
+using System;
+using System.Collections.Generic;
+using System.Linq;
+using System.Text;
+using System.Text.RegularExpressions;
+
+namespace EliteWhisper.Services.Speech
+{
+    /// <summary>
+    /// Language-aware spacing, punctuation and capitalization applied to raw transcripts.
+    /// English and the default Latin rules tidy spacing and capitalize sentence starts, and can end
+    /// the text with a period when the model left it unpunctuated; English also capitalizes "I";
+    /// French puts a narrow no-break space before ! ? ; : and inside « »;
+    /// Chinese and Japanese drop inter-word spaces and use full-width punctuation.
+    /// </summary>
+    public static class LanguageFormattingRules
+    {
+        private const char NARROW_NBSP = '\u202F';
+
+        /// <summary>
+        /// Languages with dedicated rule sets. Any other code gets the default Latin rules.
+        /// </summary>
+        public static readonly IReadOnlyList<string> SupportedLanguages = new[] { "en", "fr", "de", "es", "it", "pt", "zh", "ja", "ko" };
+
+        private static readonly Dictionary<char, char> FullWidthPunctuation = new()
+        {
+            [','] = '\uFF0C', ['.'] = '\u3002', ['!'] = '\uFF01', ['?'] = '\uFF1F', [':'] = '\uFF1A', [';'] = '\uFF1B'
+        };
+
+        /// <param name="addFinalPeriod">
+        /// End unpunctuated Latin-script text with a period. Off by default since it also
+        /// turns short dictations like "42" or a search term into "42." and "term.".
+        /// </param>
+        public static string Apply(string text, string language, bool addFinalPeriod = false)
+        {
+            if (string.IsNullOrWhiteSpace(text)) return text;
+
+            text = language switch
+            {
+                "en" => CapitalizePronounI(CapitalizeSentences(NormalizeLatinSpacing(text))),
+                "fr" => CapitalizeSentences(NormalizeLatinSpacing(text)),
+                "zh" or "ja" => ApplyCjk(text),
+                "ko" => NormalizeLatinSpacing(text),
+                _ => CapitalizeSentences(NormalizeLatinSpacing(text))
+            };
+
+            if (addFinalPeriod && language is not ("zh" or "ja" or "ko"))
+            {
+                text = EnsureFinalPunctuation(text);
+            }
+
+            // French spacing goes after the final period so it sees the finished punctuation
+            return language == "fr" ? ApplyFrenchSpacing(text) : text;
+        }
+
+        /// <summary>
+        /// Best guess of the language family from the script used in the text:
+        /// "ja" for kana, "zh" for Han without kana, "ko" for Hangul, otherwise "latin".
+        /// Latin script alone doesn't identify English, so English-only rules are not applied to a guess.
+        /// </summary>
+        public static string DetectFromScript(string text)
+        {
+            bool hasKana = false, hasHan = false, hasHangul = false;
+            foreach (char c in text)
+            {
+                if (c >= '\u3040' && c <= '\u30FF') hasKana = true;
+                else if (c >= '\u4E00' && c <= '\u9FFF') hasHan = true;
+                else if (c >= '\uAC00' && c <= '\uD7AF') hasHangul = true;
+            }
+
+            if (hasKana) return "ja";
+            if (hasHan) return "zh";
+            if (hasHangul) return "ko";
+            return "latin";
+        }
+
+        private static string NormalizeLatinSpacing(string text)
+        {
+            // Collapse runs of whitespace
+            text = Regex.Replace(text.Trim(), @"\s+", " ");
+
+            // No space before punctuation: "hello , world" -> "hello, world"
+            text = Regex.Replace(text, @"\s+([,.;:!?])", "$1");
+
+            // One space after punctuation, but leave numbers ("3.5", "1,000") and ellipses alone
+            text = Regex.Replace(text, @"([,;:!?])(?=[^\s\d,.;:!?""')\]/])", "$1 ");
+            // A missing space after a sentence end ("done.Next step"), but not dotted names like ASP.NET or Node.JS
+            text = Regex.Replace(text, @"(?<=\p{Ll}{2})\.(?=\p{Lu}\p{Ll}+(?:\s|$))", ". ");
+
+            return text;
+        }
+
+        private static string CapitalizeSentences(string text)
+        {
+            var sb = new StringBuilder(text);
+            bool capitalizeNext = true;
+
+            for (int i = 0; i < sb.Length; i++)
+            {
+                char c = sb[i];
+                if (capitalizeNext && char.IsLetter(c))
+                {
+                    sb[i] = char.ToUpper(c);
+                    capitalizeNext = false;
+                }
+                else if (c == '.' || c == '!' || c == '?')
+                {
+                    // Only a sentence end if followed by whitespace (not "3.5" or "e.g")
+                    capitalizeNext = i + 1 < sb.Length && char.IsWhiteSpace(sb[i + 1]);
+                }
+                else if (!char.IsWhiteSpace(c) && c != '"' && c != '\'')
+                {
+                    capitalizeNext = false;
+                }
+            }
+
+            return sb.ToString();
+        }
+
+        // Standalone "i" and its contractions ("i'm", "i'll") -> "I"
+        private static string CapitalizePronounI(string text)
+        {
+            return Regex.Replace(text, @"\bi(?!\.e\b)(?=$|[\s,.;:!?]|['\u2019](?:m|ll|ve|d)\b)", "I");
+        }
+
+        // Lowercase, unpunctuated model output (e.g. transducers) gets a closing period
+        private static string EnsureFinalPunctuation(string text)
+        {
+            if (text.Length == 0) return text;
+
+            char last = text[^1];
+            if (char.IsLetterOrDigit(last))
+                return text + ".";
+
+            return text;
+        }
+
+        private static string ApplyFrenchSpacing(string text)
+        {
+            // Narrow no-break space before high punctuation: "Bonjour !" / "Quoi ?"
+            text = Regex.Replace(text, @"\s*([!?;:])", $"{NARROW_NBSP}$1");
+
+            // Guillemets: « texte »
+            text = Regex.Replace(text, @"«\s*", $"«{NARROW_NBSP}");
+            text = Regex.Replace(text, @"\s*»", $"{NARROW_NBSP}»");
+
+            // Don't split times or URLs such as 10:30 or https://
+            text = Regex.Replace(text, $@"(\d){NARROW_NBSP}:(\d)", "$1:$2");
+            text = Regex.Replace(text, $@"(\w){NARROW_NBSP}:(//)", "$1:$2");
+
+            return text;
+        }
+
+        private static string ApplyCjk(string text)
+        {
+            text = Regex.Replace(text.Trim(), @"\s+", " ");
+
+            // Remove spaces touching CJK characters or full-width punctuation
+            text = Regex.Replace(text, @"(?<=[\p{IsCJKUnifiedIdeographs}\p{IsHiragana}\p{IsKatakana}\p{IsCJKSymbolsandPunctuation}\uFF00-\uFFEF])\s+", "");
+            text = Regex.Replace(text, @"\s+(?=[\p{IsCJKUnifiedIdeographs}\p{IsHiragana}\p{IsKatakana}\p{IsCJKSymbolsandPunctuation}\uFF00-\uFFEF])", "");
+
+            // ASCII punctuation after a CJK character becomes full-width
+            var sb = new StringBuilder(text.Length);
+            for (int i = 0; i < text.Length; i++)
+            {
+                char c = text[i];
+                if (i > 0 && IsCjk(text[i - 1]) && FullWidthPunctuation.TryGetValue(c, out char wide))
+                {
+                    sb.Append(wide);
+
+                    // The full-width mark carries its own spacing
+                    while (i + 1 < text.Length && text[i + 1] == ' ') i++;
+                }
+                else
+                {
+                    sb.Append(c);
+                }
+            }
+
+            return sb.ToString();
+        }
+
+        private static bool IsCjk(char c) =>
+            (c >= '\u3040' && c <= '\u30FF') || (c >= '\u4E00' && c <= '\u9FFF');
+
+        public static bool IsSupported(string language) =>
+            SupportedLanguages.Contains(language, StringComparer.OrdinalIgnoreCase);
+    }
+}
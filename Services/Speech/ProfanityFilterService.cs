@@ -0,0 +1,168 @@
+using System;
+using System.Collections.Generic;
+using System.IO;
+using System.Linq;
+using System.Text.RegularExpressions;
+
+namespace EliteWhisper.Services.Speech
+{
+    /// <summary>
+    /// Masks profanity in final transcripts with asterisks. Built-in lists per language, plus
+    /// user additions in profanity.txt: one word per line, optionally prefixed with a language
+    /// code ("fr:merde") to apply to that language only. Matching is whole-word and
+    /// case-insensitive, so "Scunthorpe" or "classic" are never touched.
+    /// </summary>
+    public class ProfanityFilterService
+    {
+        private const string CUSTOM_FILENAME = "profanity.txt";
+
+        private static readonly Dictionary<string, string[]> BuiltInLists = new()
+        {
+            ["en"] = new[]
+            {
+                "fuck", "fucks", "fucked", "fucking", "fucker", "motherfucker", "shit", "shits", "shitty", "bullshit",
+                "bitch", "bitches", "bastard", "asshole", "arsehole", "dick", "dickhead", "cunt", "twat", "wanker",
+                "bollocks", "piss", "pissed", "crap", "damn", "goddamn"
+            },
+            ["es"] = new[] { "mierda", "joder", "puta", "puto", "cabrón", "cabron", "coño", "gilipollas", "pendejo" },
+            ["fr"] = new[] { "merde", "putain", "connard", "connasse", "salope", "enculé", "encule", "bordel" },
+            ["de"] = new[] { "scheiße", "scheisse", "arschloch", "fotze", "wichser", "verdammt", "fick", "ficken" },
+            ["it"] = new[] { "cazzo", "merda", "stronzo", "vaffanculo", "puttana" },
+            ["pt"] = new[] { "merda", "porra", "caralho", "puta", "foda" }
+        };
+
+        private readonly string _filePath;
+        private readonly object _lock = new object();
+        private DateTime _loadedWriteTime = DateTime.MinValue;
+        private List<(string? Language, string Word)> _customWords = new();
+        private readonly Dictionary<string, Regex?> _patterns = new();
+
+        public ProfanityFilterService()
+            : this(Path.Combine(Environment.GetFolderPath(Environment.SpecialFolder.LocalApplicationData), "EliteWhisper"))
+        {
+        }
+
+        internal ProfanityFilterService(string directory)
+        {
+            Directory.CreateDirectory(directory);
+            _filePath = Path.Combine(directory, CUSTOM_FILENAME);
+        }
+
+        public string FilePath => _filePath;
+
+        /// <summary>
+        /// User additions as written in the file ("word" or "xx:word").
+        /// </summary>
+        public List<string> GetCustomWords()
+        {
+            lock (_lock)
+            {
+                if (!File.Exists(_filePath)) return new List<string>();
+
+                return File.ReadAllLines(_filePath)
+                    .Select(l => l.Trim())
+                    .Where(l => l.Length > 0 && !l.StartsWith("#"))
+                    .ToList();
+            }
+        }
+
+        /// <summary>
+        /// Replaces the user additions.
+        /// </summary>
+        public void SaveCustomWords(IEnumerable<string> words)
+        {
+            var lines = words
+                .Select(w => w.Trim())
+                .Where(w => w.Length > 0)
+                .Distinct(StringComparer.OrdinalIgnoreCase)
+                .ToList();
+
+            lock (_lock)
+            {
+                string tempPath = _filePath + ".tmp";
+                File.WriteAllLines(tempPath, lines);
+                File.Move(tempPath, _filePath, overwrite: true);
+                _loadedWriteTime = DateTime.MinValue;
+            }
+
+            SttLogger.Log($"[STT] Saved {lines.Count} custom profanity words");
+        }
+
+        /// <summary>
+        /// Masks listed words for the given language. Null = unknown language, every list applies.
+        /// </summary>
+        public string Apply(string text, string? language)
+        {
+            if (string.IsNullOrEmpty(text)) return text;
+
+            Regex? pattern;
+            lock (_lock)
+            {
+                ReloadIfChanged();
+
+                string key = language?.ToLowerInvariant() ?? "*";
+                if (!_patterns.TryGetValue(key, out pattern))
+                {
+                    pattern = BuildPattern(key == "*" ? null : key);
+                    _patterns[key] = pattern;
+                }
+            }
+
+            if (pattern == null) return text;
+            return pattern.Replace(text, m => new string('*', m.Length));
+        }
+
+        // The file is meant to be edited by hand, so pick up changes without a restart
+        private void ReloadIfChanged()
+        {
+            DateTime writeTime = File.Exists(_filePath) ? File.GetLastWriteTimeUtc(_filePath) : DateTime.MinValue;
+            if (writeTime == _loadedWriteTime && _loadedWriteTime != DateTime.MinValue) return;
+
+            _customWords = new List<(string?, string)>();
+            if (File.Exists(_filePath))
+            {
+                foreach (var line in File.ReadAllLines(_filePath))
+                {
+                    string entry = line.Trim();
+                    if (entry.Length == 0 || entry.StartsWith("#")) continue;
+
+                    int colon = entry.IndexOf(':');
+                    if (colon is 2 or 3 && entry[..colon].All(char.IsLetter))
+                    {
+                        _customWords.Add((entry[..colon].ToLowerInvariant(), entry[(colon + 1)..].Trim()));
+                    }
+                    else
+                    {
+                        _customWords.Add((null, entry));
+                    }
+                }
+            }
+
+            _loadedWriteTime = writeTime;
+            _patterns.Clear();
+        }
+
+        private Regex? BuildPattern(string? language)
+        {
+            IEnumerable<string> words = language != null
+                ? BuiltInLists.GetValueOrDefault(language) ?? Array.Empty<string>()
+                : BuiltInLists.Values.SelectMany(w => w);
+
+            words = words.Concat(_customWords
+                .Where(c => c.Language == null || language == null || c.Language == language)
+                .Select(c => c.Word));
+
+            var list = words
+                .Where(w => w.Length > 0)
+                .Distinct(StringComparer.OrdinalIgnoreCase)
+                .OrderByDescending(w => w.Length)
+                .Select(Regex.Escape)
+                .ToList();
+
+            if (list.Count == 0) return null;
+
+            return new Regex($@"(?<!\w)(?:{string.Join("|", list)})(?!\w)",
+                RegexOptions.IgnoreCase | RegexOptions.CultureInvariant | RegexOptions.Compiled);
+        }
+    }
+}
@@ -0,0 +1,178 @@
+using System;
+using System.Collections.Generic;
+using System.IO;
+using System.Linq;
+using System.Text.Json;
+using System.Text.RegularExpressions;
+using EliteWhisper.Models;
+
+namespace EliteWhisper.Services.Speech
+{
+    /// <summary>
+    /// Owns replacements.json, the ordered find-and-replace rules applied to final transcripts.
+    /// Unlike hotwords these don't influence recognition; they rewrite the text afterwards.
+    /// </summary>
+    public class ReplacementsService
+    {
+        private const string REPLACEMENTS_FILENAME = "replacements.json";
+        private static readonly TimeSpan RegexTimeout = TimeSpan.FromMilliseconds(200);
+
+        private readonly string _filePath;
+        private readonly object _lock = new object();
+        private List<ReplacementRule> _rules = new();
+        private List<(Regex Pattern, string Replacement)> _compiled = new();
+
+        public ReplacementsService()
+            : this(Path.Combine(Environment.GetFolderPath(Environment.SpecialFolder.LocalApplicationData), "EliteWhisper"))
+        {
+        }
+
+        internal ReplacementsService(string directory)
+        {
+            Directory.CreateDirectory(directory);
+            _filePath = Path.Combine(directory, REPLACEMENTS_FILENAME);
+
+            Load();
+        }
+
+        /// <summary>
+        /// Current rules, in the order they are applied.
+        /// </summary>
+        public List<ReplacementRule> GetRules()
+        {
+            lock (_lock)
+            {
+                return _rules.Select(Clone).ToList();
+            }
+        }
+
+        /// <summary>
+        /// Replaces the rule list. Rules with an empty From are dropped.
+        /// Throws ArgumentException when a regex rule doesn't compile.
+        /// </summary>
+        public void SaveRules(IEnumerable<ReplacementRule> rules)
+        {
+            var list = rules.Where(r => !string.IsNullOrEmpty(r.From)).Select(Clone).ToList();
+            var compiled = Compile(list);
+
+            lock (_lock)
+            {
+                string tempPath = _filePath + ".tmp";
+                var options = new JsonSerializerOptions { WriteIndented = true };
+                File.WriteAllText(tempPath, JsonSerializer.Serialize(list, options));
+                File.Move(tempPath, _filePath, overwrite: true);
+
+                _rules = list;
+                _compiled = compiled;
+            }
+
+            SttLogger.Log($"[STT] Saved {list.Count} replacement rules");
+        }
+
+        /// <summary>
+        /// Runs every rule over the text, in order; each rule sees the output of the previous one.
+        /// </summary>
+        public string Apply(string text)
+        {
+            if (string.IsNullOrEmpty(text)) return text;
+
+            List<(Regex Pattern, string Replacement)> compiled;
+            lock (_lock)
+            {
+                compiled = _compiled;
+            }
+
+            foreach (var (pattern, replacement) in compiled)
+            {
+                try
+                {
+                    text = pattern.Replace(text, replacement);
+                }
+                catch (RegexMatchTimeoutException)
+                {
+                    SttLogger.Log($"[STT] Replacement rule '{pattern}' timed out, skipped");
+                }
+            }
+
+            return text;
+        }
+
+        private static List<(Regex Pattern, string Replacement)> Compile(List<ReplacementRule> rules)
+        {
+            var compiled = new List<(Regex, string)>(rules.Count);
+            foreach (var rule in rules)
+            {
+                var options = rule.CaseSensitive ? RegexOptions.None : RegexOptions.IgnoreCase;
+                string pattern;
+                string replacement;
+
+                if (rule.IsRegex)
+                {
+                    pattern = rule.From;
+                    replacement = rule.To;
+                }
+                else
+                {
+                    pattern = Regex.Escape(rule.From);
+                    // Lookarounds instead of \b so phrases starting or ending in punctuation still match
+                    if (rule.WholeWord) pattern = $@"(?<!\w){pattern}(?!\w)";
+                    replacement = rule.To.Replace("$", "$$");
+                }
+
+                try
+                {
+                    compiled.Add((new Regex(pattern, options, RegexTimeout), replacement));
+                }
+                catch (ArgumentException ex)
+                {
+                    throw new ArgumentException($"Invalid regular expression '{rule.From}': {ex.Message}", nameof(rules), ex);
+                }
+            }
+
+            return compiled;
+        }
+
+        private void Load()
+        {
+            try
+            {
+                if (File.Exists(_filePath))
+                {
+                    var rules = JsonSerializer.Deserialize<List<ReplacementRule>>(File.ReadAllText(_filePath)) ?? new();
+                    rules.RemoveAll(r => string.IsNullOrEmpty(r.From));
+
+                    // A hand-edited file with a bad pattern shouldn't disable every other rule
+                    var valid = new List<ReplacementRule>();
+                    foreach (var rule in rules)
+                    {
+                        try
+                        {
+                            Compile(new List<ReplacementRule> { rule });
+                            valid.Add(rule);
+                        }
+                        catch (ArgumentException ex)
+                        {
+                            SttLogger.Log($"[STT] Skipping replacement rule: {ex.Message}");
+                        }
+                    }
+
+                    _rules = valid;
+                    _compiled = Compile(valid);
+                }
+            }
+            catch (Exception ex)
+            {
+                SttLogger.Log($"[STT] Failed to load replacement rules: {ex.Message}");
+            }
+        }
+
+        private static ReplacementRule Clone(ReplacementRule rule) => new()
+        {
+            From = rule.From,
+            To = rule.To ?? string.Empty,
+            CaseSensitive = rule.CaseSensitive,
+            WholeWord = rule.WholeWord,
+            IsRegex = rule.IsRegex
+        };
+    }
+}
@@ -1,15 +1,19 @@
 using System;
+using System.Collections.Generic;
 using System.Diagnostics;
 using System.IO;
+using System.Linq;
 using System.Threading;
 using System.Threading.Tasks;
+using EliteWhisper.Models;
 using SherpaOnnx;
 
 namespace EliteWhisper.Services.Speech
 {
     /// <summary>
-    /// Speech engine using Sherpa-ONNX for Parakeet TDT (Token-and-Duration Transducer) models.
-    /// Accepts raw 16kHz PCM float audio — no mel spectrogram or FFT needed.
+    /// Speech engine using Sherpa-ONNX for Parakeet TDT (Token-and-Duration Transducer), NeMo CTC
+    /// and Whisper models. The OfflineRecognizer runs in-process and is fed raw 16kHz PCM float
+    /// audio directly — no temp WAV, no subprocess, no mel spectrogram or FFT needed.
     /// CPU-first design, no CUDA dependency.
     /// </summary>
     public class SherpaOnnxEngine : ISpeechEngine, IDisposable
@@ -17,17 +21,110 @@ namespace EliteWhisper.Services.Speech
         private OfflineRecognizer? _recognizer;
         private readonly string _modelDirectory;
         private readonly int _numThreads;
-        private readonly string _engineName;
+        private readonly string? _hotwordsFile;
+        private readonly SherpaModelType _modelType;
+        private readonly string? _language;
+        private readonly WhisperTask _task;
+        private readonly DecodingStrategy _decodingStrategy;
+        private readonly int _beamSize;
         private bool _disposed;
 
-        public string Name => _engineName;
-        public bool IsAvailable => _recognizer != null;
+        // Decodes still running on _recognizer. Dispose is deferred until the last one finishes,
+        // since the selector may evict or swap this engine while a transcription is in flight.
+        private readonly object _decodeLock = new();
+        private int _activeDecodes;
 
-        public SherpaOnnxEngine(string modelDirectory, int? numThreads = null)
+        // Decode speed of the last run, for estimating progress since Decode reports none
+        private double _msPerAudioSecond = 50;
+        private const int PROGRESS_INTERVAL_MS = 250;
+
+        public string Name => ModelType switch
         {
+            SherpaModelType.NemoCtc => "NeMo CTC (Sherpa)",
+            SherpaModelType.Whisper => "Whisper (Sherpa)",
+            SherpaModelType.Paraformer => "Paraformer (Sherpa)",
+            SherpaModelType.SenseVoice => "SenseVoice (Sherpa)",
+            _ => "Parakeet (Sherpa)"
+        };
+        public bool IsAvailable => _recognizer != null && !_disposed;
+
+        /// <summary>
+        /// Why the recognizer couldn't be created, when <see cref="IsAvailable"/> is false.
+        /// </summary>
+        public string? LoadError { get; private set; }
+
+        /// <summary>
+        /// Architecture the recognizer was built for (detected or forced).
+        /// </summary>
+        public SherpaModelType ModelType { get; private set; } = SherpaModelType.Auto;
+
+        /// <summary>
+        /// Language the recognizer was built with. Only Whisper and SenseVoice models use it; null = auto-detect.
+        /// </summary>
+        public string? Language => _language;
+
+        /// <summary>
+        /// True when the hotwords list was loaded. Only transducer models support hotwords.
+        /// </summary>
+        public bool HotwordsActive { get; private set; }
+
+        /// <summary>
+        /// HotwordsService.Version of the list this engine was built with.
+        /// </summary>
+        public int HotwordsVersion { get; init; }
+
+        /// <summary>
+        /// Whether a model type can use hotwords at all.
+        /// </summary>
+        public static bool SupportsHotwords(SherpaModelType modelType) => modelType == SherpaModelType.Transducer;
+
+        /// <summary>
+        /// CPU threads the recognizer was built with.
+        /// </summary>
+        public int NumThreads => _numThreads;
+
+        /// <summary>
+        /// Task the recognizer was built with. Only Whisper models use it.
+        /// </summary>
+        public WhisperTask WhisperTask => _task;
+
+        /// <summary>
+        /// Decoding strategy the recognizer was built with. Only transducer models use beam search.
+        /// </summary>
+        public DecodingStrategy DecodingStrategy => _decodingStrategy;
+
+        /// <summary>
+        /// Beam size the recognizer was built with.
+        /// </summary>
+        public int BeamSize => _beamSize;
+
+        /// <param name="hotwordsFile">
+        /// Snapshot of the hotwords list. Read once while the recognizer is created.
+        /// </param>
+        /// <param name="modelType">
+        /// Forces the model architecture instead of detecting it from file names.
+        /// </param>
+        /// <param name="language">
+        /// ISO 639-1 code for Whisper models. Null = auto-detect.
+        /// </param>
+        /// <param name="task">
+        /// Transcribe or translate to English, for Whisper models.
+        /// </param>
+        /// <param name="decodingStrategy">
+        /// Greedy or modified beam search, for transducer models. Hotwords always use beam search.
+        /// </param>
+        public SherpaOnnxEngine(string modelDirectory, int? numThreads = null, string? hotwordsFile = null,
+            SherpaModelType modelType = SherpaModelType.Auto, string? language = null, WhisperTask task = WhisperTask.Transcribe,
+            DecodingStrategy decodingStrategy = DecodingStrategy.Greedy, int beamSize = 4)
+        {
+            _decodingStrategy = decodingStrategy;
+            _beamSize = Math.Max(1, beamSize);
             _modelDirectory = modelDirectory;
+            _hotwordsFile = hotwordsFile;
+            _modelType = modelType;
+            _language = language;
+            _task = task;
             _numThreads = numThreads ?? Math.Max(1, Environment.ProcessorCount / 2);
-            _engineName = $"Parakeet (Sherpa)";
 
             Initialize();
         }
@@ -40,62 +137,168 @@ namespace EliteWhisper.Services.Speech
                 if (!Directory.Exists(_modelDirectory))
                 {
                     SttLogger.Log($"[STT] Sherpa model directory not found: {_modelDirectory}");
+                    LoadError = $"Model folder not found: {_modelDirectory}";
                     return;
                 }
 
-                // Parakeet TDT models use transducer architecture: encoder + decoder + joiner + tokens
-                string encoderPath = FindModelFile("encoder*.onnx");
-                string decoderPath = FindModelFile("decoder*.onnx");
-                string joinerPath = FindModelFile("joiner*.onnx");
-                string tokensPath = Path.Combine(_modelDirectory, "tokens.txt");
-
-                if (string.IsNullOrEmpty(encoderPath) || string.IsNullOrEmpty(decoderPath) ||
-                    string.IsNullOrEmpty(joinerPath) || !File.Exists(tokensPath))
+                // A forced type bypasses detection for nonstandard layouts
+                SherpaModelType? detected = _modelType != SherpaModelType.Auto ? _modelType : DetectModelType(_modelDirectory);
+                if (detected == null)
                 {
-                    SttLogger.Log($"[STT] Sherpa model files incomplete in {_modelDirectory}. " +
-                        $"encoder={!string.IsNullOrEmpty(encoderPath)}, decoder={!string.IsNullOrEmpty(decoderPath)}, " +
-                        $"joiner={!string.IsNullOrEmpty(joinerPath)}, tokens={File.Exists(tokensPath)}");
+                    LoadError = $"Unrecognized model layout. {DescribeLayout(_modelDirectory)}";
+                    SttLogger.Log($"[STT] Sherpa model type not recognized in {_modelDirectory}. {DescribeLayout(_modelDirectory)}");
                     return;
                 }
+                SherpaModelType modelType = detected.Value;
 
-                SttLogger.Log($"[STT] Sherpa initializing with encoder={Path.GetFileName(encoderPath)}, " +
-                    $"decoder={Path.GetFileName(decoderPath)}, joiner={Path.GetFileName(joinerPath)}, " +
-                    $"threads={_numThreads}");
+                var missing = GetMissingFiles(_modelDirectory, modelType);
+                if (missing.Count > 0)
+                {
+                    SttLogger.Log($"[STT] Sherpa model files incomplete in {_modelDirectory} for {modelType}" +
+                        $"{(_modelType != SherpaModelType.Auto ? " (forced)" : "")}. Missing: {string.Join(", ", missing)}");
+                    LoadError = $"The {modelType} model is incomplete. Missing: {string.Join(", ", missing)}";
+                    return;
+                }
 
+                ModelType = modelType;
                 var config = new OfflineRecognizerConfig();
 
                 // Feature extraction config — Sherpa handles mel internally
                 config.FeatConfig.SampleRate = 16000;
                 config.FeatConfig.FeatureDim = 80;
 
-                // Transducer model config
-                config.ModelConfig.Transducer.Encoder = encoderPath;
-                config.ModelConfig.Transducer.Decoder = decoderPath;
-                config.ModelConfig.Transducer.Joiner = joinerPath;
-                config.ModelConfig.Tokens = tokensPath;
+                switch (modelType)
+                {
+                    case SherpaModelType.NemoCtc:
+                        config.ModelConfig.NeMoCtc.Model = FindModelFile("model*.onnx");
+                        config.ModelConfig.Tokens = Path.Combine(_modelDirectory, "tokens.txt");
+                        SttLogger.Log($"[STT] Sherpa initializing NeMo CTC model={Path.GetFileName(config.ModelConfig.NeMoCtc.Model)}, threads={_numThreads}");
+                        break;
+
+                    case SherpaModelType.Paraformer:
+                        config.ModelConfig.Paraformer.Model = FindModelFile("model*.onnx");
+                        config.ModelConfig.Tokens = Path.Combine(_modelDirectory, "tokens.txt");
+                        SttLogger.Log($"[STT] Sherpa initializing Paraformer model={Path.GetFileName(config.ModelConfig.Paraformer.Model)}, threads={_numThreads}");
+                        break;
+
+                    case SherpaModelType.SenseVoice:
+                        config.ModelConfig.SenseVoice.Model = FindModelFile("model*.onnx");
+                        // SenseVoice knows only a few languages; anything else is left to auto-detection
+                        config.ModelConfig.SenseVoice.Language = _language is "zh" or "en" or "ja" or "ko" or "yue" ? _language : "auto";
+                        config.ModelConfig.SenseVoice.UseInverseTextNormalization = 1;
+                        config.ModelConfig.Tokens = Path.Combine(_modelDirectory, "tokens.txt");
+                        SttLogger.Log($"[STT] Sherpa initializing SenseVoice model={Path.GetFileName(config.ModelConfig.SenseVoice.Model)}, language={_language ?? "auto"}, threads={_numThreads}");
+                        break;
+
+                    case SherpaModelType.Whisper:
+                        config.ModelConfig.Whisper.Encoder = FindModelFile("*encoder*.onnx");
+                        config.ModelConfig.Whisper.Decoder = FindModelFile("*decoder*.onnx");
+                        config.ModelConfig.Whisper.Language = _language ?? string.Empty;
+                        config.ModelConfig.Whisper.Task = _task == WhisperTask.Translate ? "translate" : "transcribe";
+                        config.ModelConfig.Tokens = FindModelFile("*tokens.txt");
+                        SttLogger.Log($"[STT] Sherpa initializing Whisper encoder={Path.GetFileName(config.ModelConfig.Whisper.Encoder)}, " +
+                            $"decoder={Path.GetFileName(config.ModelConfig.Whisper.Decoder)}, language={_language ?? "auto"}, task={_task}, threads={_numThreads}");
+                        break;
+
+                    default:
+                        // Parakeet TDT models use transducer architecture: encoder + decoder + joiner + tokens
+                        string encoderPath = FindModelFile("encoder*.onnx");
+                        string decoderPath = FindModelFile("decoder*.onnx");
+                        string joinerPath = FindModelFile("joiner*.onnx");
+
+                        SttLogger.Log($"[STT] Sherpa initializing with encoder={Path.GetFileName(encoderPath)}, " +
+                            $"decoder={Path.GetFileName(decoderPath)}, joiner={Path.GetFileName(joinerPath)}, " +
+                            $"threads={_numThreads}");
+
+                        // Transducer model config
+                        config.ModelConfig.Transducer.Encoder = encoderPath;
+                        config.ModelConfig.Transducer.Decoder = decoderPath;
+                        config.ModelConfig.Transducer.Joiner = joinerPath;
+                        config.ModelConfig.Tokens = Path.Combine(_modelDirectory, "tokens.txt");
+                        break;
+                }
+
                 config.ModelConfig.NumThreads = _numThreads;
                 config.ModelConfig.Debug = 0;
 
-                // Decoding
-                config.DecodingMethod = "greedy_search";
+                // Decoding: only transducers support beam search in Sherpa's offline recognizer
+                bool beamSearch = modelType == SherpaModelType.Transducer && _decodingStrategy == DecodingStrategy.BeamSearch;
+                config.DecodingMethod = beamSearch ? "modified_beam_search" : "greedy_search";
+                if (beamSearch)
+                {
+                    config.MaxActivePaths = _beamSize;
+                    SttLogger.Log($"[STT] Sherpa using beam search, beam size {_beamSize}");
+                }
+
+                bool hasHotwords = !string.IsNullOrEmpty(_hotwordsFile) && File.Exists(_hotwordsFile);
+                if (hasHotwords && !SupportsHotwords(modelType))
+                {
+                    // Sherpa's offline CTC and Whisper decoders have no contextual biasing
+                    SttLogger.Log($"[STT] Hotwords are not supported by {modelType} models and will be ignored. Use a transducer model (Parakeet, Zipformer) for custom vocabulary.");
+                }
+                else if (hasHotwords)
+                {
+                    // Hotwords are only honoured by beam search
+                    var hotwordsConfig = config;
+                    hotwordsConfig.DecodingMethod = "modified_beam_search";
+                    hotwordsConfig.MaxActivePaths = beamSearch ? _beamSize : 4;
+                    hotwordsConfig.HotwordsFile = _hotwordsFile;
+                    hotwordsConfig.HotwordsScore = 1.5f;
+
+                    try
+                    {
+                        _recognizer = new OfflineRecognizer(hotwordsConfig);
+                        HotwordsActive = true;
+                        SttLogger.Log($"[STT] Sherpa hotwords loaded from {_hotwordsFile}");
+                    }
+                    catch (Exception ex)
+                    {
+                        SttLogger.Log($"[STT] Sherpa rejected hotwords, continuing without them: {ex.Message}");
+                    }
+                }
 
-                _recognizer = new OfflineRecognizer(config);
+                _recognizer ??= new OfflineRecognizer(config);
 
                 SttLogger.Log($"[STT] Sherpa Parakeet engine initialized successfully. Model: {Path.GetFileName(_modelDirectory)}");
             }
             catch (Exception ex)
             {
                 SttLogger.Log($"[STT] Sherpa initialization failed: {ex.Message}\n{ex.StackTrace}");
+                LoadError = $"The model files couldn't be loaded, they may be corrupt or incomplete: {ex.Message}";
                 _recognizer = null;
             }
         }
 
-        public async Task<string> TranscribeAsync(float[] audioSamples, CancellationToken ct)
+        public async Task<string> TranscribeAsync(float[] audioSamples, CancellationToken ct, IProgress<int>? progress = null)
         {
-            if (_recognizer == null)
-                throw new InvalidOperationException("Sherpa Parakeet engine is not available or initialized.");
+            OfflineRecognizer recognizer;
+            lock (_decodeLock)
+            {
+                if (_disposed)
+                    throw new ObjectDisposedException(nameof(SherpaOnnxEngine), "The Sherpa engine was unloaded.");
+                recognizer = _recognizer
+                    ?? throw new InvalidOperationException("Sherpa Parakeet engine is not available or initialized.");
+                _activeDecodes++;
+            }
+
+            try
+            {
+                return await DecodeAsync(recognizer, audioSamples, ct, progress);
+            }
+            finally
+            {
+                lock (_decodeLock)
+                {
+                    _activeDecodes--;
+                    if (_activeDecodes == 0 && _disposed)
+                        ReleaseRecognizer();
+                }
+            }
+        }
 
-            return await Task.Run(() =>
+        private Task<string> DecodeAsync(OfflineRecognizer recognizer, float[] audioSamples, CancellationToken ct, IProgress<int>? progress)
+        {
+            return Task.Run(() =>
             {
                 ct.ThrowIfCancellationRequested();
 
@@ -103,15 +306,30 @@ namespace EliteWhisper.Services.Speech
                 SttLogger.Log($"[STT] Audio length: {audioSamples.Length} samples ({audioSamples.Length / 16000.0:F2}s)");
 
                 var watch = Stopwatch.StartNew();
+                double audioSeconds = audioSamples.Length / 16000.0;
+
+                // Estimated from elapsed time vs. the expected decode time, held below 100 until done
+                bool decoded = false;
+                using var ticker = progress == null ? null : new Timer(_ =>
+                {
+                    if (decoded) return;
+                    double expectedMs = Math.Max(1, audioSeconds * _msPerAudioSecond);
+                    progress.Report((int)Math.Min(95, watch.ElapsedMilliseconds / expectedMs * 100));
+                }, null, PROGRESS_INTERVAL_MS, PROGRESS_INTERVAL_MS);
 
                 try
                 {
                     // Create stream and feed audio
-                    var stream = _recognizer.CreateStream();
+                    var stream = recognizer.CreateStream();
                     stream.AcceptWaveform(16000, audioSamples);
 
-                    // Decode
-                    _recognizer.Decode(stream);
+                    // Decode. Native decoding can't be interrupted, so a cancel lands right after it.
+                    recognizer.Decode(stream);
+                    decoded = true;
+                    ct.ThrowIfCancellationRequested();
+
+                    if (audioSeconds >= 1) _msPerAudioSecond = watch.ElapsedMilliseconds / audioSeconds;
+                    progress?.Report(100);
 
                     // Get result
                     var result = stream.Result;
@@ -123,7 +341,7 @@ namespace EliteWhisper.Services.Speech
 
                     return text;
                 }
-                catch (Exception ex)
+                catch (Exception ex) when (ex is not OperationCanceledException)
                 {
                     watch.Stop();
                     SttLogger.Log($"[STT] Sherpa transcription error after {watch.ElapsedMilliseconds}ms: {ex.Message}");
@@ -135,11 +353,13 @@ namespace EliteWhisper.Services.Speech
         /// <summary>
         /// Finds a model file matching a glob pattern in the model directory.
         /// </summary>
-        private string FindModelFile(string pattern)
+        private string FindModelFile(string pattern) => FindFile(_modelDirectory, pattern);
+
+        private static string FindFile(string directory, string pattern)
         {
             try
             {
-                var files = Directory.GetFiles(_modelDirectory, pattern, SearchOption.TopDirectoryOnly);
+                var files = Directory.GetFiles(directory, pattern, SearchOption.TopDirectoryOnly);
                 if (files.Length > 0)
                     return files[0];
             }
@@ -147,14 +367,131 @@ namespace EliteWhisper.Services.Speech
             return string.Empty;
         }
 
+        /// <summary>
+        /// Guesses the model architecture from file names. Null if nothing recognizable is present.
+        /// </summary>
+        public static SherpaModelType? DetectModelType(string directory)
+        {
+            if (GetMissingFiles(directory, SherpaModelType.Transducer).Count == 0) return SherpaModelType.Transducer;
+            if (GetMissingFiles(directory, SherpaModelType.Whisper).Count == 0) return SherpaModelType.Whisper;
+            if (GetMissingFiles(directory, SherpaModelType.NemoCtc).Count == 0) return DetectSingleFileModelType(directory);
+            return null;
+        }
+
+        // Paraformer, SenseVoice and CTC models all ship as model*.onnx + tokens.txt. Sherpa's release
+        // folders are named after the architecture; otherwise the export metadata tells them apart.
+        private static SherpaModelType DetectSingleFileModelType(string directory)
+        {
+            string name = Path.GetFileName(directory.TrimEnd(Path.DirectorySeparatorChar, Path.AltDirectorySeparatorChar)).ToLowerInvariant();
+            if (name.Contains("paraformer")) return SherpaModelType.Paraformer;
+            if (name.Contains("sense-voice") || name.Contains("sensevoice")) return SherpaModelType.SenseVoice;
+            if (name.Contains("ctc")) return SherpaModelType.NemoCtc;
+
+            string metadata = ReadModelTail(FindFile(directory, "model*.onnx"));
+            if (metadata.Contains("LFR_m")) return SherpaModelType.Paraformer;
+            if (metadata.Contains("with_itn")) return SherpaModelType.SenseVoice;
+            return SherpaModelType.NemoCtc;
+        }
+
+        // ONNX writes the model's metadata_props after the graph, so the keys are in the last few KB
+        private static string ReadModelTail(string modelPath)
+        {
+            try
+            {
+                using var stream = File.OpenRead(modelPath);
+                int length = (int)Math.Min(stream.Length, 64 * 1024);
+                stream.Seek(-length, SeekOrigin.End);
+                var buffer = new byte[length];
+                stream.ReadExactly(buffer);
+                return System.Text.Encoding.ASCII.GetString(buffer);
+            }
+            catch
+            {
+                return string.Empty;
+            }
+        }
+
+        /// <summary>
+        /// Lists the model files in a directory, for errors about layouts that weren't recognized.
+        /// </summary>
+        public static string DescribeLayout(string directory)
+        {
+            try
+            {
+                var files = Directory.GetFiles(directory, "*", SearchOption.TopDirectoryOnly)
+                    .Select(f => Path.GetFileName(f))
+                    .Where(f => f.EndsWith(".onnx", StringComparison.OrdinalIgnoreCase) || f.EndsWith(".txt", StringComparison.OrdinalIgnoreCase))
+                    .ToList();
+                return files.Count == 0 ? "No .onnx or .txt files found." : $"Found: {string.Join(", ", files)}.";
+            }
+            catch (Exception ex)
+            {
+                return $"The folder couldn't be read: {ex.Message}";
+            }
+        }
+
+        /// <summary>
+        /// Files the given model type needs that are not present in the directory.
+        /// </summary>
+        public static List<string> GetMissingFiles(string directory, SherpaModelType modelType)
+        {
+            var missing = new List<string>();
+            if (!Directory.Exists(directory))
+            {
+                missing.Add(directory);
+                return missing;
+            }
+
+            void Require(string pattern)
+            {
+                if (string.IsNullOrEmpty(FindFile(directory, pattern))) missing.Add(pattern);
+            }
+
+            switch (modelType)
+            {
+                case SherpaModelType.NemoCtc:
+                case SherpaModelType.Paraformer:
+                case SherpaModelType.SenseVoice:
+                    Require("model*.onnx");
+                    Require("tokens.txt");
+                    break;
+                case SherpaModelType.Whisper:
+                    Require("*encoder*.onnx");
+                    Require("*decoder*.onnx");
+                    Require("*tokens.txt");
+                    break;
+                default:
+                    Require("encoder*.onnx");
+                    Require("decoder*.onnx");
+                    Require("joiner*.onnx");
+                    Require("tokens.txt");
+                    break;
+            }
+
+            return missing;
+        }
+
+        /// <summary>
+        /// Unloads the model. If a transcription is still decoding, the native recognizer is
+        /// freed when it finishes instead of underneath it.
+        /// </summary>
         public void Dispose()
         {
-            if (!_disposed)
+            lock (_decodeLock)
             {
-                _recognizer?.Dispose();
-                _recognizer = null;
+                if (_disposed) return;
                 _disposed = true;
+                if (_activeDecodes == 0)
+                    ReleaseRecognizer();
+                else
+                    SttLogger.Log($"[STT] Sherpa engine unload deferred until {_activeDecodes} running decode(s) finish");
             }
         }
+
+        private void ReleaseRecognizer()
+        {
+            _recognizer?.Dispose();
+            _recognizer = null;
+        }
     }
 }
@@ -0,0 +1,214 @@
+using System;
+using System.IO;
+using System.Text;
+using System.Threading.Channels;
+using System.Threading.Tasks;
+using SherpaOnnx;
+
+namespace EliteWhisper.Services.Speech
+{
+    /// <summary>
+    /// Live transcription with a Sherpa-ONNX streaming (online) transducer, e.g. a streaming Zipformer.
+    /// Microphone chunks are decoded as they arrive: <see cref="PartialTranscript"/> fires whenever the
+    /// text of the current utterance changes and <see cref="FinalTranscript"/> when Sherpa's endpointing
+    /// detects the end of an utterance. Separate from the offline file-based flow.
+    /// </summary>
+    public class SherpaStreamingEngine : IDisposable
+    {
+        private OnlineRecognizer? _recognizer;
+        private OnlineStream? _stream;
+        private Channel<float[]>? _chunks;
+        private Task? _decodeTask;
+        private readonly StringBuilder _finalText = new();
+        private readonly object _lock = new object();
+        private string _lastPartial = string.Empty;
+        private bool _disposed;
+
+        private const int SAMPLE_RATE = 16000;
+
+        public string ModelDirectory { get; }
+
+        public bool IsAvailable => _recognizer != null;
+
+        public string Name => "Zipformer Streaming (Sherpa)";
+
+        /// <summary>
+        /// Text of the utterance in progress. Raised on a background thread.
+        /// </summary>
+        public event EventHandler<string>? PartialTranscript;
+
+        /// <summary>
+        /// Text of an utterance Sherpa's endpointing has closed. Raised on a background thread.
+        /// </summary>
+        public event EventHandler<string>? FinalTranscript;
+
+        public SherpaStreamingEngine(string modelDirectory, int? numThreads = null)
+        {
+            ModelDirectory = modelDirectory;
+
+            try
+            {
+                var missing = SherpaOnnxEngine.GetMissingFiles(modelDirectory, Models.SherpaModelType.Transducer);
+                if (missing.Count > 0)
+                {
+                    SttLogger.Log($"[STT] Streaming model files incomplete in {modelDirectory}. Missing: {string.Join(", ", missing)}");
+                    return;
+                }
+
+                var config = new OnlineRecognizerConfig();
+                config.FeatConfig.SampleRate = SAMPLE_RATE;
+                config.FeatConfig.FeatureDim = 80;
+                config.ModelConfig.Transducer.Encoder = FindFile(modelDirectory, "encoder*.onnx");
+                config.ModelConfig.Transducer.Decoder = FindFile(modelDirectory, "decoder*.onnx");
+                config.ModelConfig.Transducer.Joiner = FindFile(modelDirectory, "joiner*.onnx");
+                config.ModelConfig.Tokens = Path.Combine(modelDirectory, "tokens.txt");
+                config.ModelConfig.NumThreads = numThreads ?? Math.Max(1, Environment.ProcessorCount / 2);
+                config.ModelConfig.Provider = "cpu";
+                config.ModelConfig.Debug = 0;
+                config.DecodingMethod = "greedy_search";
+
+                // Sherpa's endpointing: a long pause always ends an utterance, a shorter one ends it
+                // once something was said, and very long utterances are cut regardless
+                config.EnableEndpoint = 1;
+                config.Rule1MinTrailingSilence = 2.4f;
+                config.Rule2MinTrailingSilence = 0.8f;
+                config.Rule3MinUtteranceLength = 20f;
+
+                _recognizer = new OnlineRecognizer(config);
+                SttLogger.Log($"[STT] Sherpa streaming engine initialized. Model: {Path.GetFileName(modelDirectory)}");
+            }
+            catch (Exception ex)
+            {
+                SttLogger.Log($"[STT] Sherpa streaming initialization failed: {ex.Message}");
+                _recognizer = null;
+            }
+        }
+
+        /// <summary>
+        /// Start a new session. Any session still running is discarded.
+        /// </summary>
+        public void Begin()
+        {
+            if (_recognizer == null)
+                throw new InvalidOperationException("Sherpa streaming engine is not available or initialized.");
+
+            lock (_lock)
+            {
+                _chunks?.Writer.TryComplete();
+                _finalText.Clear();
+                _lastPartial = string.Empty;
+                _stream = _recognizer.CreateStream();
+
+                var chunks = Channel.CreateUnbounded<float[]>(new UnboundedChannelOptions { SingleReader = true });
+                _chunks = chunks;
+                var stream = _stream;
+                _decodeTask = Task.Run(() => DecodeLoopAsync(chunks.Reader, stream));
+            }
+        }
+
+        /// <summary>
+        /// Queue 16kHz mono samples for decoding. Returns immediately; safe to call from the capture thread.
+        /// </summary>
+        public void AcceptSamples(float[] samples)
+        {
+            _chunks?.Writer.TryWrite(samples);
+        }
+
+        /// <summary>
+        /// Flush the remaining audio and return the full transcript of the session.
+        /// </summary>
+        public async Task<string> FinishAsync()
+        {
+            Task? decodeTask;
+            lock (_lock)
+            {
+                _chunks?.Writer.TryComplete();
+                _chunks = null;
+                decodeTask = _decodeTask;
+                _decodeTask = null;
+            }
+
+            if (decodeTask != null) await decodeTask;
+
+            lock (_lock)
+            {
+                _stream = null;
+                return _finalText.ToString().Trim();
+            }
+        }
+
+        private async Task DecodeLoopAsync(ChannelReader<float[]> reader, OnlineStream stream)
+        {
+            try
+            {
+                await foreach (var chunk in reader.ReadAllAsync())
+                {
+                    stream.AcceptWaveform(SAMPLE_RATE, chunk);
+                    Decode(stream, endOfInput: false);
+                }
+
+                // Tail padding lets the model emit the last tokens before input ends
+                stream.AcceptWaveform(SAMPLE_RATE, new float[SAMPLE_RATE / 2]);
+                stream.InputFinished();
+                Decode(stream, endOfInput: true);
+            }
+            catch (Exception ex)
+            {
+                SttLogger.Log($"[STT] Streaming decode failed: {ex.Message}");
+            }
+        }
+
+        private void Decode(OnlineStream stream, bool endOfInput)
+        {
+            var recognizer = _recognizer;
+            if (recognizer == null) return;
+
+            while (recognizer.IsReady(stream))
+            {
+                recognizer.Decode(stream);
+            }
+
+            string text = recognizer.GetResult(stream).Text?.Trim() ?? string.Empty;
+
+            if (text != _lastPartial && !endOfInput)
+            {
+                _lastPartial = text;
+                PartialTranscript?.Invoke(this, text);
+            }
+
+            if (endOfInput || recognizer.IsEndpoint(stream))
+            {
+                if (text.Length > 0)
+                {
+                    lock (_lock)
+                    {
+                        if (_finalText.Length > 0) _finalText.Append(' ');
+                        _finalText.Append(text);
+                    }
+                    FinalTranscript?.Invoke(this, text);
+                }
+
+                recognizer.Reset(stream);
+                _lastPartial = string.Empty;
+            }
+        }
+
+        private static string FindFile(string directory, string pattern)
+        {
+            var files = Directory.GetFiles(directory, pattern, SearchOption.TopDirectoryOnly);
+            return files.Length > 0 ? files[0] : string.Empty;
+        }
+
+        public void Dispose()
+        {
+            if (!_disposed)
+            {
+                _chunks?.Writer.TryComplete();
+                try { _decodeTask?.Wait(1000); } catch { }
+                _recognizer?.Dispose();
+                _recognizer = null;
+                _disposed = true;
+            }
+        }
+    }
+}
@@ -0,0 +1,227 @@
+using System;
+using System.Collections.Generic;
+using System.IO;
+using System.Linq;
+using System.Text;
+using System.Threading;
+using System.Threading.Tasks;
+using EliteWhisper.Models;
+using SherpaOnnx;
+
+namespace EliteWhisper.Services.Speech
+{
+    /// <summary>
+    /// Labels who spoke when using Sherpa-ONNX speaker diarization: a pyannote segmentation model
+    /// finds speaker turns and a speaker embedding model clusters them into speakers.
+    /// The labels are then assigned to timestamped transcript segments by overlap.
+    /// </summary>
+    public class SpeakerDiarizationService : IDisposable
+    {
+        private readonly WhisperConfigurationService _configService;
+        private readonly object _lock = new object();
+        private OfflineSpeakerDiarization? _diarizer;
+        private string? _loadedKey;
+        private bool _disposed;
+
+        // Runs still using each diarizer. A replaced or unloaded diarizer is disposed when its last run
+        // finishes, not while native code is still processing audio with it.
+        private readonly Dictionary<OfflineSpeakerDiarization, int> _activeRuns = new();
+
+        // Cluster distance used when the number of speakers is detected automatically
+        private const float AUTO_CLUSTER_THRESHOLD = 0.5f;
+
+        public SpeakerDiarizationService(WhisperConfigurationService configService)
+        {
+            _configService = configService;
+        }
+
+        /// <summary>
+        /// True when diarization is enabled and both models are present.
+        /// </summary>
+        public bool IsAvailable
+        {
+            get
+            {
+                var config = _configService.CurrentConfiguration;
+                return config.EnableDiarization &&
+                    File.Exists(config.DiarizationSegmentationModel) &&
+                    File.Exists(config.DiarizationEmbeddingModel);
+            }
+        }
+
+        /// <summary>
+        /// Assigns a 1-based speaker number to each segment, from the speaker turn it overlaps most.
+        /// </summary>
+        public async Task LabelSpeakersAsync(float[] audioSamples, List<TranscriptSegment> segments, CancellationToken ct)
+        {
+            if (segments.Count == 0) return;
+
+            var turns = await Task.Run(() =>
+            {
+                ct.ThrowIfCancellationRequested();
+                var diarizer = AcquireDiarizer();
+
+                try
+                {
+                    var watch = System.Diagnostics.Stopwatch.StartNew();
+                    var result = diarizer.Process(audioSamples);
+                    SttLogger.Log($"[STT] Diarization found {result.Select(r => r.Speaker).Distinct().Count()} speaker(s) " +
+                        $"in {result.Length} turn(s), {watch.ElapsedMilliseconds}ms");
+                    return result;
+                }
+                finally
+                {
+                    ReleaseDiarizer(diarizer);
+                }
+            }, ct);
+
+            // Sherpa numbers speakers from 0 in no particular order; renumber by first appearance
+            var speakerNumbers = new Dictionary<int, int>();
+            foreach (var segment in segments)
+            {
+                int? best = null;
+                float bestOverlap = 0;
+                float start = segment.StartMs / 1000f;
+                float end = segment.EndMs / 1000f;
+
+                foreach (var turn in turns)
+                {
+                    float overlap = Math.Min(end, turn.End) - Math.Max(start, turn.Start);
+                    if (overlap > bestOverlap)
+                    {
+                        bestOverlap = overlap;
+                        best = turn.Speaker;
+                    }
+                }
+
+                if (best == null) continue;
+                if (!speakerNumbers.TryGetValue(best.Value, out int number))
+                {
+                    number = speakerNumbers.Count + 1;
+                    speakerNumbers[best.Value] = number;
+                }
+                segment.Speaker = number;
+            }
+
+            // Segments with no overlapping turn (e.g. very short) join the previous speaker
+            int? previous = null;
+            foreach (var segment in segments)
+            {
+                segment.Speaker ??= previous;
+                previous = segment.Speaker;
+            }
+        }
+
+        /// <summary>
+        /// Formats labeled segments as "Speaker N: text" paragraphs, merging consecutive segments of one speaker.
+        /// </summary>
+        public static string FormatTranscript(IEnumerable<TranscriptSegment> segments)
+        {
+            var sb = new StringBuilder();
+            int? current = null;
+
+            foreach (var segment in segments)
+            {
+                if (sb.Length == 0 || segment.Speaker != current)
+                {
+                    if (sb.Length > 0) sb.Append("\n\n");
+                    if (segment.Speaker != null) sb.Append($"Speaker {segment.Speaker}: ");
+                    current = segment.Speaker;
+                }
+                else
+                {
+                    sb.Append(' ');
+                }
+                sb.Append(segment.Text.Trim());
+            }
+
+            return sb.ToString();
+        }
+
+        /// <summary>
+        /// Returns the diarizer for the current settings, loading it if they changed, and counts the caller as a user
+        /// until <see cref="ReleaseDiarizer"/>.
+        /// </summary>
+        private OfflineSpeakerDiarization AcquireDiarizer()
+        {
+            var config = _configService.CurrentConfiguration;
+            string key = $"{config.DiarizationSegmentationModel}|{config.DiarizationEmbeddingModel}|{config.DiarizationSpeakerCount}";
+
+            lock (_lock)
+            {
+                if (_disposed)
+                    throw new ObjectDisposedException(nameof(SpeakerDiarizationService));
+
+                if (_diarizer == null || _loadedKey != key)
+                {
+                    RetireDiarizer();
+                    _diarizer = LoadDiarizer(config);
+                    _loadedKey = key;
+                }
+
+                _activeRuns[_diarizer] = _activeRuns.GetValueOrDefault(_diarizer) + 1;
+                return _diarizer;
+            }
+        }
+
+        private void ReleaseDiarizer(OfflineSpeakerDiarization diarizer)
+        {
+            lock (_lock)
+            {
+                int runs = _activeRuns[diarizer] - 1;
+                if (runs > 0)
+                {
+                    _activeRuns[diarizer] = runs;
+                    return;
+                }
+
+                _activeRuns.Remove(diarizer);
+                if (diarizer != _diarizer) diarizer.Dispose();
+            }
+        }
+
+        // Unloads the current diarizer, or leaves it to the last running call to dispose. Call with _lock held.
+        private void RetireDiarizer()
+        {
+            if (_diarizer == null) return;
+
+            if (_activeRuns.ContainsKey(_diarizer))
+                SttLogger.Log("[STT] Diarization unload deferred until the running diarization finishes");
+            else
+                _diarizer.Dispose();
+
+            _diarizer = null;
+            _loadedKey = null;
+        }
+
+        private static OfflineSpeakerDiarization LoadDiarizer(WhisperConfiguration config)
+        {
+            var diarizationConfig = new OfflineSpeakerDiarizationConfig();
+            diarizationConfig.Segmentation.Pyannote.Model = config.DiarizationSegmentationModel!;
+            diarizationConfig.Segmentation.NumThreads = Math.Max(1, config.TranscriptionThreads);
+            diarizationConfig.Embedding.Model = config.DiarizationEmbeddingModel!;
+            diarizationConfig.Embedding.NumThreads = Math.Max(1, config.TranscriptionThreads);
+
+            // A known speaker count fixes the clusters; otherwise the threshold decides
+            diarizationConfig.Clustering.NumClusters = config.DiarizationSpeakerCount > 0 ? config.DiarizationSpeakerCount : -1;
+            diarizationConfig.Clustering.Threshold = AUTO_CLUSTER_THRESHOLD;
+            diarizationConfig.MinDurationOn = 0.3f;
+            diarizationConfig.MinDurationOff = 0.5f;
+
+            var diarizer = new OfflineSpeakerDiarization(diarizationConfig);
+            SttLogger.Log($"[STT] Diarization loaded: segmentation={Path.GetFileName(config.DiarizationSegmentationModel)}, " +
+                $"embedding={Path.GetFileName(config.DiarizationEmbeddingModel)}, speakers={(config.DiarizationSpeakerCount > 0 ? config.DiarizationSpeakerCount.ToString() : "auto")}");
+            return diarizer;
+        }
+
+        public void Dispose()
+        {
+            lock (_lock)
+            {
+                if (_disposed) return;
+                _disposed = true;
+                RetireDiarizer();
+            }
+        }
+    }
+}
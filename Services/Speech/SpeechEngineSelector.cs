@@ -1,28 +1,94 @@
 using System;
 using System.IO;
 using System.Linq;
+using System.Threading;
+using System.Threading.Tasks;
+using EliteWhisper.Models;
 
 namespace EliteWhisper.Services.Speech
 {
-    public class SpeechEngineSelector
+    public class SpeechEngineSelector : IDisposable
     {
         private readonly HardwareDetectionService _hardwareProfile;
         private readonly WhisperConfigurationService _configService;
+        private readonly HotwordsService _hotwords;
         
+        private readonly AIEngineService _whisperCore;
         private SherpaOnnxEngine? _sherpaEngine;
+        private string? _sherpaModelDirectory;
+        private readonly object _sherpaLock = new object();
         private WhisperEngine _whisper;
+        private CancellationTokenSource? _warmUpCts;
+        private SherpaStreamingEngine? _streamingEngine;
+
+        // Recently used Sherpa models kept loaded besides the active one, most recent first
+        private readonly System.Collections.Generic.List<(string Directory, SherpaOnnxEngine Engine)> _standbyEngines = new();
+
+        // Half a second of silence is enough to initialize the runtime without a real decode
+        private const int WARM_UP_SAMPLES = 8000;
+
+        /// <summary>
+        /// Raised with the engine name once a warm-up run has finished. Raised on a background thread.
+        /// </summary>
+        public event EventHandler<string>? ModelReady;
+
+        /// <summary>
+        /// Raised with a description after a failed engine was discarded and reloaded. Raised on a background thread.
+        /// </summary>
+        public event EventHandler<string>? EngineReset;
         
         public SpeechEngineSelector(
             HardwareDetectionService hardwareProfile, 
             WhisperConfigurationService configService,
-            AIEngineService whisperCore)
+            AIEngineService whisperCore,
+            HotwordsService hotwords)
         {
             _hardwareProfile = hardwareProfile;
             _configService = configService;
+            _hotwords = hotwords;
+            _whisperCore = whisperCore;
             
             _whisper = new WhisperEngine(whisperCore);
             
             InitializeEngines();
+
+            _whisperCore.ModelActivated += (s, path) => StartWarmUp();
+            StartWarmUp();
+        }
+
+        /// <summary>
+        /// Transcribes a short silent clip on the selected engine in the background and discards the
+        /// result, so the first real dictation after a model load is fast. A newer call cancels an older one.
+        /// </summary>
+        public void StartWarmUp()
+        {
+            if (!_configService.CurrentConfiguration.WarmUpModelOnLoad) return;
+
+            var cts = new CancellationTokenSource();
+            Interlocked.Exchange(ref _warmUpCts, cts)?.Cancel();
+
+            _ = Task.Run(async () =>
+            {
+                try
+                {
+                    var engine = GetBestEngine();
+                    if (!engine.IsAvailable) return;
+
+                    var watch = System.Diagnostics.Stopwatch.StartNew();
+                    await engine.TranscribeAsync(new float[WARM_UP_SAMPLES], cts.Token);
+                    SttLogger.Log($"[STT] Warm-up of {engine.Name} finished in {watch.ElapsedMilliseconds}ms");
+
+                    ModelReady?.Invoke(this, engine.Name);
+                }
+                catch (OperationCanceledException)
+                {
+                }
+                catch (Exception ex)
+                {
+                    // A failed warm-up only costs the first dictation its speed-up
+                    SttLogger.Log($"[STT] Warm-up failed: {ex.Message}");
+                }
+            });
         }
 
         private void InitializeEngines()
@@ -35,11 +101,28 @@ namespace EliteWhisper.Services.Speech
                 Directory.CreateDirectory(appDataPath);
 
             // ── Sherpa-ONNX Parakeet TDT ──────────────────────────────────
-            InitializeSherpaEngine(appDataPath);
+            // Load hotwords from a private snapshot so a concurrent save can't hand
+            // the recognizer a half-written file.
+            string? hotwordsSnapshot = null;
+            try
+            {
+                hotwordsSnapshot = _hotwords.CreateSnapshot();
+                InitializeSherpaEngine(appDataPath, hotwordsSnapshot);
+            }
+            finally
+            {
+                if (hotwordsSnapshot != null)
+                {
+                    try { File.Delete(hotwordsSnapshot); } catch { }
+                }
+            }
         }
 
-        private void InitializeSherpaEngine(string modelsDir)
+        private void InitializeSherpaEngine(string modelsDir, string? hotwordsFile)
         {
+            // Forced type from settings, otherwise detected per directory
+            var forcedType = _configService.CurrentConfiguration.SherpaModelType;
+
             // Look for known Sherpa model directories
             string[] sherpaModelNames = new[]
             {
@@ -55,21 +138,19 @@ namespace EliteWhisper.Services.Speech
                 if (Directory.Exists(modelDir))
                 {
                     // Verify it has the required files
-                    string tokensPath = Path.Combine(modelDir, "tokens.txt");
-                    bool hasEncoder = Directory.GetFiles(modelDir, "encoder*.onnx").Length > 0;
-                    bool hasDecoder = Directory.GetFiles(modelDir, "decoder*.onnx").Length > 0;
-                    bool hasJoiner = Directory.GetFiles(modelDir, "joiner*.onnx").Length > 0;
-                    bool hasTokens = File.Exists(tokensPath);
-
-                    if (hasEncoder && hasDecoder && hasJoiner && hasTokens)
+                    if (HasSherpaModelFiles(modelDir, forcedType))
                     {
                         SttLogger.Log($"[STT] Discovered Sherpa Parakeet TDT model at: {modelDir}");
                         try
                         {
-                            _sherpaEngine = new SherpaOnnxEngine(modelDir);
+                            _sherpaEngine = new SherpaOnnxEngine(modelDir, numThreads: GetThreadCount(), hotwordsFile: hotwordsFile, modelType: forcedType,
+                                language: GetSherpaLanguage(), task: _configService.CurrentConfiguration.WhisperTask,
+                                decodingStrategy: _configService.CurrentConfiguration.DecodingStrategy, beamSize: _configService.CurrentConfiguration.BeamSize)
+                            { HotwordsVersion = _hotwords.Version };
                             if (_sherpaEngine.IsAvailable)
                             {
                                 SttLogger.Log("[STT] Sherpa Parakeet engine initialized successfully.");
+                                _sherpaModelDirectory = modelDir;
                                 return; // Use the first valid model
                             }
                             else
@@ -102,20 +183,19 @@ namespace EliteWhisper.Services.Speech
                     string dirName = Path.GetFileName(dir);
                     if (!dirName.StartsWith("sherpa-onnx", StringComparison.OrdinalIgnoreCase)) continue;
 
-                    string tokensPath = Path.Combine(dir, "tokens.txt");
-                    bool hasEncoder = Directory.GetFiles(dir, "encoder*.onnx").Length > 0;
-                    bool hasDecoder = Directory.GetFiles(dir, "decoder*.onnx").Length > 0;
-                    bool hasJoiner = Directory.GetFiles(dir, "joiner*.onnx").Length > 0;
-
-                    if (hasEncoder && hasDecoder && hasJoiner && File.Exists(tokensPath))
+                    if (HasSherpaModelFiles(dir, forcedType))
                     {
                         SttLogger.Log($"[STT] Discovered generic Sherpa model at: {dir}");
                         try
                         {
-                            _sherpaEngine = new SherpaOnnxEngine(dir);
+                            _sherpaEngine = new SherpaOnnxEngine(dir, numThreads: GetThreadCount(), hotwordsFile: hotwordsFile, modelType: forcedType,
+                                language: GetSherpaLanguage(), task: _configService.CurrentConfiguration.WhisperTask,
+                                decodingStrategy: _configService.CurrentConfiguration.DecodingStrategy, beamSize: _configService.CurrentConfiguration.BeamSize)
+                            { HotwordsVersion = _hotwords.Version };
                             if (_sherpaEngine.IsAvailable)
                             {
                                 SttLogger.Log($"[STT] Sherpa engine loaded from {dirName}");
+                                _sherpaModelDirectory = dir;
                                 return;
                             }
                             else
@@ -142,6 +222,239 @@ namespace EliteWhisper.Services.Speech
             }
         }
 
+        /// <summary>
+        /// Loads a Sherpa model and keeps its recognizer resident for all following transcriptions,
+        /// disposing the previously loaded one. Does nothing if that model is already loaded.
+        /// Call off the UI thread: loading a large model takes a few seconds.
+        /// </summary>
+        public bool PreloadSherpaModel(string modelDirectory) => PreloadSherpaModel(modelDirectory, warmUp: true);
+
+        /// <summary>
+        /// Why the last Sherpa model load failed. Null after a successful load.
+        /// </summary>
+        public string? LastLoadError { get; private set; }
+
+        /// <summary>
+        /// Loads and test-decodes a Sherpa model, and only if that works makes it the active engine and
+        /// saves it as the active model. On failure the current engine and settings stay as they were.
+        /// Returns an error message, or null on success. Call off the UI thread.
+        /// </summary>
+        public string? ActivateSherpaModel(string modelDirectory, SherpaModelType modelType = SherpaModelType.Auto)
+        {
+            lock (_sherpaLock)
+            {
+                if (!PreloadSherpaModel(modelDirectory, warmUp: false, modelType))
+                    return LastLoadError ?? "The model couldn't be loaded.";
+
+                _configService.SetActiveSherpaModel(modelDirectory, modelType);
+            }
+
+            // After saving, so the warm-up selects the new model
+            StartWarmUp();
+            return null;
+        }
+
+        private bool PreloadSherpaModel(string modelDirectory, bool warmUp, SherpaModelType? modelType = null)
+        {
+            lock (_sherpaLock)
+            {
+                if (_sherpaEngine?.IsAvailable == true && !IsSherpaStale(_sherpaEngine) &&
+                    string.Equals(_sherpaModelDirectory, modelDirectory, StringComparison.OrdinalIgnoreCase))
+                {
+                    return true;
+                }
+
+                // A recently used model can be swapped back in without reloading
+                int cached = _standbyEngines.FindIndex(e => string.Equals(e.Directory, modelDirectory, StringComparison.OrdinalIgnoreCase));
+                if (cached >= 0)
+                {
+                    var entry = _standbyEngines[cached];
+                    _standbyEngines.RemoveAt(cached);
+
+                    if (entry.Engine.IsAvailable && !IsSherpaStale(entry.Engine))
+                    {
+                        SttLogger.Log($"[STT] Reusing cached Sherpa model: {modelDirectory}");
+                        SetActiveSherpaEngine(entry.Engine, modelDirectory);
+                        LastLoadError = null;
+                        return true;
+                    }
+
+                    entry.Engine.Dispose();
+                }
+
+                SttLogger.Log($"[STT] Preloading Sherpa model: {modelDirectory}");
+
+                string? hotwordsSnapshot = null;
+                try
+                {
+                    hotwordsSnapshot = _hotwords.CreateSnapshot();
+                    var engine = new SherpaOnnxEngine(modelDirectory, numThreads: GetThreadCount(), hotwordsFile: hotwordsSnapshot,
+                        modelType: modelType ?? _configService.CurrentConfiguration.SherpaModelType, language: GetSherpaLanguage(), task: _configService.CurrentConfiguration.WhisperTask,
+                        decodingStrategy: _configService.CurrentConfiguration.DecodingStrategy, beamSize: _configService.CurrentConfiguration.BeamSize)
+                        { HotwordsVersion = _hotwords.Version };
+
+                    // A model that loads but can't decode (e.g. corrupt weights) is caught here, not mid-dictation
+                    string? error = engine.IsAvailable ? ProbeEngine(engine) : engine.LoadError ?? "The model couldn't be loaded.";
+                    if (error != null)
+                    {
+                        // Keep the previous model rather than ending up with none
+                        SttLogger.Log($"[STT] Sherpa preload failed ({error}), keeping {_sherpaModelDirectory ?? "no model"}");
+                        LastLoadError = error;
+                        engine.Dispose();
+                        return false;
+                    }
+
+                    SetActiveSherpaEngine(engine, modelDirectory);
+                    LastLoadError = null;
+
+                    if (warmUp) StartWarmUp();
+                    return true;
+                }
+                finally
+                {
+                    if (hotwordsSnapshot != null)
+                    {
+                        try { File.Delete(hotwordsSnapshot); } catch { }
+                    }
+                }
+            }
+        }
+
+        // Decodes a moment of silence; returns why it failed, or null
+        private static string? ProbeEngine(SherpaOnnxEngine engine)
+        {
+            try
+            {
+                engine.TranscribeAsync(new float[WARM_UP_SAMPLES], CancellationToken.None).GetAwaiter().GetResult();
+                return null;
+            }
+            catch (Exception ex)
+            {
+                return $"The model loaded but failed a test transcription: {ex.Message}";
+            }
+        }
+
+        /// <summary>
+        /// Replaces a Sherpa engine whose recognizer threw mid-decode. The native recognizer may be left
+        /// in a broken state that fails every later transcription, so the model is reloaded from disk;
+        /// if that fails too the engine is dropped and selection falls back to Whisper.
+        /// Whisper runs in a fresh process per transcription and needs no reset.
+        /// </summary>
+        public void ResetEngine(ISpeechEngine failed)
+        {
+            if (failed is not SherpaOnnxEngine sherpa) return;
+
+            string message;
+            lock (_sherpaLock)
+            {
+                // Already replaced by a model switch or an earlier reset
+                if (_sherpaEngine != sherpa) return;
+
+                string? directory = _sherpaModelDirectory;
+                _sherpaEngine = null;
+                _sherpaModelDirectory = null;
+                sherpa.Dispose();
+
+                bool reloaded = directory != null && PreloadSherpaModel(directory, warmUp: false);
+                message = reloaded
+                    ? $"reloaded {Path.GetFileName(directory)} after an engine error"
+                    : "unloaded the Sherpa model after an engine error, using Whisper until it is loaded again";
+                SttLogger.Log($"[STT] Engine reset: {message}");
+            }
+
+            EngineReset?.Invoke(this, message);
+        }
+
+        /// <summary>
+        /// Makes the engine active and parks the previous one in the cache, unless it is
+        /// for the same directory or built with outdated settings. Call with _sherpaLock held.
+        /// </summary>
+        private void SetActiveSherpaEngine(SherpaOnnxEngine engine, string modelDirectory)
+        {
+            var previous = _sherpaEngine;
+            var previousDirectory = _sherpaModelDirectory;
+            _sherpaEngine = engine;
+            _sherpaModelDirectory = modelDirectory;
+
+            if (previous != null && previous != engine)
+            {
+                if (previousDirectory != null && previous.IsAvailable && !IsSherpaStale(previous) &&
+                    !string.Equals(previousDirectory, modelDirectory, StringComparison.OrdinalIgnoreCase))
+                {
+                    _standbyEngines.Insert(0, (previousDirectory, previous));
+                }
+                else
+                {
+                    previous.Dispose();
+                }
+            }
+
+            TrimModelCache();
+        }
+
+        /// <summary>
+        /// Changes how many models stay loaded and releases any above the new limit.
+        /// </summary>
+        public void SetModelCacheSize(int size)
+        {
+            _configService.SetModelCacheSize(size);
+            lock (_sherpaLock)
+            {
+                TrimModelCache();
+            }
+        }
+
+        // Evicts the least recently used models beyond the configured size (the active model counts as one)
+        private void TrimModelCache()
+        {
+            int standbyLimit = Math.Max(0, _configService.CurrentConfiguration.ModelCacheSize - 1);
+            while (_standbyEngines.Count > standbyLimit)
+            {
+                var evicted = _standbyEngines[^1];
+                _standbyEngines.RemoveAt(_standbyEngines.Count - 1);
+                SttLogger.Log($"[STT] Unloading cached Sherpa model: {evicted.Directory}");
+                evicted.Engine.Dispose();
+            }
+        }
+
+        // Sherpa's Whisper models auto-detect when no language is given
+        private string? GetSherpaLanguage()
+        {
+            string language = _configService.CurrentConfiguration.TranscriptionLanguage;
+            return string.IsNullOrEmpty(language) || language == "auto" ? null : language;
+        }
+
+        /// <summary>
+        /// True when settings baked into the recognizer at load time have changed since.
+        /// </summary>
+        private bool IsSherpaStale(SherpaOnnxEngine engine)
+        {
+            var config = _configService.CurrentConfiguration;
+            if (engine.NumThreads != GetThreadCount() ||
+                engine.DecodingStrategy != config.DecodingStrategy || engine.BeamSize != Math.Max(1, config.BeamSize))
+                return true;
+
+            // Hotwords are read when the recognizer is built, so an edited list needs a rebuild
+            if (SherpaOnnxEngine.SupportsHotwords(engine.ModelType) && engine.HotwordsVersion != _hotwords.Version)
+                return true;
+
+            if (engine.ModelType == SherpaModelType.SenseVoice)
+                return engine.Language != GetSherpaLanguage();
+
+            return engine.ModelType == SherpaModelType.Whisper &&
+                (engine.Language != GetSherpaLanguage() || engine.WhisperTask != _configService.CurrentConfiguration.WhisperTask);
+        }
+
+        private int GetThreadCount() => Math.Max(1, _configService.CurrentConfiguration.TranscriptionThreads);
+
+        private static bool HasSherpaModelFiles(string dir, SherpaModelType forcedType)
+        {
+            if (forcedType == SherpaModelType.Auto)
+                return SherpaOnnxEngine.DetectModelType(dir) != null;
+
+            return SherpaOnnxEngine.GetMissingFiles(dir, forcedType).Count == 0;
+        }
+
         public ISpeechEngine GetBestEngine()
         {
             var config = _configService.CurrentConfiguration;
@@ -151,9 +464,22 @@ namespace EliteWhisper.Services.Speech
                 $"AutoSelect: {config.AutoSelectSTT}, Preferred: {config.PreferredSTTEngine}, " +
                 $"SherpaAvailable: {_sherpaEngine?.IsAvailable == true}");
 
+            // Rebuild the resident recognizer if the language or task changed since it was loaded
+            if (_sherpaEngine != null && _sherpaModelDirectory != null && IsSherpaStale(_sherpaEngine))
+            {
+                // A transcription is about to run on it, which warms it up anyway
+                PreloadSherpaModel(_sherpaModelDirectory, warmUp: false);
+            }
+
             // 1. Check User Override
             if (!config.AutoSelectSTT && !string.IsNullOrEmpty(config.PreferredSTTEngine) && config.PreferredSTTEngine != "Auto")
             {
+                // Follow a Sherpa model switch if it wasn't preloaded already
+                if (config.PreferredSTTEngine == "Sherpa" && Directory.Exists(config.DefaultModelPath))
+                {
+                    PreloadSherpaModel(config.DefaultModelPath, warmUp: false);
+                }
+
                 if (config.PreferredSTTEngine == "Sherpa" && _sherpaEngine?.IsAvailable == true)
                 {
                     SttLogger.Log("[STT] Selected engine (Manual): Sherpa Parakeet");
@@ -178,9 +504,103 @@ namespace EliteWhisper.Services.Speech
             return _whisper;
         }
 
+        /// <summary>
+        /// Describes the engine the next dictation will use and what it supports. Unlike
+        /// <see cref="GetBestEngine"/> this never loads a model, so it is cheap to call from the UI.
+        /// </summary>
+        public EngineInfo GetEngineInfo()
+        {
+            var config = _configService.CurrentConfiguration;
+            var sherpa = _sherpaEngine;
+
+            // Same order as GetBestEngine: a manual Whisper choice wins, otherwise a loaded Sherpa model
+            bool whisperChosen = !config.AutoSelectSTT && config.PreferredSTTEngine == "Whisper" && _whisper.IsAvailable;
+            bool streaming = config.EnableStreaming && !string.IsNullOrEmpty(config.StreamingModelPath) &&
+                SherpaOnnxEngine.GetMissingFiles(config.StreamingModelPath, SherpaModelType.Transducer).Count == 0;
+
+            if (!whisperChosen && sherpa?.IsAvailable == true)
+            {
+                return new EngineInfo
+                {
+                    Name = sherpa.Name,
+                    ModelName = _sherpaModelDirectory != null ? Path.GetFileName(_sherpaModelDirectory) : null,
+                    Kind = InstalledModelKind.Sherpa,
+                    ModelType = sherpa.ModelType,
+                    // Timed transcripts always go through Whisper
+                    SupportsTimestamps = false,
+                    SupportsTranslate = sherpa.ModelType == SherpaModelType.Whisper,
+                    SupportsHotwords = SherpaOnnxEngine.SupportsHotwords(sherpa.ModelType),
+                    SupportsStreaming = streaming,
+                    SupportsBeamSearch = sherpa.ModelType == SherpaModelType.Transducer,
+                    SupportsPrompt = false
+                };
+            }
+
+            return new EngineInfo
+            {
+                Name = _whisper.Name,
+                ModelName = File.Exists(config.DefaultModelPath) ? Path.GetFileName(config.DefaultModelPath) : null,
+                Kind = InstalledModelKind.Whisper,
+                SupportsTimestamps = true,
+                SupportsTranslate = true,
+                SupportsHotwords = false,
+                SupportsStreaming = streaming,
+                SupportsBeamSearch = true,
+                SupportsPrompt = true
+            };
+        }
+
+        /// <summary>
+        /// The streaming engine for live transcription, or null when streaming is off or its model
+        /// can't be loaded. Loaded on first use and reloaded when the model folder changes.
+        /// </summary>
+        public SherpaStreamingEngine? GetStreamingEngine()
+        {
+            var config = _configService.CurrentConfiguration;
+            if (!config.EnableStreaming || string.IsNullOrEmpty(config.StreamingModelPath)) return null;
+
+            lock (_sherpaLock)
+            {
+                if (_streamingEngine == null || _streamingEngine.ModelDirectory != config.StreamingModelPath)
+                {
+                    _streamingEngine?.Dispose();
+                    _streamingEngine = new SherpaStreamingEngine(config.StreamingModelPath, GetThreadCount());
+                }
+
+                return _streamingEngine.IsAvailable ? _streamingEngine : null;
+            }
+        }
+
         public ISpeechEngine GetFallbackEngine()
         {
+            // A dedicated fallback model lets Whisper recover even when the default model
+            // points at a Sherpa directory.
+            string? fallbackModel = _configService.CurrentConfiguration.FallbackWhisperModelPath;
+            if (!string.IsNullOrEmpty(fallbackModel) && File.Exists(fallbackModel))
+            {
+                return new WhisperEngine(_whisperCore, fallbackModel);
+            }
+
             return _whisper;
         }
+
+        public void Dispose()
+        {
+            _warmUpCts?.Cancel();
+            lock (_sherpaLock)
+            {
+                _sherpaEngine?.Dispose();
+                _sherpaEngine = null;
+
+                foreach (var entry in _standbyEngines)
+                {
+                    entry.Engine.Dispose();
+                }
+                _standbyEngines.Clear();
+
+                _streamingEngine?.Dispose();
+                _streamingEngine = null;
+            }
+        }
     }
 }
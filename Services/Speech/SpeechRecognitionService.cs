@@ -1,6 +1,11 @@
 using System;
+using System.Collections.Generic;
+using System.Diagnostics;
+using System.IO;
+using System.Linq;
 using System.Threading;
 using System.Threading.Tasks;
+using EliteWhisper.Models;
 using NAudio.Wave;
 
 namespace EliteWhisper.Services.Speech
@@ -8,33 +13,353 @@ namespace EliteWhisper.Services.Speech
     public class SpeechRecognitionService
     {
         private readonly SpeechEngineSelector _engineSelector;
+        private readonly WhisperConfigurationService _configService;
+        private readonly ReplacementsService _replacements;
+        private readonly ProfanityFilterService _profanityFilter;
+        private readonly SpeakerDiarizationService _diarization;
+        private readonly HallucinationFilterService _hallucinationFilter;
 
-        public SpeechRecognitionService(SpeechEngineSelector engineSelector)
+        /// <summary>
+        /// Raised when the primary engine failed and the Whisper fallback produced the transcript.
+        /// The argument describes why the fallback was used.
+        /// </summary>
+        public event EventHandler<string>? FallbackUsed;
+
+        /// <summary>
+        /// Raised when a transcript scores below the configured minimum quality. The argument is the score.
+        /// </summary>
+        public event EventHandler<double>? LowQualityResult;
+
+        /// <summary>
+        /// Raised as the speech engine works through a file, tagged with the job id passed to TranscribeAsync.
+        /// </summary>
+        public event EventHandler<TranscriptionProgress>? ProgressChanged;
+
+        public SpeechRecognitionService(SpeechEngineSelector engineSelector, WhisperConfigurationService configService,
+            ReplacementsService replacements, ProfanityFilterService profanityFilter, SpeakerDiarizationService diarization,
+            HallucinationFilterService hallucinationFilter)
         {
+            _hallucinationFilter = hallucinationFilter;
+            _diarization = diarization;
             _engineSelector = engineSelector;
+            _configService = configService;
+            _replacements = replacements;
+            _profanityFilter = profanityFilter;
+        }
+
+        /// <param name="jobId">Tags the ProgressChanged events of this transcription.</param>
+        public async Task<string> TranscribeAsync(string audioFilePath, CancellationToken ct, Guid jobId = default)
+        {
+            var result = await TranscribeTimedAsync(audioFilePath, ct, jobId);
+            return result.Text;
         }
 
-        public async Task<string> TranscribeAsync(string audioFilePath, CancellationToken ct)
+        /// <summary>
+        /// Transcribes an audio file and reports how long each pipeline stage took.
+        /// </summary>
+        /// <param name="jobId">Tags the ProgressChanged events of this transcription.</param>
+        public async Task<TranscriptionResult> TranscribeTimedAsync(string audioFilePath, CancellationToken ct, Guid jobId = default)
         {
-            // First, load the audio file into float samples
-            // We use standard NAudio MediaFoundationReader to resample to 16kHz mono
-            float[] audioSamples = await LoadAudioSamplesAsync(audioFilePath, 16000, ct);
+            var timings = new TranscriptionTimings();
+            var total = Stopwatch.StartNew();
+            var stage = Stopwatch.StartNew();
+
+            // Decode stage: read the file at its native format
+            var (rawSamples, sourceFormat) = await DecodeAudioAsync(audioFilePath, ct);
+            timings.DecodeMs = stage.ElapsedMilliseconds;
+
+            // Preprocess stage: bring it to 16kHz mono for the engines
+            stage.Restart();
+            float[] audioSamples = await PreprocessAsync(rawSamples, sourceFormat, 16000, ct);
 
             // 5. Improve Silence Detection
             double sumSquares = 0;
             for (int i = 0; i < audioSamples.Length; i++)
                 sumSquares += audioSamples[i] * audioSamples[i];
             double audioRms = Math.Sqrt(sumSquares / Math.Max(1, audioSamples.Length));
+            string fingerprint = AudioFingerprint.Compute(audioSamples, 16000);
+            timings.PreprocessMs = stage.ElapsedMilliseconds;
 
             // 6. Add Debug Logging
             EliteWhisper.Services.Speech.SttLogger.Log($"[STT] Input audio length: {audioSamples.Length} samples, RMS: {audioRms:F4}");
 
+            // Optional VAD: strip silence, and don't run the model at all on a silent clip
+            var config = _configService.CurrentConfiguration;
+
+            // A near-empty clip (e.g. a mis-fired shortcut) only gives the model room to invent text
+            if (audioSamples.Length * 1000L < config.MinAudioDurationMs * 16000L)
+            {
+                EliteWhisper.Services.Speech.SttLogger.Log($"[STT] Audio is {audioSamples.Length / 16.0:F0}ms, below the {config.MinAudioDurationMs}ms minimum. Skipping the engine.");
+                timings.TotalMs = total.ElapsedMilliseconds;
+                return new TranscriptionResult
+                {
+                    Timings = timings,
+                    AudioSeconds = audioSamples.Length / 16000.0,
+                    AudioFingerprint = fingerprint,
+                    IsTooShort = true
+                };
+            }
+
+            float[] inferenceSamples = audioSamples;
+            if (config.EnableVoiceActivityDetection)
+            {
+                inferenceSamples = VoiceActivityDetector.Trim(audioSamples, 16000, config.VoiceActivityThresholdDb);
+                EliteWhisper.Services.Speech.SttLogger.Log($"[STT] VAD kept {inferenceSamples.Length}/{audioSamples.Length} samples");
+            }
+
+            // Inference stage (including fallback)
+            stage.Restart();
+            var progress = new Progress<int>(percent =>
+                ProgressChanged?.Invoke(this, new TranscriptionProgress { JobId = jobId, Percent = percent }));
+            var result = inferenceSamples.Length == 0
+                ? new TranscriptionResult()
+                : await RunInferenceAsync(inferenceSamples, audioRms, ct, progress);
+            timings.InferenceMs = stage.ElapsedMilliseconds;
+
+            // Postprocess stage
+            stage.Restart();
+            result.Text = FinalizeTranscript(result.Text);
+            timings.PostprocessMs = stage.ElapsedMilliseconds;
+
+            // Quality gate
+            result.QualityScore = TranscriptQualityEstimator.Estimate(result.Text, audioSamples.Length / 16000.0, audioRms);
+            result.IsLowQuality = result.Text.Length > 0 && config.MinTranscriptQuality > 0 && result.QualityScore < config.MinTranscriptQuality;
+            if (result.IsLowQuality)
+            {
+                EliteWhisper.Services.Speech.SttLogger.Log($"[STT] Low-quality transcript: score {result.QualityScore:F2} < {config.MinTranscriptQuality:F2}");
+                LowQualityResult?.Invoke(this, result.QualityScore);
+            }
+
+            timings.TotalMs = total.ElapsedMilliseconds;
+            EliteWhisper.Services.Speech.SttLogger.Log($"[STT] Latency breakdown: {timings}");
+
+            result.AudioSeconds = audioSamples.Length / 16000.0;
+            result.Timings = timings;
+            result.AudioFingerprint = fingerprint;
+            return result;
+        }
+
+        /// <summary>
+        /// Transcribes an audio file into timed segments with per-word timings, for subtitle
+        /// editing and click-to-seek playback. Timings always come from Whisper, since it is the
+        /// only engine that reports them; non-speech segments are dropped individually so the
+        /// remaining timestamps stay aligned.
+        /// </summary>
+        public async Task<List<TranscriptSegment>> TranscribeWithTimestampsAsync(string audioFilePath, CancellationToken ct)
+        {
+            var (rawSamples, sourceFormat) = await DecodeAudioAsync(audioFilePath, ct);
+            float[] audioSamples = await PreprocessAsync(rawSamples, sourceFormat, 16000, ct);
+            var result = await TranscribeSegmentsAsync(audioSamples, ct);
+            return result.Segments!;
+        }
+
+        /// <summary>
+        /// True when speaker diarization is enabled and its models are present.
+        /// </summary>
+        public bool IsDiarizationAvailable => _diarization.IsAvailable;
+
+        /// <summary>
+        /// Transcribes an audio file into timed segments labeled with speaker numbers.
+        /// The result's Text is the "Speaker N:" formatted transcript.
+        /// </summary>
+        public async Task<TranscriptionResult> TranscribeWithSpeakersAsync(string audioFilePath, CancellationToken ct)
+        {
+            var (rawSamples, sourceFormat) = await DecodeAudioAsync(audioFilePath, ct);
+            float[] audioSamples = await PreprocessAsync(rawSamples, sourceFormat, 16000, ct);
+
+            var result = await TranscribeSegmentsAsync(audioSamples, ct);
+            await _diarization.LabelSpeakersAsync(audioSamples, result.Segments!, ct);
+            result.Text = SpeakerDiarizationService.FormatTranscript(result.Segments!);
+            return result;
+        }
+
+        private async Task<TranscriptionResult> TranscribeSegmentsAsync(float[] audioSamples, CancellationToken ct)
+        {
+            var whisper = _engineSelector.GetBestEngine() as WhisperEngine;
+            if (whisper == null || !whisper.IsAvailable)
+                whisper = _engineSelector.GetFallbackEngine() as WhisperEngine;
+
+            if (whisper == null || !whisper.IsAvailable)
+                throw new TranscriptionException(TranscriptionError.NoModel, "Timestamps require a Whisper model. Please configure Whisper in settings.");
+
+            var segments = await whisper.TranscribeWithTimestampsAsync(audioSamples, ct);
+
+            var kept = new List<TranscriptSegment>(segments.Count);
+            foreach (var segment in segments)
+            {
+                // Whole-segment markers like "[BLANK_AUDIO]" filter down to nothing and are dropped
+                string text = _hallucinationFilter.Apply(segment.Text);
+                if (string.IsNullOrWhiteSpace(text)) continue;
+
+                segment.Text = PostProcessTranscript(text);
+                if (segment.Text.Length > 0) kept.Add(segment);
+            }
+
+            EliteWhisper.Services.Speech.SttLogger.Log($"[STT] Timestamped transcription: {kept.Count}/{segments.Count} segments kept");
+            return new TranscriptionResult
+            {
+                EngineName = whisper.Name,
+                AudioSeconds = audioSamples.Length / 16000.0,
+                Segments = kept,
+                NoSpeechProbability = AIEngineService.AggregateNoSpeechProbability(segments)
+            };
+        }
+
+        /// <summary>
+        /// Transcribes a clip and scores it against a known reference transcript (WER/CER).
+        /// Useful for comparing models on the user's own recordings.
+        /// </summary>
+        public async Task<EvaluationResult> EvaluateAsync(string audioFilePath, string referenceText, CancellationToken ct)
+        {
+            var result = await TranscribeTimedAsync(audioFilePath, ct);
+            var evaluation = TranscriptEvaluator.Evaluate(result.Text, referenceText);
+            evaluation.EngineName = result.EngineName;
+
+            EliteWhisper.Services.Speech.SttLogger.Log($"[STT] Evaluation ({result.EngineName}): WER={evaluation.Wer:P1}, CER={evaluation.Cer:P1}, " +
+                $"S={evaluation.Substitutions}, I={evaluation.Insertions}, D={evaluation.Deletions}");
+
+            return evaluation;
+        }
+
+        private const string SELF_TEST_PHRASE = "The quick brown fox jumps over the lazy dog.";
+
+        /// <summary>
+        /// Runs a spoken test clip through decode, preprocess, the selected engine and postprocess,
+        /// reporting pass/fail and timing per stage. The clip is generated offline with the Windows
+        /// speech synthesizer; no network access is needed.
+        /// </summary>
+        public async Task<SelfTestReport> RunSelfTestAsync(CancellationToken ct)
+        {
+            var report = new SelfTestReport();
+            string clipPath = Path.Combine(Path.GetTempPath(), $"elitewhisper_selftest_{DateTime.Now:yyyyMMdd_HHmmss}.wav");
+
+            try
+            {
+                if (!await RunStageAsync(report, "Generate test clip", async () =>
+                    {
+                        await Task.Run(() => SynthesizeSpeech(SELF_TEST_PHRASE, clipPath), ct);
+                        return $"{new FileInfo(clipPath).Length / 1024} KB";
+                    })) return report;
+
+                float[] rawSamples = Array.Empty<float>();
+                WaveFormat? sourceFormat = null;
+                if (!await RunStageAsync(report, "Decode", async () =>
+                    {
+                        (rawSamples, sourceFormat) = await DecodeAudioAsync(clipPath, ct);
+                        if (rawSamples.Length == 0) throw new InvalidOperationException("Decoded clip is empty.");
+                        return $"{sourceFormat.SampleRate}Hz, {sourceFormat.Channels}ch";
+                    })) return report;
+
+                float[] samples = Array.Empty<float>();
+                if (!await RunStageAsync(report, "Preprocess", async () =>
+                    {
+                        samples = await PreprocessAsync(rawSamples, sourceFormat!, 16000, ct);
+                        if (samples.Length == 0) throw new InvalidOperationException("Resampled clip is empty.");
+                        return $"{samples.Length / 16000.0:F1}s at 16kHz";
+                    })) return report;
+
+                string transcript = string.Empty;
+                if (!await RunStageAsync(report, "Engine", async () =>
+                    {
+                        var engine = _engineSelector.GetBestEngine();
+                        report.EngineName = engine.Name;
+                        if (!engine.IsAvailable) throw new InvalidOperationException($"{engine.Name} is not available. Check the model and executable paths.");
+
+                        transcript = await engine.TranscribeAsync(samples, ct);
+                        return null;
+                    })) return report;
+
+                await RunStageAsync(report, "Postprocess", () =>
+                {
+                    transcript = PostProcessTranscript(transcript);
+                    report.Transcript = transcript;
+
+                    // Plausible = non-empty and recognizes at least a couple of the test words
+                    var expected = new[] { "quick", "brown", "fox", "jumps", "lazy", "dog" };
+                    int hits = expected.Count(w => transcript.Contains(w, StringComparison.OrdinalIgnoreCase));
+                    if (hits < 2) throw new InvalidOperationException($"Transcript doesn't match the test phrase ({hits}/{expected.Length} words).");
+                    return Task.FromResult<string?>($"{hits}/{expected.Length} test words recognized");
+                });
+            }
+            finally
+            {
+                try { if (File.Exists(clipPath)) File.Delete(clipPath); } catch { }
+                EliteWhisper.Services.Speech.SttLogger.Log($"[STT] Self-test: {(report.Passed ? "passed" : "failed")}\n{report}");
+            }
+
+            return report;
+        }
+
+        private static async Task<bool> RunStageAsync(SelfTestReport report, string name, Func<Task<string?>> stage)
+        {
+            var watch = Stopwatch.StartNew();
+            var result = new SelfTestStage { Name = name };
+            try
+            {
+                result.Message = await stage();
+                result.Passed = true;
+            }
+            catch (Exception ex)
+            {
+                result.Message = ex.Message;
+                result.Passed = false;
+            }
+            result.DurationMs = watch.ElapsedMilliseconds;
+            report.Stages.Add(result);
+            return result.Passed;
+        }
+
+        /// <summary>
+        /// Speaks text into a 16kHz mono WAV file using the built-in Windows SAPI voice.
+        /// </summary>
+        private static void SynthesizeSpeech(string text, string wavPath)
+        {
+            const int SSFMCreateForWrite = 3;
+            const int SAFT16kHz16BitMono = 18;
+
+            var voiceType = Type.GetTypeFromProgID("SAPI.SpVoice")
+                ?? throw new InvalidOperationException("Windows speech synthesis (SAPI) is not available.");
+            var streamType = Type.GetTypeFromProgID("SAPI.SpFileStream")
+                ?? throw new InvalidOperationException("Windows speech synthesis (SAPI) is not available.");
+
+            dynamic voice = Activator.CreateInstance(voiceType)!;
+            dynamic stream = Activator.CreateInstance(streamType)!;
+            try
+            {
+                stream.Format.Type = SAFT16kHz16BitMono;
+                stream.Open(wavPath, SSFMCreateForWrite, false);
+                voice.AudioOutputStream = stream;
+                voice.Speak(text, 0);
+            }
+            finally
+            {
+                stream.Close();
+                System.Runtime.InteropServices.Marshal.FinalReleaseComObject(stream);
+                System.Runtime.InteropServices.Marshal.FinalReleaseComObject(voice);
+            }
+        }
+
+        /// <summary>
+        /// Runs one engine and returns its raw text with the engine name and, for Whisper, its no-speech probability.
+        /// </summary>
+        private static async Task<TranscriptionResult> TranscribeWithEngineAsync(ISpeechEngine engine, float[] audioSamples, CancellationToken ct, IProgress<int>? progress)
+        {
+            var result = engine is WhisperEngine whisper
+                ? await whisper.TranscribeDetailedAsync(audioSamples, ct, progress)
+                : new TranscriptionResult { Text = await engine.TranscribeAsync(audioSamples, ct, progress) };
+            result.EngineName = engine.Name;
+            return result;
+        }
+
+        private async Task<TranscriptionResult> RunInferenceAsync(float[] audioSamples, double audioRms, CancellationToken ct, IProgress<int>? progress = null)
+        {
             var engine = _engineSelector.GetBestEngine();
 
             try
             {
                 var watch = System.Diagnostics.Stopwatch.StartNew();
-                string transcript = await engine.TranscribeAsync(audioSamples, ct);
+                var result = await TranscribeWithEngineAsync(engine, audioSamples, ct, progress);
+                string transcript = result.Text;
                 watch.Stop();
 
                 EliteWhisper.Services.Speech.SttLogger.Log($"[STT] ONNX inference duration: {watch.ElapsedMilliseconds}ms for engine {engine.Name}");
@@ -47,76 +372,249 @@ namespace EliteWhisper.Services.Speech
                     if (audioRms < 0.01)
                     {
                         EliteWhisper.Services.Speech.SttLogger.Log("[STT] audioRMS < 0.01 and empty transcript, confirming 'No speech detected'.");
-                        return "";
+                        result.Text = string.Empty;
+                        return result;
                     }
 
+                    ct.ThrowIfCancellationRequested();
                     var fallback = _engineSelector.GetFallbackEngine();
-                    if (fallback != engine && fallback.IsAvailable)
+                    if (CanFallBack(engine, fallback))
                     {
                         EliteWhisper.Services.Speech.SttLogger.Log($"[STT] Engine returned empty but audio is loud. Falling back to {fallback.Name}");
-                        transcript = await fallback.TranscribeAsync(audioSamples, ct);
+                        result = await TranscribeWithEngineAsync(fallback, audioSamples, ct, progress);
+                        FallbackUsed?.Invoke(this, $"{engine.Name} returned no text");
                     }
                 }
 
-                return transcript;
+                return result;
             }
-            catch (Exception ex)
+            catch (Exception ex) when (ex is not OperationCanceledException)
             {
                 EliteWhisper.Services.Speech.SttLogger.Log($"[STT] Primary engine {engine.Name} failed: {ex.Message}");
+
+                // Don't let one bad decode break every later dictation; reloading takes a few seconds
+                if (ex is not TranscriptionException) _ = Task.Run(() => _engineSelector.ResetEngine(engine));
                 
                 // Fallback Safety Process
                 var fallback = _engineSelector.GetFallbackEngine();
-                if (fallback != engine && fallback.IsAvailable)
+                if (CanFallBack(engine, fallback))
                 {
                     EliteWhisper.Services.Speech.SttLogger.Log($"[STT] Error occurred. Falling back to {fallback.Name}");
-                    return await fallback.TranscribeAsync(audioSamples, ct);
+                    var result = await TranscribeWithEngineAsync(fallback, audioSamples, ct, progress);
+                    FallbackUsed?.Invoke(this, $"{engine.Name} failed: {ex.Message}");
+                    return result;
                 }
-                
-                throw;
+
+                if (ex is TranscriptionException) throw;
+                throw new TranscriptionException(TranscriptionError.EngineFailed, $"{engine.Name} failed: {ex.Message}", ex);
+            }
+        }
+
+        private bool CanFallBack(ISpeechEngine primary, ISpeechEngine fallback)
+        {
+            if (!_configService.CurrentConfiguration.EnableWhisperFallback)
+            {
+                EliteWhisper.Services.Speech.SttLogger.Log("[STT] Whisper fallback disabled in settings.");
+                return false;
+            }
+
+            return fallback != primary && fallback.IsAvailable;
+        }
+
+        /// <summary>
+        /// Hallucination filtering plus the same clean-up as <see cref="TranscribeAsync"/>, for text
+        /// that was decoded outside this pipeline (e.g. the live streaming engine).
+        /// </summary>
+        public string FinalizeTranscript(string transcript)
+        {
+            transcript = _hallucinationFilter.Apply(transcript);
+            return PostProcessTranscript(transcript);
+        }
+
+        /// <summary>
+        /// Clean-up applied to raw engine output before it leaves the speech pipeline.
+        /// </summary>
+        private string PostProcessTranscript(string transcript)
+        {
+            transcript = transcript.Trim();
+
+            var config = _configService.CurrentConfiguration;
+
+            // Prefer what the user told us over a guess from the script
+            string? spokenLanguage = config.TranscriptionLanguage is { Length: > 0 } lang && lang != "auto" ? lang : null;
+            string? configuredLanguage = config.FormattingLanguage ?? spokenLanguage;
+
+            // Number words are only rewritten for a language the user chose, never a guess
+            if (config.EnableNumberFormatting && configuredLanguage != null && transcript.Length > 0)
+            {
+                transcript = SpokenNumberFormatter.Apply(transcript, configuredLanguage);
+            }
+
+            if (config.EnableLanguageFormatting && transcript.Length > 0)
+            {
+                string language = configuredLanguage ?? LanguageFormattingRules.DetectFromScript(transcript);
+                transcript = LanguageFormattingRules.Apply(transcript, language, config.AddFinalPunctuation);
+            }
+
+            // User rules run last so formatting can't undo them (e.g. a multi-line signature)
+            transcript = _replacements.Apply(transcript);
+
+            // Masking goes after replacements so a rule can't bring a masked word back
+            if (config.EnableProfanityFilter)
+            {
+                transcript = _profanityFilter.Apply(transcript, configuredLanguage);
             }
+
+            // Casing goes last, on top of the punctuation and capitals formatting produced
+            transcript = TextCasing.Apply(transcript, config.OutputCasing);
+
+            return transcript;
         }
 
-        private async Task<float[]> LoadAudioSamplesAsync(string filePath, int targetSampleRate, CancellationToken ct)
+        private async Task<(float[] Samples, WaveFormat Format)> DecodeAudioAsync(string filePath, CancellationToken ct)
+        {
+            if (!File.Exists(filePath))
+                throw new TranscriptionException(TranscriptionError.AudioNotFound, $"Audio file not found: {filePath}");
+
+            try
+            {
+                return await DecodeSamplesAsync(filePath, ct);
+            }
+            catch (Exception ex) when (ex is InvalidDataException or NotSupportedException)
+            {
+                throw new TranscriptionException(TranscriptionError.DecodeFailed, ex.Message, ex);
+            }
+        }
+
+        private async Task<(float[] Samples, WaveFormat Format)> DecodeSamplesAsync(string filePath, CancellationToken ct)
         {
             return await Task.Run(() =>
             {
                 ct.ThrowIfCancellationRequested();
 
-                using var reader = new MediaFoundationReader(filePath);
-                ISampleProvider provider = reader.ToSampleProvider();
+                var container = AudioContainerDetector.Detect(filePath);
+                EliteWhisper.Services.Speech.SttLogger.Log($"[STT] Decoding {container} input: {Path.GetFileName(filePath)}");
 
-                // 1. Audio Format Requirements (16kHz, mono)
-                if (provider.WaveFormat.SampleRate != targetSampleRate)
+                // PCM and float WAV are normalized by their exact bit depth; anything else (ADPCM, A-law...) goes to the codecs
+                if (container == AudioContainer.Wav)
                 {
-                    provider = new NAudio.Wave.SampleProviders.WdlResamplingSampleProvider(provider, targetSampleRate);
+                    try
+                    {
+                        return WavSampleReader.Read(filePath, ct);
+                    }
+                    catch (InvalidDataException ex)
+                    {
+                        EliteWhisper.Services.Speech.SttLogger.Log($"[STT] {ex.Message} Trying the system codecs.");
+                    }
                 }
 
-                if (provider.WaveFormat.Channels > 1)
-                {
-                    provider = new NAudio.Wave.SampleProviders.MultiplexingSampleProvider(new[] { provider }, 1);
-                }
-                
-                // Read all samples
-                long projectedLength = (long)(reader.TotalTime.TotalSeconds * targetSampleRate);
-                var sampleList = new System.Collections.Generic.List<float>((int)projectedLength);
-                
+                using var reader = AudioContainerDetector.OpenReader(filePath, container);
+                ISampleProvider provider = reader.ToSampleProvider();
+
+                long projectedLength = (long)(reader.TotalTime.TotalSeconds * provider.WaveFormat.SampleRate * provider.WaveFormat.Channels);
+                var sampleList = new List<float>((int)Math.Min(projectedLength, int.MaxValue));
+
                 float[] buffer = new float[16000];
                 int read;
                 while ((read = provider.Read(buffer, 0, buffer.Length)) > 0)
                 {
                     ct.ThrowIfCancellationRequested();
-                    for(int i = 0; i < read; i++)
-                    {
-                        // ensure [-1, 1] bounds just in case
-                        float s = buffer[i];
-                        if (s > 1f) s = 1f;
-                        if (s < -1f) s = -1f;
-                        sampleList.Add(s);
-                    }
+                    for (int i = 0; i < read; i++)
+                        sampleList.Add(buffer[i]);
+                }
+
+                return (sampleList.ToArray(), provider.WaveFormat);
+            }, ct);
+        }
+
+        private async Task<float[]> PreprocessAsync(float[] samples, WaveFormat sourceFormat, int targetSampleRate, CancellationToken ct)
+        {
+            return await Task.Run(() =>
+            {
+                ct.ThrowIfCancellationRequested();
+
+                // 1. Audio Format Requirements (16kHz, mono)
+                // Reduce to mono first so the resampler only has to process one channel.
+                // Averaging all channels keeps speech that was only recorded on some of them.
+                float[] mono = AudioDownmixer.ToMono(samples, Math.Max(1, sourceFormat.Channels));
+                float[] result = ResampleMono(mono, sourceFormat.SampleRate, targetSampleRate, ct);
+
+                // Clamping lets NaN through; zero it so normalization never scales garbage
+                int invalid = AudioNormalizer.Sanitize(result);
+                if (invalid > 0)
+                    EliteWhisper.Services.Speech.SttLogger.Log($"[STT] Replaced {invalid} NaN/infinite samples with silence");
+
+                var config = _configService.CurrentConfiguration;
+                if (config.EnableNormalization)
+                {
+                    double gainDb = AudioNormalizer.Normalize(result, config.NormalizationTargetDb);
+                    EliteWhisper.Services.Speech.SttLogger.Log($"[STT] Normalization gain: {gainDb:F1} dB (target {config.NormalizationTargetDb:F1} dBFS)");
                 }
 
-                return sampleList.ToArray();
+                return result;
             }, ct);
         }
+
+        /// <summary>
+        /// Converts mono samples to the target rate, clamped to [-1, 1]. Input already at the target rate is only clamped.
+        /// </summary>
+        internal static float[] ResampleMono(float[] mono, int sourceSampleRate, int targetSampleRate, CancellationToken ct = default)
+        {
+            ISampleProvider provider = new BufferedSampleProvider(mono, WaveFormat.CreateIeeeFloatWaveFormat(sourceSampleRate, 1));
+
+            // WDL is a band-limited (windowed sinc) resampler, so 44.1/48kHz input doesn't alias on the way down
+            if (provider.WaveFormat.SampleRate != targetSampleRate)
+            {
+                provider = new NAudio.Wave.SampleProviders.WdlResamplingSampleProvider(provider, targetSampleRate);
+            }
+            
+            // Read all samples
+            long projectedLength = (long)mono.Length * targetSampleRate / Math.Max(1, sourceSampleRate);
+            var sampleList = new List<float>((int)projectedLength);
+            
+            float[] buffer = new float[16000];
+            int read;
+            while ((read = provider.Read(buffer, 0, buffer.Length)) > 0)
+            {
+                ct.ThrowIfCancellationRequested();
+                for(int i = 0; i < read; i++)
+                {
+                    // ensure [-1, 1] bounds just in case
+                    float s = buffer[i];
+                    if (s > 1f) s = 1f;
+                    if (s < -1f) s = -1f;
+                    sampleList.Add(s);
+                }
+            }
+
+            return sampleList.ToArray();
+        }
+
+        /// <summary>
+        /// Exposes already-decoded samples as an ISampleProvider so they can run through NAudio's resamplers.
+        /// </summary>
+        private class BufferedSampleProvider : ISampleProvider
+        {
+            private readonly float[] _samples;
+            private int _position;
+
+            public BufferedSampleProvider(float[] samples, WaveFormat format)
+            {
+                _samples = samples;
+                WaveFormat = WaveFormat.CreateIeeeFloatWaveFormat(format.SampleRate, format.Channels);
+            }
+
+            public WaveFormat WaveFormat { get; }
+
+            public int Read(float[] buffer, int offset, int count)
+            {
+                int toCopy = Math.Min(count, _samples.Length - _position);
+                if (toCopy <= 0) return 0;
+
+                Array.Copy(_samples, _position, buffer, offset, toCopy);
+                _position += toCopy;
+                return toCopy;
+            }
+        }
     }
 }
@@ -0,0 +1,376 @@
+using System;
+using System.Collections.Generic;
+using System.Globalization;
+using System.Linq;
+using System.Text;
+using System.Text.RegularExpressions;
+
+namespace EliteWhisper.Services.Speech
+{
+    /// <summary>
+    /// Inverse text normalization: rewrites spoken numbers as digits.
+    /// "one hundred and five" -> "105", "twenty percent" -> "20%", "twenty five dollars" -> "$25",
+    /// "march third" / "the third of march" -> "March 3rd". Single words below ten ("one of them")
+    /// stay as words unless a unit follows. English only for now.
+    /// </summary>
+    public static class SpokenNumberFormatter
+    {
+        /// <summary>
+        /// Languages with number rules. The formatter leaves text in any other language untouched.
+        /// </summary>
+        public static readonly IReadOnlyList<string> SupportedLanguages = new[] { "en" };
+
+        private static readonly Regex WordPattern = new(@"[A-Za-z]+", RegexOptions.Compiled);
+
+        private static readonly Dictionary<string, int> Units = new()
+        {
+            ["zero"] = 0, ["one"] = 1, ["two"] = 2, ["three"] = 3, ["four"] = 4, ["five"] = 5, ["six"] = 6,
+            ["seven"] = 7, ["eight"] = 8, ["nine"] = 9, ["ten"] = 10, ["eleven"] = 11, ["twelve"] = 12,
+            ["thirteen"] = 13, ["fourteen"] = 14, ["fifteen"] = 15, ["sixteen"] = 16, ["seventeen"] = 17,
+            ["eighteen"] = 18, ["nineteen"] = 19
+        };
+
+        private static readonly Dictionary<string, int> Tens = new()
+        {
+            ["twenty"] = 20, ["thirty"] = 30, ["forty"] = 40, ["fifty"] = 50,
+            ["sixty"] = 60, ["seventy"] = 70, ["eighty"] = 80, ["ninety"] = 90
+        };
+
+        private static readonly Dictionary<string, long> Scales = new()
+        {
+            ["thousand"] = 1_000, ["million"] = 1_000_000, ["billion"] = 1_000_000_000
+        };
+
+        private static readonly Dictionary<string, int> Ordinals = new()
+        {
+            ["first"] = 1, ["second"] = 2, ["third"] = 3, ["fourth"] = 4, ["fifth"] = 5, ["sixth"] = 6,
+            ["seventh"] = 7, ["eighth"] = 8, ["ninth"] = 9, ["tenth"] = 10, ["eleventh"] = 11, ["twelfth"] = 12,
+            ["thirteenth"] = 13, ["fourteenth"] = 14, ["fifteenth"] = 15, ["sixteenth"] = 16, ["seventeenth"] = 17,
+            ["eighteenth"] = 18, ["nineteenth"] = 19, ["twentieth"] = 20, ["thirtieth"] = 30
+        };
+
+        private static readonly string[] Months =
+        {
+            "january", "february", "march", "april", "may", "june",
+            "july", "august", "september", "october", "november", "december"
+        };
+
+        private enum NumberPart { None, Unit, Teen, Ten, Hundred, Scale }
+
+        public static bool IsSupported(string language) =>
+            SupportedLanguages.Contains(language, StringComparer.OrdinalIgnoreCase);
+
+        public static string Apply(string text, string language)
+        {
+            if (string.IsNullOrWhiteSpace(text) || !IsSupported(language)) return text;
+
+            var words = WordPattern.Matches(text).ToList();
+            var sb = new StringBuilder(text.Length);
+            int copied = 0;
+            int i = 0;
+
+            while (i < words.Count)
+            {
+                if (TryFormatDate(text, words, i, out int consumed, out string replacement) ||
+                    TryFormatNumber(text, words, i, out consumed, out replacement))
+                {
+                    int start = words[i].Index;
+                    var last = words[i + consumed - 1];
+                    sb.Append(text, copied, start - copied);
+                    sb.Append(replacement);
+                    copied = last.Index + last.Length;
+                    i += consumed;
+                }
+                else
+                {
+                    i++;
+                }
+            }
+
+            sb.Append(text, copied, text.Length - copied);
+            return sb.ToString();
+        }
+
+        private static bool TryFormatNumber(string text, List<Match> words, int start, out int consumed, out string replacement)
+        {
+            consumed = 0;
+            replacement = string.Empty;
+
+            if (!TryParseCardinal(text, words, start, out long value, out int used)) return false;
+
+            int next = start + used;
+            string number = value.ToString(CultureInfo.InvariantCulture);
+            bool isDecimal = false;
+
+            // "three point one four" -> 3.14
+            if (Word(words, next) == "point" && IsJoined(text, words, next - 1))
+            {
+                var digits = new StringBuilder();
+                int j = next + 1;
+                while (j < words.Count && IsJoined(text, words, j - 1) &&
+                       Units.TryGetValue(Word(words, j)!, out int digit) && digit < 10)
+                {
+                    digits.Append(digit);
+                    j++;
+                }
+
+                if (digits.Length > 0)
+                {
+                    number += "." + digits;
+                    isDecimal = true;
+                    next = j;
+                }
+            }
+
+            string? unit = Word(words, next);
+            bool unitJoined = unit != null && IsJoined(text, words, next - 1);
+
+            if (unitJoined && unit == "percent")
+            {
+                replacement = number + "%";
+                consumed = next + 1 - start;
+                return true;
+            }
+
+            if (unitJoined && unit == "per" && Word(words, next + 1) == "cent" && IsJoined(text, words, next))
+            {
+                replacement = number + "%";
+                consumed = next + 2 - start;
+                return true;
+            }
+
+            if (unitJoined && unit is "dollar" or "dollars" or "bucks")
+            {
+                replacement = "$" + number;
+                consumed = next + 1 - start;
+
+                // "five dollars and twenty cents" -> $5.20
+                int andIndex = next + 1;
+                if (!isDecimal && Word(words, andIndex) == "and" && IsJoined(text, words, andIndex - 1) && IsJoined(text, words, andIndex) &&
+                    TryParseCardinal(text, words, andIndex + 1, out long cents, out int centsUsed) && cents < 100)
+                {
+                    int centsUnit = andIndex + 1 + centsUsed;
+                    if (Word(words, centsUnit) is "cent" or "cents" && IsJoined(text, words, centsUnit - 1))
+                    {
+                        replacement += "." + cents.ToString("00", CultureInfo.InvariantCulture);
+                        consumed = centsUnit + 1 - start;
+                    }
+                }
+
+                return true;
+            }
+
+            if (unitJoined && unit is "euro" or "euros")
+            {
+                replacement = "€" + number;
+                consumed = next + 1 - start;
+                return true;
+            }
+
+            // Leave "one of them" and "zero" alone; longer or larger numbers read better as digits
+            if (!isDecimal && used == 1 && value < 10) return false;
+
+            replacement = number;
+            consumed = next - start;
+            return true;
+        }
+
+        private static bool TryFormatDate(string text, List<Match> words, int start, out int consumed, out string replacement)
+        {
+            consumed = 0;
+            replacement = string.Empty;
+
+            // "March third", "March twenty first"
+            if (TryGetMonth(words, start, out string month) && IsJoined(text, words, start) &&
+                TryParseOrdinal(text, words, start + 1, out int day, out int used))
+            {
+                replacement = $"{month} {FormatOrdinal(day)}";
+                consumed = 1 + used;
+                return true;
+            }
+
+            // "the third of March"
+            if (Word(words, start) == "the" && IsJoined(text, words, start) &&
+                TryParseOrdinal(text, words, start + 1, out day, out used))
+            {
+                int ofIndex = start + 1 + used;
+                if (Word(words, ofIndex) == "of" && IsJoined(text, words, ofIndex - 1) && IsJoined(text, words, ofIndex) &&
+                    TryGetMonth(words, ofIndex + 1, out month))
+                {
+                    replacement = $"{month} {FormatOrdinal(day)}";
+                    consumed = ofIndex + 2 - start;
+                    return true;
+                }
+            }
+
+            return false;
+        }
+
+        private static bool TryGetMonth(List<Match> words, int index, out string month)
+        {
+            month = string.Empty;
+            string? word = Word(words, index);
+            if (word == null || !Months.Contains(word)) return false;
+
+            // "you may first need to..." is not a date; only trust the month when it was capitalized
+            if (word == "may" && !char.IsUpper(words[index].Value[0])) return false;
+
+            month = char.ToUpperInvariant(word[0]) + word[1..];
+            return true;
+        }
+
+        private static bool TryParseOrdinal(string text, List<Match> words, int start, out int value, out int consumed)
+        {
+            value = 0;
+            consumed = 0;
+            string? word = Word(words, start);
+            if (word == null) return false;
+
+            if (Ordinals.TryGetValue(word, out value))
+            {
+                consumed = 1;
+                return true;
+            }
+
+            // "twenty first", "thirty-first"
+            if (word is "twenty" or "thirty" && IsJoined(text, words, start) &&
+                Ordinals.TryGetValue(Word(words, start + 1) ?? string.Empty, out int unit) && unit < 10)
+            {
+                value = Tens[word] + unit;
+                consumed = 2;
+                return value <= 31;
+            }
+
+            return false;
+        }
+
+        /// <summary>
+        /// Reads a run of number words ("two thousand and five", "twenty-five hundred").
+        /// Stops at the first word that can't continue the number, so "five five" reads only the first "five".
+        /// </summary>
+        private static bool TryParseCardinal(string text, List<Match> words, int start, out long value, out int consumed)
+        {
+            value = 0;
+            consumed = 0;
+
+            long total = 0, current = 0;
+            long lastScale = long.MaxValue;
+            var last = NumberPart.None;
+            int end = start;
+            int i = start;
+
+            while (i < words.Count)
+            {
+                if (i > start && !IsJoined(text, words, i - 1)) break;
+                string word = Word(words, i)!;
+                string? nextWord = i + 1 < words.Count && IsJoined(text, words, i) ? Word(words, i + 1) : null;
+
+                if (word == "a" && last == NumberPart.None && nextWord != null && (nextWord == "hundred" || Scales.ContainsKey(nextWord)))
+                {
+                    // "a hundred", "a thousand"
+                    current = 1;
+                    last = NumberPart.Unit;
+                    i++;
+                    continue;
+                }
+
+                if (word == "and" && (last == NumberPart.Hundred || last == NumberPart.Scale) && nextWord != null &&
+                    ((Units.TryGetValue(nextWord, out int small) && small > 0) || Tens.ContainsKey(nextWord)))
+                {
+                    i++;
+                    continue;
+                }
+
+                if (Units.TryGetValue(word, out int unit))
+                {
+                    bool isTeen = unit >= 10;
+                    bool allowed = unit == 0
+                        ? last == NumberPart.None
+                        : last is NumberPart.None or NumberPart.Hundred or NumberPart.Scale || (!isTeen && last == NumberPart.Ten);
+                    if (!allowed) break;
+
+                    current += unit;
+                    last = isTeen ? NumberPart.Teen : NumberPart.Unit;
+                    i++;
+                    end = i;
+
+                    // "zero" never combines with anything
+                    if (unit == 0) break;
+                }
+                else if (Tens.TryGetValue(word, out int ten))
+                {
+                    if (last is not (NumberPart.None or NumberPart.Hundred or NumberPart.Scale)) break;
+
+                    current += ten;
+                    last = NumberPart.Ten;
+                    i++;
+                    end = i;
+                }
+                else if (word == "hundred")
+                {
+                    if (last is not (NumberPart.Unit or NumberPart.Teen or NumberPart.Ten) || current >= 100) break;
+
+                    current *= 100;
+                    last = NumberPart.Hundred;
+                    i++;
+                    end = i;
+                }
+                else if (Scales.TryGetValue(word, out long scale))
+                {
+                    if (last is NumberPart.None or NumberPart.Scale || current == 0 || scale >= lastScale) break;
+
+                    total += current * scale;
+                    current = 0;
+                    lastScale = scale;
+                    last = NumberPart.Scale;
+                    i++;
+                    end = i;
+                }
+                else
+                {
+                    break;
+                }
+            }
+
+            if (end == start) return false;
+
+            value = total + current;
+            consumed = end - start;
+            return true;
+        }
+
+        private static string FormatOrdinal(int n)
+        {
+            string suffix = ((n % 100) is 11 or 12 or 13) ? "th" : (n % 10) switch
+            {
+                1 => "st",
+                2 => "nd",
+                3 => "rd",
+                _ => "th"
+            };
+            return n.ToString(CultureInfo.InvariantCulture) + suffix;
+        }
+
+        private static string? Word(List<Match> words, int index) =>
+            index >= 0 && index < words.Count ? words[index].Value.ToLowerInvariant() : null;
+
+        // Two words belong to the same phrase when only spaces or a single hyphen separate them
+        private static bool IsJoined(string text, List<Match> words, int index)
+        {
+            if (index < 0 || index + 1 >= words.Count) return false;
+
+            int gapStart = words[index].Index + words[index].Length;
+            int gapLength = words[index + 1].Index - gapStart;
+            if (gapLength <= 0) return false;
+
+            var gap = text.AsSpan(gapStart, gapLength);
+            if (gap.Length == 1 && gap[0] == '-') return true;
+
+            foreach (char c in gap)
+            {
+                if (!char.IsWhiteSpace(c)) return false;
+            }
+            return true;
+        }
+    }
+}
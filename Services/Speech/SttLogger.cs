@@ -5,7 +5,9 @@ namespace EliteWhisper.Services.Speech
 {
     public static class SttLogger
     {
-        private static readonly string LogFile = @"d:\Personal\voiceapp\stt_debug.log";
+        private static readonly string LogFile = Path.Combine(
+            Environment.GetFolderPath(Environment.SpecialFolder.LocalApplicationData),
+            "EliteWhisper", "logs", "stt_debug.log");
         private static readonly object _lock = new object();
 
         public static void Log(string message)
@@ -14,6 +16,7 @@ namespace EliteWhisper.Services.Speech
             {
                 lock (_lock)
                 {
+                    Directory.CreateDirectory(Path.GetDirectoryName(LogFile)!);
                     File.AppendAllText(LogFile, $"[{DateTime.Now:HH:mm:ss.fff}] {message}{Environment.NewLine}");
                 }
             }
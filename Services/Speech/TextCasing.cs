@@ -0,0 +1,124 @@
+using System;
+using System.Collections.Generic;
+using System.Text;
+using System.Text.RegularExpressions;
+using EliteWhisper.Models;
+
+namespace EliteWhisper.Services.Speech
+{
+    /// <summary>
+    /// Output casing transforms. Sentence and title case keep acronyms ("NASA") and words with inner
+    /// capitals ("iPhone", "McDonald") as written, since the model already got those right.
+    /// </summary>
+    public static class TextCasing
+    {
+        // Lowercase in title case unless first or last, or after a colon or sentence end
+        private static readonly HashSet<string> TitleSmallWords = new(StringComparer.OrdinalIgnoreCase)
+        {
+            "a", "an", "the", "and", "but", "or", "nor", "for", "so", "yet",
+            "as", "at", "by", "in", "of", "off", "on", "per", "to", "up", "via", "vs"
+        };
+
+        // A word: letters plus inner apostrophes and hyphens ("don't", "well-known")
+        private static readonly Regex WordPattern = new(@"\p{L}[\p{L}\p{Mn}'’\-]*", RegexOptions.Compiled);
+
+        public static string Apply(string text, OutputCasing casing)
+        {
+            if (string.IsNullOrEmpty(text)) return text;
+
+            return casing switch
+            {
+                OutputCasing.Lower => text.ToLowerInvariant(),
+                OutputCasing.Upper => text.ToUpperInvariant(),
+                OutputCasing.Sentence => ToSentenceCase(text),
+                OutputCasing.Title => ToTitleCase(text),
+                _ => text
+            };
+        }
+
+        public static string ToSentenceCase(string text)
+        {
+            var sb = new StringBuilder(text.Length);
+            int last = 0;
+            bool sentenceStart = true;
+
+            foreach (Match match in WordPattern.Matches(text))
+            {
+                string between = text.Substring(last, match.Index - last);
+                sb.Append(between);
+                if (EndsSentence(between)) sentenceStart = true;
+
+                string word = match.Value;
+                if (IsKeptAsWritten(word))
+                    sb.Append(word);
+                else if (sentenceStart || word.Equals("i", StringComparison.OrdinalIgnoreCase) || word.StartsWith("i'", StringComparison.OrdinalIgnoreCase))
+                    sb.Append(Capitalize(word.ToLowerInvariant()));
+                else
+                    sb.Append(word.ToLowerInvariant());
+
+                sentenceStart = false;
+                last = match.Index + match.Length;
+            }
+
+            sb.Append(text, last, text.Length - last);
+            return sb.ToString();
+        }
+
+        public static string ToTitleCase(string text)
+        {
+            var matches = WordPattern.Matches(text);
+            var sb = new StringBuilder(text.Length);
+            int last = 0;
+            bool forceCapital = true;
+
+            for (int i = 0; i < matches.Count; i++)
+            {
+                var match = matches[i];
+                string between = text.Substring(last, match.Index - last);
+                sb.Append(between);
+                if (EndsSentence(between) || between.Contains(':')) forceCapital = true;
+
+                string word = match.Value;
+                bool isLast = i == matches.Count - 1;
+                if (IsKeptAsWritten(word))
+                    sb.Append(word);
+                else if (!forceCapital && !isLast && TitleSmallWords.Contains(word))
+                    sb.Append(word.ToLowerInvariant());
+                else
+                    sb.Append(CapitalizeParts(word.ToLowerInvariant()));
+
+                forceCapital = false;
+                last = match.Index + match.Length;
+            }
+
+            sb.Append(text, last, text.Length - last);
+            return sb.ToString();
+        }
+
+        /// <summary>
+        /// Acronyms and mixed-case words: any capital after the first letter.
+        /// </summary>
+        private static bool IsKeptAsWritten(string word)
+        {
+            for (int i = 1; i < word.Length; i++)
+            {
+                if (char.IsUpper(word[i])) return true;
+            }
+            return false;
+        }
+
+        private static bool EndsSentence(string between) =>
+            between.IndexOfAny(new[] { '.', '!', '?', '\n' }) >= 0;
+
+        private static string Capitalize(string word) =>
+            word.Length == 0 ? word : char.ToUpperInvariant(word[0]) + word.Substring(1);
+
+        // Each part of a hyphenated word is capitalized ("Well-Known")
+        private static string CapitalizeParts(string word)
+        {
+            var parts = word.Split('-');
+            for (int i = 0; i < parts.Length; i++) parts[i] = Capitalize(parts[i]);
+            return string.Join("-", parts);
+        }
+    }
+}
@@ -0,0 +1,141 @@
+using System;
+using System.Collections.Generic;
+using System.Linq;
+using System.Text;
+
+namespace EliteWhisper.Services.Speech
+{
+    public class EvaluationResult
+    {
+        public string Hypothesis { get; set; } = string.Empty;
+        public string Reference { get; set; } = string.Empty;
+
+        /// <summary>
+        /// Word Error Rate: (S + D + I) / reference word count.
+        /// </summary>
+        public double Wer { get; set; }
+
+        /// <summary>
+        /// Character Error Rate over the normalized text.
+        /// </summary>
+        public double Cer { get; set; }
+
+        public int Substitutions { get; set; }
+        public int Insertions { get; set; }
+        public int Deletions { get; set; }
+        public int ReferenceWords { get; set; }
+
+        /// <summary>
+        /// Engine that produced the hypothesis, when it came from a transcription.
+        /// </summary>
+        public string EngineName { get; set; } = string.Empty;
+
+        public override string ToString() =>
+            $"WER {Wer:P1} ({Substitutions} substituted, {Insertions} inserted, {Deletions} deleted of {ReferenceWords} words)\nCER {Cer:P1}";
+    }
+
+    /// <summary>
+    /// Scores a transcript against a reference using Levenshtein alignment (WER / CER).
+    /// Text is lower-cased and stripped of punctuation before comparison.
+    /// </summary>
+    public static class TranscriptEvaluator
+    {
+        public static EvaluationResult Evaluate(string hypothesis, string reference)
+        {
+            string normHyp = Normalize(hypothesis);
+            string normRef = Normalize(reference);
+
+            var hypWords = normHyp.Split(' ', StringSplitOptions.RemoveEmptyEntries);
+            var refWords = normRef.Split(' ', StringSplitOptions.RemoveEmptyEntries);
+
+            var (subs, ins, dels) = Align(hypWords, refWords);
+            var (charSubs, charIns, charDels) = Align(normHyp.ToCharArray(), normRef.ToCharArray());
+
+            return new EvaluationResult
+            {
+                Hypothesis = hypothesis,
+                Reference = reference,
+                Substitutions = subs,
+                Insertions = ins,
+                Deletions = dels,
+                ReferenceWords = refWords.Length,
+                Wer = ErrorRate(subs + ins + dels, refWords.Length),
+                Cer = ErrorRate(charSubs + charIns + charDels, normRef.Length)
+            };
+        }
+
+        private static double ErrorRate(int errors, int referenceLength)
+        {
+            // Empty reference: any output at all is 100% wrong, no output is perfect
+            if (referenceLength == 0) return errors == 0 ? 0.0 : 1.0;
+            return (double)errors / referenceLength;
+        }
+
+        /// <summary>
+        /// Minimum edit-distance alignment, returning the substitution/insertion/deletion counts
+        /// of one optimal path.
+        /// </summary>
+        private static (int Substitutions, int Insertions, int Deletions) Align<T>(IReadOnlyList<T> hyp, IReadOnlyList<T> reference)
+        {
+            int n = reference.Count;
+            int m = hyp.Count;
+            var cost = new int[n + 1, m + 1];
+
+            for (int i = 0; i <= n; i++) cost[i, 0] = i;
+            for (int j = 0; j <= m; j++) cost[0, j] = j;
+
+            var comparer = EqualityComparer<T>.Default;
+            for (int i = 1; i <= n; i++)
+            {
+                for (int j = 1; j <= m; j++)
+                {
+                    int sub = cost[i - 1, j - 1] + (comparer.Equals(reference[i - 1], hyp[j - 1]) ? 0 : 1);
+                    int del = cost[i - 1, j] + 1;
+                    int ins = cost[i, j - 1] + 1;
+                    cost[i, j] = Math.Min(sub, Math.Min(del, ins));
+                }
+            }
+
+            // Backtrack to classify the edits
+            int s = 0, d = 0, a = 0;
+            int r = n, h = m;
+            while (r > 0 || h > 0)
+            {
+                if (r > 0 && h > 0 && cost[r, h] == cost[r - 1, h - 1] + (comparer.Equals(reference[r - 1], hyp[h - 1]) ? 0 : 1))
+                {
+                    if (!comparer.Equals(reference[r - 1], hyp[h - 1])) s++;
+                    r--; h--;
+                }
+                else if (r > 0 && cost[r, h] == cost[r - 1, h] + 1)
+                {
+                    d++;
+                    r--;
+                }
+                else
+                {
+                    a++;
+                    h--;
+                }
+            }
+
+            return (s, a, d);
+        }
+
+        private static string Normalize(string text)
+        {
+            if (string.IsNullOrWhiteSpace(text)) return string.Empty;
+
+            var sb = new StringBuilder(text.Length);
+            foreach (char c in text.ToLowerInvariant())
+            {
+                if (char.IsLetterOrDigit(c) || c == '\'')
+                    sb.Append(c);
+                else if (char.IsWhiteSpace(c) || char.IsPunctuation(c))
+                    sb.Append(' ');
+            }
+
+            // Collapse runs of whitespace
+            return string.Join(' ', sb.ToString().Split(' ', StringSplitOptions.RemoveEmptyEntries));
+        }
+    }
+}
@@ -0,0 +1,51 @@
+using System;
+using System.Collections.Generic;
+using System.Linq;
+
+namespace EliteWhisper.Services.Speech
+{
+    /// <summary>
+    /// Heuristic 0..1 score of how trustworthy a transcript looks, from signals available for every engine:
+    /// speaking rate, repeated phrases (a typical hallucination pattern) and input level.
+    /// </summary>
+    public static class TranscriptQualityEstimator
+    {
+        public static double Estimate(string transcript, double audioSeconds, double audioRms)
+        {
+            var words = transcript.Split((char[]?)null, StringSplitOptions.RemoveEmptyEntries);
+            if (words.Length == 0) return 0.0;
+
+            double score = 1.0;
+
+            // Speaking rate: conversational speech is ~2-3 words/s; far outside suggests garbage
+            if (audioSeconds > 0.5)
+            {
+                double wordsPerSecond = words.Length / audioSeconds;
+                if (wordsPerSecond > 6.0) score *= 6.0 / wordsPerSecond;
+                else if (wordsPerSecond < 0.3 && audioSeconds > 5) score *= 0.6;
+            }
+
+            // Repetition: the same three-word phrase over and over
+            if (words.Length >= 9)
+            {
+                var trigrams = new Dictionary<string, int>(StringComparer.OrdinalIgnoreCase);
+                for (int i = 0; i + 2 < words.Length; i++)
+                {
+                    string key = $"{words[i]} {words[i + 1]} {words[i + 2]}";
+                    trigrams[key] = trigrams.TryGetValue(key, out int n) ? n + 1 : 1;
+                }
+
+                int maxRepeats = trigrams.Values.Max();
+                if (maxRepeats >= 3) score *= Math.Max(0.1, 1.0 - 0.15 * maxRepeats);
+
+                double distinctRatio = words.Distinct(StringComparer.OrdinalIgnoreCase).Count() / (double)words.Length;
+                if (distinctRatio < 0.3) score *= distinctRatio / 0.3;
+            }
+
+            // Very quiet input is usually noise the engine tried to read words into
+            if (audioRms < 0.005) score *= 0.5;
+
+            return Math.Clamp(score, 0.0, 1.0);
+        }
+    }
+}
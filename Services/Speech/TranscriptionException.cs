@@ -0,0 +1,20 @@
+using System;
+using EliteWhisper.Models;
+
+namespace EliteWhisper.Services.Speech
+{
+    /// <summary>
+    /// A transcription failure with a stable <see cref="TranscriptionError"/> code next to the
+    /// human-readable message.
+    /// </summary>
+    public class TranscriptionException : Exception
+    {
+        public TranscriptionError Code { get; }
+
+        public TranscriptionException(TranscriptionError code, string message, Exception? innerException = null)
+            : base(message, innerException)
+        {
+            Code = code;
+        }
+    }
+}
@@ -0,0 +1,207 @@
+using System;
+using System.Collections.Generic;
+using System.Linq;
+using System.Threading;
+using System.Threading.Channels;
+using System.Threading.Tasks;
+using EliteWhisper.Models;
+
+namespace EliteWhisper.Services.Speech
+{
+    /// <summary>
+    /// Runs file transcriptions one after another in the background. Each job gets an id up front,
+    /// so callers can queue many files at once and follow them through the events or GetQueueStatus
+    /// instead of blocking on each call.
+    /// </summary>
+    public class TranscriptionQueueService
+    {
+        private readonly SpeechRecognitionService _speechService;
+        private readonly Channel<TranscriptionJob> _pending =
+            Channel.CreateUnbounded<TranscriptionJob>(new UnboundedChannelOptions { SingleReader = true });
+        private readonly List<TranscriptionJob> _jobs = new();
+        private readonly object _lock = new object();
+        private CancellationTokenSource? _runningCts;
+        private Guid _runningJobId;
+
+        // Finished jobs kept for status display; the oldest are dropped beyond this
+        private const int MAX_FINISHED_JOBS = 200;
+
+        /// <summary>
+        /// Raised on a background thread when a job begins transcribing.
+        /// </summary>
+        public event EventHandler<TranscriptionJob>? JobStarted;
+
+        /// <summary>
+        /// Raised on a background thread as the running job progresses.
+        /// </summary>
+        public event EventHandler<TranscriptionJob>? JobProgress;
+
+        /// <summary>
+        /// Raised on a background thread when a job completes, fails or is cancelled.
+        /// </summary>
+        public event EventHandler<TranscriptionJob>? JobFinished;
+
+        public TranscriptionQueueService(SpeechRecognitionService speechService)
+        {
+            _speechService = speechService;
+            _speechService.ProgressChanged += OnProgressChanged;
+            _ = Task.Run(ProcessQueueAsync);
+        }
+
+        /// <summary>
+        /// Queues an audio file for transcription and returns the job id.
+        /// </summary>
+        public Guid Enqueue(string audioFilePath)
+        {
+            var job = new TranscriptionJob { AudioFilePath = audioFilePath };
+            lock (_lock)
+            {
+                _jobs.Add(job);
+            }
+            _pending.Writer.TryWrite(job);
+            System.Diagnostics.Debug.WriteLine($"[Queue] Enqueued {job.Id}: {audioFilePath}");
+            return job.Id;
+        }
+
+        /// <summary>
+        /// Snapshot of the pending, running and recently finished jobs, oldest first.
+        /// </summary>
+        public List<TranscriptionJob> GetQueueStatus()
+        {
+            lock (_lock)
+            {
+                return _jobs.ToList();
+            }
+        }
+
+        public TranscriptionJob? GetJob(Guid id)
+        {
+            lock (_lock)
+            {
+                return _jobs.FirstOrDefault(j => j.Id == id);
+            }
+        }
+
+        /// <summary>
+        /// Cancels a pending or running job. Returns false if it already finished or doesn't exist.
+        /// </summary>
+        public bool Cancel(Guid id)
+        {
+            TranscriptionJob? cancelled = null;
+            lock (_lock)
+            {
+                var job = _jobs.FirstOrDefault(j => j.Id == id);
+                if (job == null) return false;
+
+                if (job.Status == TranscriptionJobStatus.Running && _runningJobId == id)
+                {
+                    _runningCts?.Cancel();
+                    return true;
+                }
+
+                if (job.Status != TranscriptionJobStatus.Pending) return false;
+
+                // The worker skips it when it comes up
+                job.Status = TranscriptionJobStatus.Cancelled;
+                job.FinishedAt = DateTime.Now;
+                cancelled = job;
+            }
+
+            JobFinished?.Invoke(this, cancelled);
+            return true;
+        }
+
+        /// <summary>
+        /// Forgets all completed, failed and cancelled jobs.
+        /// </summary>
+        public void ClearFinished()
+        {
+            lock (_lock)
+            {
+                _jobs.RemoveAll(IsFinished);
+            }
+        }
+
+        private async Task ProcessQueueAsync()
+        {
+            await foreach (var job in _pending.Reader.ReadAllAsync())
+            {
+                using var cts = new CancellationTokenSource();
+                lock (_lock)
+                {
+                    if (job.Status != TranscriptionJobStatus.Pending) continue;
+
+                    job.Status = TranscriptionJobStatus.Running;
+                    job.StartedAt = DateTime.Now;
+                    _runningJobId = job.Id;
+                    _runningCts = cts;
+                }
+
+                JobStarted?.Invoke(this, job);
+
+                try
+                {
+                    var result = await _speechService.TranscribeTimedAsync(job.AudioFilePath, cts.Token, job.Id);
+                    job.Result = result;
+                    job.Percent = 100;
+                    job.Status = TranscriptionJobStatus.Completed;
+                }
+                catch (OperationCanceledException)
+                {
+                    job.Status = TranscriptionJobStatus.Cancelled;
+                }
+                catch (TranscriptionException ex)
+                {
+                    job.Status = TranscriptionJobStatus.Failed;
+                    job.Error = ex.Message;
+                    job.ErrorCode = ex.Code;
+                }
+                catch (Exception ex)
+                {
+                    job.Status = TranscriptionJobStatus.Failed;
+                    job.Error = ex.Message;
+                }
+
+                lock (_lock)
+                {
+                    job.FinishedAt = DateTime.Now;
+                    _runningCts = null;
+                    _runningJobId = Guid.Empty;
+                    TrimFinished();
+                }
+
+                System.Diagnostics.Debug.WriteLine($"[Queue] Job {job.Id} {job.Status}{(job.Error != null ? $": {job.Error}" : "")}");
+                JobFinished?.Invoke(this, job);
+            }
+        }
+
+        private void OnProgressChanged(object? sender, TranscriptionProgress progress)
+        {
+            var job = GetJob(progress.JobId);
+            if (job == null || job.Status != TranscriptionJobStatus.Running) return;
+
+            job.Percent = progress.Percent;
+            JobProgress?.Invoke(this, job);
+        }
+
+        private void TrimFinished()
+        {
+            int excess = _jobs.Count(IsFinished) - MAX_FINISHED_JOBS;
+            for (int i = 0; i < _jobs.Count && excess > 0;)
+            {
+                if (IsFinished(_jobs[i]))
+                {
+                    _jobs.RemoveAt(i);
+                    excess--;
+                }
+                else
+                {
+                    i++;
+                }
+            }
+        }
+
+        private static bool IsFinished(TranscriptionJob job) =>
+            job.Status is TranscriptionJobStatus.Completed or TranscriptionJobStatus.Failed or TranscriptionJobStatus.Cancelled;
+    }
+}
@@ -0,0 +1,99 @@
+using System;
+using System.Collections.Generic;
+
+namespace EliteWhisper.Services.Speech
+{
+    /// <summary>
+    /// Energy-based voice activity detection on 16kHz mono samples. Frames louder than the threshold
+    /// count as voiced; regions are padded so word onsets and trailing consonants aren't clipped.
+    /// </summary>
+    public static class VoiceActivityDetector
+    {
+        private const int FRAME_MS = 30;
+        private const int PADDING_MS = 250;
+
+        // Gaps shorter than this stay inside one region, so pauses between words aren't cut out
+        private const int MIN_GAP_MS = 600;
+
+        /// <summary>
+        /// Returns the voiced regions as [start, end) sample ranges. Empty when the clip is silent.
+        /// </summary>
+        public static List<(int Start, int End)> FindVoicedRegions(float[] samples, int sampleRate, double thresholdDb)
+        {
+            var regions = new List<(int Start, int End)>();
+            int frameLength = sampleRate * FRAME_MS / 1000;
+            if (frameLength == 0 || samples.Length == 0) return regions;
+
+            int padding = sampleRate * PADDING_MS / 1000;
+            int minGap = sampleRate * MIN_GAP_MS / 1000;
+            double threshold = Math.Pow(10, thresholdDb / 20.0);
+
+            int regionStart = -1;
+            int lastVoicedEnd = -1;
+
+            for (int frameStart = 0; frameStart < samples.Length; frameStart += frameLength)
+            {
+                int frameEnd = Math.Min(frameStart + frameLength, samples.Length);
+
+                double sum = 0;
+                for (int i = frameStart; i < frameEnd; i++)
+                    sum += samples[i] * samples[i];
+                double rms = Math.Sqrt(sum / (frameEnd - frameStart));
+
+                if (rms < threshold) continue;
+
+                if (regionStart >= 0 && frameStart - lastVoicedEnd > minGap)
+                {
+                    regions.Add(Pad(regionStart, lastVoicedEnd, padding, samples.Length));
+                    regionStart = -1;
+                }
+
+                if (regionStart < 0) regionStart = frameStart;
+                lastVoicedEnd = frameEnd;
+            }
+
+            if (regionStart >= 0)
+                regions.Add(Pad(regionStart, lastVoicedEnd, padding, samples.Length));
+
+            // Padding can make neighbours overlap
+            for (int i = regions.Count - 1; i > 0; i--)
+            {
+                if (regions[i].Start <= regions[i - 1].End)
+                {
+                    regions[i - 1] = (regions[i - 1].Start, regions[i].End);
+                    regions.RemoveAt(i);
+                }
+            }
+
+            return regions;
+        }
+
+        /// <summary>
+        /// Joins the voiced regions into one buffer with the silence between them removed.
+        /// Returns an empty array for a silent clip.
+        /// </summary>
+        public static float[] Trim(float[] samples, int sampleRate, double thresholdDb)
+        {
+            var regions = FindVoicedRegions(samples, sampleRate, thresholdDb);
+
+            int total = 0;
+            foreach (var (start, end) in regions)
+                total += end - start;
+
+            var trimmed = new float[total];
+            int offset = 0;
+            foreach (var (start, end) in regions)
+            {
+                Array.Copy(samples, start, trimmed, offset, end - start);
+                offset += end - start;
+            }
+
+            return trimmed;
+        }
+
+        private static (int Start, int End) Pad(int start, int end, int padding, int length)
+        {
+            return (Math.Max(0, start - padding), Math.Min(length, end + padding));
+        }
+    }
+}
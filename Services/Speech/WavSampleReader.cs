@@ -0,0 +1,97 @@
+using System;
+using System.IO;
+using System.Threading;
+using NAudio.Wave;
+
+namespace EliteWhisper.Services.Speech
+{
+    /// <summary>
+    /// Reads WAV samples as floats in [-1, 1], normalizing by the exact bit depth in the header:
+    /// 8-bit unsigned, 16/24/32-bit signed integer (24-bit packed in 3 bytes) and 32/64-bit float,
+    /// plain or WAVE_FORMAT_EXTENSIBLE. NAudio's generic converters reject 64-bit float and
+    /// extensible formats, which made such files fail or play back distorted.
+    /// </summary>
+    public static class WavSampleReader
+    {
+        // KSDATAFORMAT_SUBTYPE_PCM / KSDATAFORMAT_SUBTYPE_IEEE_FLOAT
+        private static readonly Guid SubtypePcm = new("00000001-0000-0010-8000-00aa00389b71");
+        private static readonly Guid SubtypeFloat = new("00000003-0000-0010-8000-00aa00389b71");
+
+        /// <summary>
+        /// Returns interleaved samples and a float format with the file's sample rate and channel count.
+        /// Throws InvalidDataException for compressed or unsupported sample formats.
+        /// </summary>
+        public static (float[] Samples, WaveFormat Format) Read(string filePath, CancellationToken ct)
+        {
+            using var reader = new WaveFileReader(filePath);
+            var format = reader.WaveFormat;
+
+            bool isFloat = IsFloat(format);
+            int bits = format.BitsPerSample;
+            int bytesPerSample = bits / 8;
+
+            if (bits % 8 != 0 || bytesPerSample < 1 || bytesPerSample > 8 ||
+                (isFloat && bits != 32 && bits != 64) || (!isFloat && bits > 32) ||
+                (!isFloat && !IsPcm(format)))
+            {
+                throw new InvalidDataException($"Unsupported WAV sample format: {format.Encoding}, {bits}-bit.");
+            }
+
+            // Whole frames only; a truncated final frame is ignored
+            int blockAlign = bytesPerSample * format.Channels;
+            long frames = reader.Length / blockAlign;
+            long total = frames * format.Channels;
+            if (total > int.MaxValue)
+                throw new InvalidDataException("WAV file is too long to transcribe.");
+
+            var samples = new float[total];
+            var buffer = new byte[blockAlign * 4096];
+            int index = 0;
+            int read;
+
+            while (index < samples.Length && (read = reader.Read(buffer, 0, buffer.Length)) > 0)
+            {
+                ct.ThrowIfCancellationRequested();
+
+                int usable = read - read % bytesPerSample;
+                for (int offset = 0; offset < usable && index < samples.Length; offset += bytesPerSample)
+                {
+                    samples[index++] = ToFloat(buffer, offset, bits, isFloat);
+                }
+            }
+
+            if (index < samples.Length) Array.Resize(ref samples, index - index % format.Channels);
+
+            return (samples, WaveFormat.CreateIeeeFloatWaveFormat(format.SampleRate, format.Channels));
+        }
+
+        private static float ToFloat(byte[] buffer, int offset, int bits, bool isFloat)
+        {
+            if (isFloat)
+            {
+                return bits == 64
+                    ? (float)BitConverter.ToDouble(buffer, offset)
+                    : BitConverter.ToSingle(buffer, offset);
+            }
+
+            return bits switch
+            {
+                // 8-bit WAV is unsigned with silence at 128
+                8 => (buffer[offset] - 128) / 128f,
+                16 => BitConverter.ToInt16(buffer, offset) / 32768f,
+                // Packed little-endian; shift into the top of an int to sign-extend
+                24 => ((buffer[offset] << 8) | (buffer[offset + 1] << 16) | (buffer[offset + 2] << 24)) / 2147483648f,
+                32 => BitConverter.ToInt32(buffer, offset) / 2147483648f,
+                _ => 0f
+            };
+        }
+
+        private static bool IsPcm(WaveFormat format) =>
+            format.Encoding == WaveFormatEncoding.Pcm ||
+            (format is WaveFormatExtensible ext && ext.SubFormat == SubtypePcm);
+
+        private static bool IsFloat(WaveFormat format) =>
+            format.Encoding == WaveFormatEncoding.IeeeFloat ||
+            (format is WaveFormatExtensible ext && ext.SubFormat == SubtypeFloat);
+    }
+}
@@ -1,7 +1,9 @@
 using System;
+using System.Collections.Generic;
 using System.IO;
 using System.Threading;
 using System.Threading.Tasks;
+using EliteWhisper.Models;
 
 namespace EliteWhisper.Services.Speech
 {
@@ -13,17 +15,33 @@ namespace EliteWhisper.Services.Speech
     public class WhisperEngine : ISpeechEngine
     {
         private readonly AIEngineService _aiEngineService;
+        private readonly string? _modelPathOverride;
         
-        public WhisperEngine(AIEngineService aiEngineService)
+        /// <param name="modelPathOverride">
+        /// Optional ggml model to use instead of the configured default (e.g. a dedicated fallback model).
+        /// </param>
+        public WhisperEngine(AIEngineService aiEngineService, string? modelPathOverride = null)
         {
             _aiEngineService = aiEngineService;
+            _modelPathOverride = modelPathOverride;
         }
 
-        public string Name => "Whisper";
+        public string Name => _modelPathOverride == null ? "Whisper" : $"Whisper ({Path.GetFileNameWithoutExtension(_modelPathOverride)})";
 
-        public bool IsAvailable => _aiEngineService.IsConfigured();
+        public bool IsAvailable => _modelPathOverride == null
+            ? _aiEngineService.IsConfigured()
+            : _aiEngineService.IsExecutableConfigured() && File.Exists(_modelPathOverride);
 
-        public async Task<string> TranscribeAsync(float[] audioSamples, CancellationToken ct)
+        public async Task<string> TranscribeAsync(float[] audioSamples, CancellationToken ct, IProgress<int>? progress = null)
+        {
+            var result = await TranscribeDetailedAsync(audioSamples, ct, progress);
+            return result.Text;
+        }
+
+        /// <summary>
+        /// Same as TranscribeAsync but keeps Whisper's no-speech probability for this run.
+        /// </summary>
+        public async Task<TranscriptionResult> TranscribeDetailedAsync(float[] audioSamples, CancellationToken ct, IProgress<int>? progress = null)
         {
             // The existing AIEngineService expects a WAV file path.
             // We need to convert the float array to a WAV file temporarily.
@@ -34,8 +52,32 @@ namespace EliteWhisper.Services.Speech
                 SaveSamplesAsWav(audioSamples, 16000, tempFilePath); // Whisper uses 16kHz
                 
                 // Use the Balanced model as default for dictation, or fetch from config
-                string result = await _aiEngineService.TranscribeAsync(tempFilePath, TranscriptionModel.Balanced, ct);
-                return result;
+                return await _aiEngineService.TranscribeDetailedAsync(tempFilePath, ct, _modelPathOverride, progress);
+            }
+            finally
+            {
+                try
+                {
+                    if (File.Exists(tempFilePath))
+                    {
+                        File.Delete(tempFilePath);
+                    }
+                }
+                catch { /* Ignore cleanup errors */ }
+            }
+        }
+
+        /// <summary>
+        /// Same as TranscribeAsync but keeps segment and word timings.
+        /// </summary>
+        public async Task<List<TranscriptSegment>> TranscribeWithTimestampsAsync(float[] audioSamples, CancellationToken ct)
+        {
+            string tempFilePath = Path.Combine(Path.GetTempPath(), $"whisper_temp_{Guid.NewGuid():N}.wav");
+
+            try
+            {
+                SaveSamplesAsWav(audioSamples, 16000, tempFilePath);
+                return await _aiEngineService.TranscribeWithTimestampsAsync(tempFilePath, ct, _modelPathOverride);
             }
             finally
             {
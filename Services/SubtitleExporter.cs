@@ -0,0 +1,111 @@
+using System;
+using System.Collections.Generic;
+using System.IO;
+using System.Text;
+using EliteWhisper.Models;
+
+namespace EliteWhisper.Services
+{
+    public enum SubtitleFormat
+    {
+        Srt,
+        Vtt
+    }
+
+    /// <summary>
+    /// Writes transcripts as SRT or WebVTT subtitles. Cues are built from word timings when the
+    /// record has them; otherwise the whole transcript becomes one cue spanning the recording.
+    /// </summary>
+    public static class SubtitleExporter
+    {
+        // Readability limits for a single cue
+        private const int MAX_CUE_CHARS = 84;
+        private const long MAX_CUE_MS = 7000;
+
+        // Zero-length cues are dropped by most players
+        private const long MIN_CUE_MS = 500;
+
+        public static void Export(DictationRecord record, SubtitleFormat format, string path)
+        {
+            File.WriteAllText(path, Format(BuildCues(record), format), new UTF8Encoding(false));
+        }
+
+        public static List<TranscriptSegment> BuildCues(DictationRecord record)
+        {
+            var cues = new List<TranscriptSegment>();
+
+            if (record.Words == null || record.Words.Count == 0)
+            {
+                long durationMs = record.Duration > TimeSpan.Zero
+                    ? (long)record.Duration.TotalMilliseconds
+                    : record.DurationSeconds * 1000L;
+                cues.Add(new TranscriptSegment { StartMs = 0, EndMs = durationMs, Text = record.Content.Trim() });
+                return cues;
+            }
+
+            TranscriptSegment? current = null;
+            var text = new StringBuilder();
+
+            foreach (var word in record.Words)
+            {
+                if (current != null &&
+                    (text.Length + 1 + word.Word.Length > MAX_CUE_CHARS || word.EndMs - current.StartMs > MAX_CUE_MS))
+                {
+                    current.Text = text.ToString();
+                    cues.Add(current);
+                    current = null;
+                    text.Clear();
+                }
+
+                current ??= new TranscriptSegment { StartMs = word.StartMs };
+                if (text.Length > 0) text.Append(' ');
+                text.Append(word.Word);
+                current.EndMs = word.EndMs;
+            }
+
+            if (current != null)
+            {
+                current.Text = text.ToString();
+                cues.Add(current);
+            }
+
+            return cues;
+        }
+
+        public static string Format(IReadOnlyList<TranscriptSegment> cues, SubtitleFormat format)
+        {
+            var sb = new StringBuilder();
+            if (format == SubtitleFormat.Vtt)
+            {
+                sb.Append("WEBVTT\n\n");
+            }
+
+            int sequence = 1;
+            foreach (var cue in cues)
+            {
+                if (string.IsNullOrWhiteSpace(cue.Text)) continue;
+
+                long start = Math.Max(0, cue.StartMs);
+                long end = Math.Max(cue.EndMs, start + MIN_CUE_MS);
+
+                if (format == SubtitleFormat.Srt)
+                {
+                    sb.Append(sequence).Append('\n');
+                }
+                sb.Append(FormatTime(start, format)).Append(" --> ").Append(FormatTime(end, format)).Append('\n');
+                sb.Append(cue.Text.Trim()).Append("\n\n");
+                sequence++;
+            }
+
+            return sb.ToString();
+        }
+
+        // SRT: 00:01:02,345  VTT: 00:01:02.345
+        private static string FormatTime(long ms, SubtitleFormat format)
+        {
+            var time = TimeSpan.FromMilliseconds(ms);
+            char separator = format == SubtitleFormat.Srt ? ',' : '.';
+            return $"{(int)time.TotalHours:00}:{time.Minutes:00}:{time.Seconds:00}{separator}{time.Milliseconds:000}";
+        }
+    }
+}
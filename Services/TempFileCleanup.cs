@@ -0,0 +1,66 @@
+using System;
+using System.IO;
+
+namespace EliteWhisper.Services
+{
+    /// <summary>
+    /// Removes temp files the app leaves behind when it is closed or crashes mid-transcription,
+    /// or when a failed recording was kept for troubleshooting.
+    /// </summary>
+    public static class TempFileCleanup
+    {
+        /// <summary>
+        /// Temp files younger than this are left alone, as they may belong to a running transcription.
+        /// </summary>
+        public static readonly TimeSpan StaleAge = TimeSpan.FromDays(1);
+
+        // Recordings, Whisper input copies and their .txt/.json outputs, hotword snapshots, self-test clips
+        private static readonly string[] Patterns =
+        {
+            "elitewhisper_*.wav", "elitewhisper_*.txt", "elitewhisper_*.json",
+            "whisper_temp_*.wav", "whisper_temp_*.txt", "whisper_temp_*.json"
+        };
+
+        /// <summary>
+        /// Deletes the app's temp files older than StaleAge. Returns how many were deleted.
+        /// </summary>
+        public static int SweepStale()
+        {
+            int deleted = 0;
+            DateTime cutoff = DateTime.UtcNow - StaleAge;
+            string tempDirectory = Path.GetTempPath();
+
+            foreach (var pattern in Patterns)
+            {
+                string[] files;
+                try
+                {
+                    files = Directory.GetFiles(tempDirectory, pattern, SearchOption.TopDirectoryOnly);
+                }
+                catch (Exception ex)
+                {
+                    System.Diagnostics.Debug.WriteLine($"[TempCleanup] Could not list {pattern}: {ex.Message}");
+                    continue;
+                }
+
+                foreach (var file in files)
+                {
+                    try
+                    {
+                        if (File.GetLastWriteTimeUtc(file) >= cutoff) continue;
+                        File.Delete(file);
+                        deleted++;
+                    }
+                    catch
+                    {
+                        // In use or already gone; the next sweep gets it
+                    }
+                }
+            }
+
+            if (deleted > 0)
+                System.Diagnostics.Debug.WriteLine($"[TempCleanup] Deleted {deleted} stale temp file(s)");
+            return deleted;
+        }
+    }
+}
@@ -4,6 +4,7 @@ using System.Runtime.InteropServices;
 using System.Threading;
 using System.Threading.Tasks;
 using System.Windows;
+using EliteWhisper.Models;
 
 namespace EliteWhisper.Services
 {
@@ -23,6 +24,21 @@ namespace EliteWhisper.Services
         [DllImport("user32.dll")]
         private static extern bool SetForegroundWindow(IntPtr hWnd);
 
+        [DllImport("user32.dll")]
+        private static extern uint GetWindowThreadProcessId(IntPtr hWnd, out uint processId);
+
+        [DllImport("kernel32.dll", SetLastError = true)]
+        private static extern IntPtr OpenProcess(uint dwDesiredAccess, bool bInheritHandle, uint dwProcessId);
+
+        [DllImport("kernel32.dll")]
+        private static extern bool CloseHandle(IntPtr hObject);
+
+        [DllImport("advapi32.dll", SetLastError = true)]
+        private static extern bool OpenProcessToken(IntPtr processHandle, uint desiredAccess, out IntPtr tokenHandle);
+
+        [DllImport("advapi32.dll", SetLastError = true)]
+        private static extern bool GetTokenInformation(IntPtr tokenHandle, int tokenInformationClass, out int tokenInformation, int tokenInformationLength, out int returnLength);
+
         [StructLayout(LayoutKind.Sequential)]
         public struct INPUT
         {
@@ -72,12 +88,23 @@ namespace EliteWhisper.Services
         private const ushort VK_CONTROL = 0x11;
         private const ushort VK_V = 0x56;
 
+        private const uint PROCESS_QUERY_LIMITED_INFORMATION = 0x1000;
+        private const uint TOKEN_QUERY = 0x0008;
+        private const int TOKEN_ELEVATION = 20;
+
         #endregion
 
         // Configuration
         public int TypingDelayMs { get; set; } = 0; // 0 = instant, >0 = simulated typing
         public int ClipboardThreshold { get; set; } = 100; // Use clipboard for text > this length
         public bool PreferClipboard { get; set; } = false; // Always use clipboard
+        public bool RestoreClipboard { get; set; } = true; // Put the user's clipboard back after pasting
+
+        /// <summary>
+        /// Raised when simulated input is blocked and the text was copied to the clipboard instead.
+        /// The argument explains why typing was unavailable.
+        /// </summary>
+        public event EventHandler<string>? TypingUnavailable;
 
         /// <summary>
         /// Injects text into the currently focused window
@@ -102,6 +129,120 @@ namespace EliteWhisper.Services
             }
         }
 
+        /// <summary>
+        /// Delivers text according to the configured output action
+        /// </summary>
+        public async Task DeliverTextAsync(string text, OutputAction action, CancellationToken cancellationToken = default)
+        {
+            if (string.IsNullOrEmpty(text)) return;
+
+            if (action != OutputAction.Clipboard && action != OutputAction.None)
+            {
+                var (available, reason) = CheckTypingAvailable();
+                if (!available)
+                {
+                    await FallBackToClipboardAsync(text, reason!);
+                    return;
+                }
+            }
+
+            switch (action)
+            {
+                case OutputAction.Type:
+                    await InjectViaSendInputAsync(text, cancellationToken);
+                    break;
+                case OutputAction.Paste:
+                    await InjectViaClipboardAsync(text, cancellationToken);
+                    break;
+                case OutputAction.Clipboard:
+                    await CopyToClipboardAsync(text);
+                    break;
+                case OutputAction.None:
+                    break;
+                default:
+                    await InjectTextAsync(text, cancellationToken);
+                    break;
+            }
+        }
+
+        /// <summary>
+        /// Reports whether simulated keyboard input can reach the focused window.
+        /// Windows blocks input from a normal process into an elevated (administrator) window.
+        /// </summary>
+        public (bool Available, string? Reason) CheckTypingAvailable()
+        {
+            IntPtr foregroundWindow = GetForegroundWindow();
+            if (foregroundWindow == IntPtr.Zero)
+            {
+                return (false, "No window has keyboard focus (the screen may be locked).");
+            }
+
+            if (!IsCurrentProcessElevated() && IsWindowProcessElevated(foregroundWindow))
+            {
+                return (false, "The target window is running as administrator. Run Elite Whisper as administrator to type into it.");
+            }
+
+            return (true, null);
+        }
+
+        private async Task FallBackToClipboardAsync(string text, string reason)
+        {
+            System.Diagnostics.Debug.WriteLine($"Typing unavailable, copied to clipboard: {reason}");
+            await CopyToClipboardAsync(text);
+            TypingUnavailable?.Invoke(this, reason);
+        }
+
+        private static bool IsCurrentProcessElevated()
+        {
+            using var identity = System.Security.Principal.WindowsIdentity.GetCurrent();
+            return new System.Security.Principal.WindowsPrincipal(identity)
+                .IsInRole(System.Security.Principal.WindowsBuiltInRole.Administrator);
+        }
+
+        private static bool IsWindowProcessElevated(IntPtr hwnd)
+        {
+            GetWindowThreadProcessId(hwnd, out uint processId);
+            IntPtr process = OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, false, processId);
+            if (process == IntPtr.Zero) return false;
+
+            try
+            {
+                // Access denied on the token is itself a strong sign of an elevated process
+                if (!OpenProcessToken(process, TOKEN_QUERY, out IntPtr token)) return true;
+
+                try
+                {
+                    return GetTokenInformation(token, TOKEN_ELEVATION, out int elevated, sizeof(int), out _) && elevated != 0;
+                }
+                finally
+                {
+                    CloseHandle(token);
+                }
+            }
+            finally
+            {
+                CloseHandle(process);
+            }
+        }
+
+        /// <summary>
+        /// Places text on the clipboard without pasting it
+        /// </summary>
+        public async Task CopyToClipboardAsync(string text)
+        {
+            await Application.Current.Dispatcher.InvokeAsync(() =>
+            {
+                try
+                {
+                    Clipboard.SetText(text);
+                }
+                catch (Exception ex)
+                {
+                    System.Diagnostics.Debug.WriteLine($"Clipboard error: {ex.Message}");
+                }
+            });
+        }
+
         /// <summary>
         /// Uses SendInput with Unicode characters for direct keystroke simulation
         /// </summary>
@@ -111,10 +252,12 @@ namespace EliteWhisper.Services
             {
                 cancellationToken.ThrowIfCancellationRequested();
 
+                bool sent;
+
                 // Handle newlines as Enter key
                 if (c == '\n')
                 {
-                    SendKeyPress(0x0D); // VK_RETURN
+                    sent = SendKeyPress(0x0D); // VK_RETURN
                 }
                 else if (c == '\r')
                 {
@@ -123,7 +266,14 @@ namespace EliteWhisper.Services
                 }
                 else
                 {
-                    SendUnicodeChar(c);
+                    sent = SendUnicodeChar(c);
+                }
+
+                if (!sent)
+                {
+                    // SendInput was blocked (UIPI or secure desktop); don't lose the rest of the text
+                    await FallBackToClipboardAsync(text, "Windows blocked simulated typing into the focused window.");
+                    return;
                 }
 
                 if (TypingDelayMs > 0)
@@ -133,7 +283,7 @@ namespace EliteWhisper.Services
             }
         }
 
-        private void SendUnicodeChar(char c)
+        private bool SendUnicodeChar(char c)
         {
             INPUT[] inputs = new INPUT[2];
 
@@ -171,10 +321,10 @@ namespace EliteWhisper.Services
                 }
             };
 
-            SendInput(2, inputs, INPUT.Size);
+            return SendInput(2, inputs, INPUT.Size) == 2;
         }
 
-        private void SendKeyPress(ushort vk)
+        private bool SendKeyPress(ushort vk)
         {
             INPUT[] inputs = new INPUT[2];
 
@@ -196,7 +346,7 @@ namespace EliteWhisper.Services
                 }
             };
 
-            SendInput(2, inputs, INPUT.Size);
+            return SendInput(2, inputs, INPUT.Size) == 2;
         }
 
         /// <summary>
@@ -207,16 +357,8 @@ namespace EliteWhisper.Services
             // Must run clipboard operations on STA thread
             await Application.Current.Dispatcher.InvokeAsync(() =>
             {
-                // Backup existing clipboard
-                string? originalClipboard = null;
-                try
-                {
-                    if (Clipboard.ContainsText())
-                    {
-                        originalClipboard = Clipboard.GetText();
-                    }
-                }
-                catch { }
+                // Backup existing clipboard (every format, not just text, so images and files survive too)
+                IDataObject? originalClipboard = RestoreClipboard ? SnapshotClipboard() : null;
 
                 try
                 {
@@ -242,13 +384,58 @@ namespace EliteWhisper.Services
                     {
                         Application.Current.Dispatcher.Invoke(() =>
                         {
-                            try { Clipboard.SetText(originalClipboard); } catch { }
+                            try
+                            {
+                                // Leave it alone if something else was copied in the meantime
+                                if (Clipboard.ContainsText() && Clipboard.GetText() == text)
+                                {
+                                    Clipboard.SetDataObject(originalClipboard, true);
+                                }
+                            }
+                            catch { }
                         });
                     });
                 }
             });
         }
 
+        /// <summary>
+        /// Copies every format currently on the clipboard. Returns null when it is empty or unreadable.
+        /// </summary>
+        private static IDataObject? SnapshotClipboard()
+        {
+            try
+            {
+                var current = Clipboard.GetDataObject();
+                if (current == null) return null;
+
+                var snapshot = new DataObject();
+                bool any = false;
+                foreach (var format in current.GetFormats(false))
+                {
+                    try
+                    {
+                        var data = current.GetData(format, false);
+                        if (data != null)
+                        {
+                            snapshot.SetData(format, data, false);
+                            any = true;
+                        }
+                    }
+                    catch
+                    {
+                        // Some formats (delay-rendered or COM-only) can't be copied; skip them
+                    }
+                }
+
+                return any ? snapshot : null;
+            }
+            catch
+            {
+                return null;
+            }
+        }
+
         private void SendCtrlV()
         {
             INPUT[] inputs = new INPUT[4];
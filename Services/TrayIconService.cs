@@ -8,12 +8,23 @@ namespace EliteWhisper.Services
 {
     public class TrayIconService : IDisposable
     {
+        private const int RECENT_MODELS_IN_MENU = 3;
+
+        private readonly RecentModelsService _recentModels;
         private NotifyIcon? _notifyIcon;
         private ContextMenuStrip? _contextMenu;
+        private ToolStripMenuItem? _recentModelsItem;
         
         public event EventHandler? SettingsRequested;
         public event EventHandler? ExitRequested;
         public event EventHandler? ShowWidgetRequested;
+        public event EventHandler? ResetWidgetPositionRequested;
+        public event EventHandler<Models.RecentModelEntry>? ModelSwitchRequested;
+
+        public TrayIconService(RecentModelsService recentModels)
+        {
+            _recentModels = recentModels;
+        }
 
         public void Initialize()
         {
@@ -24,9 +35,17 @@ namespace EliteWhisper.Services
             showItem.Click += (s, e) => ShowWidgetRequested?.Invoke(this, EventArgs.Empty);
             showItem.Font = new Font(showItem.Font, System.Drawing.FontStyle.Bold);
             _contextMenu.Items.Add(showItem);
+
+            var resetPositionItem = new ToolStripMenuItem("Reset Widget Position");
+            resetPositionItem.Click += (s, e) => ResetWidgetPositionRequested?.Invoke(this, EventArgs.Empty);
+            _contextMenu.Items.Add(resetPositionItem);
             
             _contextMenu.Items.Add(new ToolStripSeparator());
             
+            _recentModelsItem = new ToolStripMenuItem("Recent Models");
+            _contextMenu.Items.Add(_recentModelsItem);
+            _contextMenu.Opening += (s, e) => RefreshRecentModels();
+            
             var settingsItem = new ToolStripMenuItem("Settings...");
             settingsItem.Click += (s, e) => SettingsRequested?.Invoke(this, EventArgs.Empty);
             _contextMenu.Items.Add(settingsItem);
@@ -50,6 +69,22 @@ namespace EliteWhisper.Services
             _notifyIcon.DoubleClick += (s, e) => ShowWidgetRequested?.Invoke(this, EventArgs.Empty);
         }
 
+        private void RefreshRecentModels()
+        {
+            if (_recentModelsItem == null) return;
+
+            _recentModelsItem.DropDownItems.Clear();
+            var recent = _recentModels.GetRecentModels(RECENT_MODELS_IN_MENU);
+            foreach (var entry in recent)
+            {
+                var item = new ToolStripMenuItem($"{entry.Name} ({entry.Engine})");
+                item.Click += (s, e) => ModelSwitchRequested?.Invoke(this, entry);
+                _recentModelsItem.DropDownItems.Add(item);
+            }
+
+            _recentModelsItem.Enabled = recent.Count > 0;
+        }
+
         private Icon CreateDefaultIcon()
         {
             // Create a simple colored icon programmatically
@@ -3,6 +3,7 @@ using System.Collections.Generic;
 using System.IO;
 using System.Linq;
 using System.Text.Json;
+using System.Text.Json.Nodes;
 using System.Threading.Tasks;
 using EliteWhisper.Models;
 
@@ -30,15 +31,16 @@ namespace EliteWhisper.Services
 
         public WhisperConfiguration CurrentConfiguration => _currentConfig;
 
+        // Store config in AppData
         public WhisperConfigurationService()
+            : this(Path.Combine(Environment.GetFolderPath(Environment.SpecialFolder.LocalApplicationData), "EliteWhisper"))
         {
-            // Store config in AppData
-            string appDataPath = Path.Combine(
-                Environment.GetFolderPath(Environment.SpecialFolder.LocalApplicationData),
-                "EliteWhisper");
-            
-            Directory.CreateDirectory(appDataPath);
-            _configFilePath = Path.Combine(appDataPath, "whisper_config.json");
+        }
+
+        internal WhisperConfigurationService(string directory)
+        {
+            Directory.CreateDirectory(directory);
+            _configFilePath = Path.Combine(directory, "whisper_config.json");
             
             _currentConfig = LoadConfiguration();
         }
@@ -160,10 +162,24 @@ namespace EliteWhisper.Services
                 LastValidated = DateTime.Now,
 
                 // Restore persistent user settings
+                SchemaVersion = existingConfig.SchemaVersion,
                 HasCompletedFirstRun = existingConfig.HasCompletedFirstRun,
+                HasOfferedDefaultModel = existingConfig.HasOfferedDefaultModel,
+                DefaultDownloadModelId = existingConfig.DefaultDownloadModelId,
                 HistoryStoragePath = existingConfig.HistoryStoragePath,
+                SaveTranscriptFiles = existingConfig.SaveTranscriptFiles,
+                EncryptHistory = existingConfig.EncryptHistory,
+                TranscriptsDirectory = existingConfig.TranscriptsDirectory,
+                HistoryRetentionMode = existingConfig.HistoryRetentionMode,
+                HistoryRetentionLimit = existingConfig.HistoryRetentionLimit,
+                SaveRecordings = existingConfig.SaveRecordings,
+                RecordingStorageCapMB = existingConfig.RecordingStorageCapMB,
+                RecordingRetentionDays = existingConfig.RecordingRetentionDays,
+                KeepFailedRecordings = existingConfig.KeepFailedRecordings,
                 Modes = existingConfig.Modes ?? new System.Collections.Generic.List<DictationMode>(),
                 ActiveModeId = existingConfig.ActiveModeId,
+                OutputAction = existingConfig.OutputAction,
+                RestoreClipboardAfterPaste = existingConfig.RestoreClipboardAfterPaste,
                 GeminiApiKey = existingConfig.GeminiApiKey,
                 OpenRouterApiKey = existingConfig.OpenRouterApiKey,
                 DefaultProviderPreference = existingConfig.DefaultProviderPreference,
@@ -171,7 +187,63 @@ namespace EliteWhisper.Services
                 // STT Engine Preferences
                 PreferredSTTEngine = existingConfig.PreferredSTTEngine,
                 AutoSelectSTT = existingConfig.AutoSelectSTT,
-                EnableGPUAcceleration = existingConfig.EnableGPUAcceleration
+                SherpaModelType = existingConfig.SherpaModelType,
+                TranscriptionLanguage = existingConfig.TranscriptionLanguage,
+                HotkeyVirtualKey = existingConfig.HotkeyVirtualKey,
+                HotkeyModifiers = existingConfig.HotkeyModifiers,
+                HotkeyMode = existingConfig.HotkeyMode,
+                AutoStopOnSilence = existingConfig.AutoStopOnSilence,
+                AutoStopSilenceSeconds = existingConfig.AutoStopSilenceSeconds,
+                ActionHotkeys = existingConfig.ActionHotkeys ?? new System.Collections.Generic.List<HotkeyBinding>(),
+                InputDeviceName = existingConfig.InputDeviceName,
+                PreRollMs = existingConfig.PreRollMs,
+                WidgetLeft = existingConfig.WidgetLeft,
+                WidgetTop = existingConfig.WidgetTop,
+                WhisperTask = existingConfig.WhisperTask,
+                EnableNormalization = existingConfig.EnableNormalization,
+                NormalizationTargetDb = existingConfig.NormalizationTargetDb,
+                MinAudioDurationMs = existingConfig.MinAudioDurationMs,
+                EnableVoiceActivityDetection = existingConfig.EnableVoiceActivityDetection,
+                VoiceActivityThresholdDb = existingConfig.VoiceActivityThresholdDb,
+                EnableGPUAcceleration = existingConfig.EnableGPUAcceleration,
+                TranscriptionThreads = existingConfig.TranscriptionThreads,
+                WarmUpModelOnLoad = existingConfig.WarmUpModelOnLoad,
+                ModelCacheSize = existingConfig.ModelCacheSize,
+                EnableStreaming = existingConfig.EnableStreaming,
+                StreamingModelPath = existingConfig.StreamingModelPath,
+                EnableDiarization = existingConfig.EnableDiarization,
+                DiarizationSegmentationModel = existingConfig.DiarizationSegmentationModel,
+                DiarizationEmbeddingModel = existingConfig.DiarizationEmbeddingModel,
+                DiarizationSpeakerCount = existingConfig.DiarizationSpeakerCount,
+                EnableWhisperFallback = existingConfig.EnableWhisperFallback,
+                FallbackWhisperModelPath = existingConfig.FallbackWhisperModelPath,
+
+                // Whisper Decoding
+                DecodingStrategy = existingConfig.DecodingStrategy,
+                BeamSize = existingConfig.BeamSize,
+                WhisperBestOf = existingConfig.WhisperBestOf,
+                WhisperTemperature = existingConfig.WhisperTemperature,
+                WhisperTemperatureIncrement = existingConfig.WhisperTemperatureIncrement,
+                WhisperEntropyThreshold = existingConfig.WhisperEntropyThreshold,
+                WhisperLogProbThreshold = existingConfig.WhisperLogProbThreshold,
+                WhisperNoSpeechThreshold = existingConfig.WhisperNoSpeechThreshold,
+                WhisperSuppressNonSpeechTokens = existingConfig.WhisperSuppressNonSpeechTokens,
+                WhisperSuppressRegex = existingConfig.WhisperSuppressRegex,
+                WhisperInitialPrompt = existingConfig.WhisperInitialPrompt,
+                WhisperPromptWithPreviousTranscript = existingConfig.WhisperPromptWithPreviousTranscript,
+
+                // Quality Gate
+                MinTranscriptQuality = existingConfig.MinTranscriptQuality,
+                SuppressLowQualityOutput = existingConfig.SuppressLowQualityOutput,
+                NoSpeechWarningThreshold = existingConfig.NoSpeechWarningThreshold,
+
+                // Output Formatting
+                EnableLanguageFormatting = existingConfig.EnableLanguageFormatting,
+                FormattingLanguage = existingConfig.FormattingLanguage,
+                AddFinalPunctuation = existingConfig.AddFinalPunctuation,
+                EnableNumberFormatting = existingConfig.EnableNumberFormatting,
+                EnableProfanityFilter = existingConfig.EnableProfanityFilter,
+                OutputCasing = existingConfig.OutputCasing
             };
 
             // If we have a custom models directory set in the wizard (that might differ from auto-discovery),
@@ -182,6 +254,23 @@ namespace EliteWhisper.Services
             SaveConfiguration(_currentConfig);
         }
 
+        /// <summary>
+        /// Overwrites every setting with the given configuration (used when restoring a backup).
+        /// Values are copied into the live object so services holding it see the change.
+        /// </summary>
+        public void ReplaceConfiguration(WhisperConfiguration config)
+        {
+            foreach (var property in typeof(WhisperConfiguration).GetProperties())
+            {
+                if (!property.CanRead || !property.CanWrite) continue;
+                if (property.IsDefined(typeof(System.Text.Json.Serialization.JsonIgnoreAttribute), false)) continue;
+
+                property.SetValue(_currentConfig, property.GetValue(config));
+            }
+
+            SaveConfiguration(_currentConfig);
+        }
+
         /// <summary>
         /// Set the default model to use
         /// </summary>
@@ -194,6 +283,29 @@ namespace EliteWhisper.Services
             SaveConfiguration(_currentConfig);
         }
 
+        /// <summary>
+        /// Make a Sherpa model directory the active speech engine.
+        /// A non-Auto model type bypasses detection; its required files must be present.
+        /// </summary>
+        public void SetActiveSherpaModel(string modelDirectory, SherpaModelType modelType = SherpaModelType.Auto)
+        {
+            if (!Directory.Exists(modelDirectory))
+                throw new DirectoryNotFoundException($"Model directory not found: {modelDirectory}");
+
+            if (modelType != SherpaModelType.Auto)
+            {
+                var missing = Speech.SherpaOnnxEngine.GetMissingFiles(modelDirectory, modelType);
+                if (missing.Count > 0)
+                    throw new InvalidOperationException($"Not a {modelType} model. Missing: {string.Join(", ", missing)}");
+            }
+
+            _currentConfig.SherpaModelType = modelType;
+            _currentConfig.PreferredSTTEngine = "Sherpa";
+            _currentConfig.AutoSelectSTT = false;
+            _currentConfig.DefaultModelPath = modelDirectory; // Crucial for UI resolving ActiveModelId
+            SaveConfiguration(_currentConfig);
+        }
+
         /// <summary>
         /// Set the custom history storage path
         /// </summary>
@@ -215,6 +327,478 @@ namespace EliteWhisper.Services
             SaveConfiguration(_currentConfig);
         }
 
+        /// <summary>
+        /// Record whether history.json is encrypted. HistoryService rewrites the file; call it rather than this directly.
+        /// </summary>
+        public void SetHistoryEncryption(bool enabled)
+        {
+            _currentConfig.EncryptHistory = enabled;
+            SaveConfiguration(_currentConfig);
+        }
+
+        /// <summary>
+        /// Set which history items are kept: all, the last N items, or the last N days
+        /// </summary>
+        public void SetHistoryRetention(HistoryRetentionMode mode, int limit)
+        {
+            if (!Enum.IsDefined(mode))
+                throw new ArgumentOutOfRangeException(nameof(mode));
+            if (mode != HistoryRetentionMode.Unlimited && limit < 1)
+                throw new ArgumentOutOfRangeException(nameof(limit), "Keep at least one item or one day of history.");
+
+            _currentConfig.HistoryRetentionMode = mode;
+            _currentConfig.HistoryRetentionLimit = mode == HistoryRetentionMode.Unlimited ? 0 : limit;
+            SaveConfiguration(_currentConfig);
+        }
+
+        /// <summary>
+        /// Set whether recordings are kept for playback and how much space they may take (0 = no limit)
+        /// </summary>
+        public void SetSaveRecordings(bool enabled, int storageCapMB)
+        {
+            if (storageCapMB < 0)
+                throw new ArgumentOutOfRangeException(nameof(storageCapMB), "Storage limit must be 0 (no limit) or a positive number of MB.");
+
+            _currentConfig.SaveRecordings = enabled;
+            _currentConfig.RecordingStorageCapMB = storageCapMB;
+            SaveConfiguration(_currentConfig);
+        }
+
+        /// <summary>
+        /// Set how long saved recordings are kept (0 = forever)
+        /// </summary>
+        public void SetRecordingRetentionDays(int days)
+        {
+            if (days < 0)
+                throw new ArgumentOutOfRangeException(nameof(days), "Retention must be 0 (keep forever) or a positive number of days.");
+
+            _currentConfig.RecordingRetentionDays = days;
+            SaveConfiguration(_currentConfig);
+        }
+
+        /// <summary>
+        /// Keep or delete recordings whose transcription failed
+        /// </summary>
+        public void SetKeepFailedRecordings(bool enabled)
+        {
+            _currentConfig.KeepFailedRecordings = enabled;
+            SaveConfiguration(_currentConfig);
+        }
+
+        /// <summary>
+        /// Persist the microphone to record from. Null = Windows default device.
+        /// </summary>
+        public void SetInputDevice(string? deviceName)
+        {
+            _currentConfig.InputDeviceName = string.IsNullOrWhiteSpace(deviceName) ? null : deviceName;
+            SaveConfiguration(_currentConfig);
+        }
+
+        /// <summary>
+        /// Set the pre-roll length in milliseconds (0..2000, 0 = off)
+        /// </summary>
+        public void SetPreRoll(int milliseconds)
+        {
+            if (milliseconds < 0 || milliseconds > 2000)
+                throw new ArgumentOutOfRangeException(nameof(milliseconds), "Pre-roll must be between 0 and 2000 ms.");
+
+            _currentConfig.PreRollMs = milliseconds;
+            SaveConfiguration(_currentConfig);
+        }
+
+        /// <summary>
+        /// Remember where the widget was dragged to. Null clears it so the widget recenters.
+        /// </summary>
+        public void SetWidgetPosition(double? left, double? top)
+        {
+            if ((left.HasValue && !double.IsFinite(left.Value)) || (top.HasValue && !double.IsFinite(top.Value)))
+                throw new ArgumentOutOfRangeException(nameof(left), "Widget position must be a finite number.");
+
+            _currentConfig.WidgetLeft = left;
+            _currentConfig.WidgetTop = top;
+            SaveConfiguration(_currentConfig);
+        }
+
+        /// <summary>
+        /// Persist the global dictation shortcut
+        /// </summary>
+        public void SetHotkey(uint virtualKey, uint modifiers)
+        {
+            _currentConfig.HotkeyVirtualKey = virtualKey;
+            _currentConfig.HotkeyModifiers = modifiers;
+            SaveConfiguration(_currentConfig);
+        }
+
+        /// <summary>
+        /// Set how transcripts are delivered and whether pasting preserves the clipboard
+        /// </summary>
+        public void SetOutputAction(OutputAction action, bool restoreClipboard)
+        {
+            if (!Enum.IsDefined(action))
+                throw new ArgumentOutOfRangeException(nameof(action));
+
+            _currentConfig.OutputAction = action;
+            _currentConfig.RestoreClipboardAfterPaste = restoreClipboard;
+            SaveConfiguration(_currentConfig);
+        }
+
+        /// <summary>
+        /// Set the shortcut of an action; a virtual key of 0 removes it. The dictation shortcut is set with SetHotkey.
+        /// </summary>
+        public void SetActionHotkey(HotkeyAction action, uint virtualKey, uint modifiers)
+        {
+            if (!Enum.IsDefined(action) || action == HotkeyAction.ToggleRecording)
+                throw new ArgumentOutOfRangeException(nameof(action));
+
+            _currentConfig.ActionHotkeys ??= new System.Collections.Generic.List<HotkeyBinding>();
+            _currentConfig.ActionHotkeys.RemoveAll(b => b.Action == action);
+            if (virtualKey != 0)
+            {
+                _currentConfig.ActionHotkeys.Add(new HotkeyBinding { Action = action, VirtualKey = virtualKey, Modifiers = modifiers });
+            }
+            SaveConfiguration(_currentConfig);
+        }
+
+        /// <summary>
+        /// Set whether the shortcut toggles recording or works as push-to-talk
+        /// </summary>
+        public void SetHotkeyMode(HotkeyMode mode)
+        {
+            if (!Enum.IsDefined(mode))
+                throw new ArgumentOutOfRangeException(nameof(mode));
+
+            _currentConfig.HotkeyMode = mode;
+            SaveConfiguration(_currentConfig);
+        }
+
+        /// <summary>
+        /// Set whether toggle-mode recordings stop by themselves after a stretch of silence
+        /// </summary>
+        public void SetAutoStopOnSilence(bool enabled, int seconds)
+        {
+            if (seconds < 1 || seconds > 60)
+                throw new ArgumentOutOfRangeException(nameof(seconds), "Silence duration must be between 1 and 60 seconds.");
+
+            _currentConfig.AutoStopOnSilence = enabled;
+            _currentConfig.AutoStopSilenceSeconds = seconds;
+            SaveConfiguration(_currentConfig);
+        }
+
+        /// <summary>
+        /// Set the spoken language ("auto" or an ISO 639-1 code) used by Whisper and Sherpa Whisper models
+        /// </summary>
+        public void SetTranscriptionLanguage(string language)
+        {
+            string normalized = (language ?? string.Empty).Trim().ToLowerInvariant();
+            if (normalized != "auto" && !System.Text.RegularExpressions.Regex.IsMatch(normalized, "^[a-z]{2,3}$"))
+                throw new ArgumentException($"'{language}' is not a language code.", nameof(language));
+
+            _currentConfig.TranscriptionLanguage = normalized;
+            SaveConfiguration(_currentConfig);
+        }
+
+        /// <summary>
+        /// Set whether Whisper models transcribe or translate to English
+        /// </summary>
+        public void SetWhisperTask(WhisperTask task)
+        {
+            if (!Enum.IsDefined(task))
+                throw new ArgumentOutOfRangeException(nameof(task));
+
+            _currentConfig.WhisperTask = task;
+            SaveConfiguration(_currentConfig);
+        }
+
+        /// <summary>
+        /// Enable or disable raising quiet recordings and set the target peak level
+        /// </summary>
+        public void SetNormalization(bool enabled, double targetDb)
+        {
+            if (targetDb < -20.0 || targetDb > 0.0)
+                throw new ArgumentOutOfRangeException(nameof(targetDb), "Target level must be between -20 and 0 dBFS.");
+
+            _currentConfig.EnableNormalization = enabled;
+            _currentConfig.NormalizationTargetDb = targetDb;
+            SaveConfiguration(_currentConfig);
+        }
+
+        /// <summary>
+        /// Set the shortest audio that is sent to the model (0 = no minimum)
+        /// </summary>
+        public void SetMinAudioDuration(int milliseconds)
+        {
+            if (milliseconds < 0 || milliseconds > 5000)
+                throw new ArgumentOutOfRangeException(nameof(milliseconds), "Minimum audio length must be between 0 and 5000 ms.");
+
+            _currentConfig.MinAudioDurationMs = milliseconds;
+            SaveConfiguration(_currentConfig);
+        }
+
+        /// <summary>
+        /// Set whether silence is trimmed before transcription and the speech level threshold
+        /// </summary>
+        public void SetVoiceActivityDetection(bool enabled, double thresholdDb)
+        {
+            if (thresholdDb < -80.0 || thresholdDb > -10.0)
+                throw new ArgumentOutOfRangeException(nameof(thresholdDb), "Threshold must be between -80 and -10 dBFS.");
+
+            _currentConfig.EnableVoiceActivityDetection = enabled;
+            _currentConfig.VoiceActivityThresholdDb = thresholdDb;
+            SaveConfiguration(_currentConfig);
+        }
+
+        /// <summary>
+        /// Allow or disable GPU acceleration for transcription
+        /// </summary>
+        public void SetGpuAcceleration(bool enabled)
+        {
+            _currentConfig.EnableGPUAcceleration = enabled;
+            SaveConfiguration(_currentConfig);
+        }
+
+        /// <summary>
+        /// Set how many CPU threads transcription uses. Values below 1 are clamped to 1.
+        /// </summary>
+        public void SetTranscriptionThreads(int threads)
+        {
+            threads = Math.Max(1, threads);
+            if (threads > Environment.ProcessorCount)
+            {
+                System.Diagnostics.Debug.WriteLine(
+                    $"[Config] {threads} transcription threads exceeds the {Environment.ProcessorCount} logical cores; expect slower transcription.");
+            }
+
+            _currentConfig.TranscriptionThreads = threads;
+            SaveConfiguration(_currentConfig);
+        }
+
+        /// <summary>
+        /// Set the decoding strategy and the beam size used by beam search
+        /// </summary>
+        public void SetDecodingStrategy(DecodingStrategy strategy, int beamSize)
+        {
+            if (!Enum.IsDefined(strategy))
+                throw new ArgumentOutOfRangeException(nameof(strategy));
+
+            if (beamSize < 1 || beamSize > 16)
+                throw new ArgumentOutOfRangeException(nameof(beamSize), "Beam size must be between 1 and 16.");
+
+            _currentConfig.DecodingStrategy = strategy;
+            _currentConfig.BeamSize = beamSize;
+            SaveConfiguration(_currentConfig);
+        }
+
+        /// <summary>
+        /// Set how many loaded models are kept in memory (1..8). Use SpeechEngineSelector.SetModelCacheSize
+        /// to also release models above the new limit straight away.
+        /// </summary>
+        public void SetModelCacheSize(int size)
+        {
+            if (size < 1 || size > 8)
+                throw new ArgumentOutOfRangeException(nameof(size), "Model cache size must be between 1 and 8.");
+
+            _currentConfig.ModelCacheSize = size;
+            SaveConfiguration(_currentConfig);
+        }
+
+        /// <summary>
+        /// Enable or disable the warm-up run after a model loads
+        /// </summary>
+        public void SetWarmUpModelOnLoad(bool enabled)
+        {
+            _currentConfig.WarmUpModelOnLoad = enabled;
+            SaveConfiguration(_currentConfig);
+        }
+
+        /// <summary>
+        /// Enable or disable live streaming transcription and set its model folder
+        /// </summary>
+        public void SetStreaming(bool enabled, string? modelDirectory)
+        {
+            if (enabled)
+            {
+                if (string.IsNullOrEmpty(modelDirectory) || !Directory.Exists(modelDirectory))
+                    throw new ArgumentException("Select a streaming model folder first.", nameof(modelDirectory));
+
+                var missing = Speech.SherpaOnnxEngine.GetMissingFiles(modelDirectory, SherpaModelType.Transducer);
+                if (missing.Count > 0)
+                    throw new ArgumentException($"Not a streaming transducer model. Missing: {string.Join(", ", missing)}", nameof(modelDirectory));
+            }
+
+            _currentConfig.EnableStreaming = enabled;
+            _currentConfig.StreamingModelPath = modelDirectory;
+            SaveConfiguration(_currentConfig);
+        }
+
+        /// <summary>
+        /// Configure speaker diarization. speakerCount 0 = detect the number of speakers automatically.
+        /// </summary>
+        public void SetDiarization(bool enabled, string? segmentationModel, string? embeddingModel, int speakerCount)
+        {
+            if (speakerCount < 0 || speakerCount > 20)
+                throw new ArgumentOutOfRangeException(nameof(speakerCount), "Speaker count must be between 0 (auto) and 20.");
+
+            if (enabled)
+            {
+                if (string.IsNullOrEmpty(segmentationModel) || !File.Exists(segmentationModel))
+                    throw new ArgumentException("Select a speaker segmentation model first.", nameof(segmentationModel));
+                if (string.IsNullOrEmpty(embeddingModel) || !File.Exists(embeddingModel))
+                    throw new ArgumentException("Select a speaker embedding model first.", nameof(embeddingModel));
+            }
+
+            _currentConfig.EnableDiarization = enabled;
+            _currentConfig.DiarizationSegmentationModel = segmentationModel;
+            _currentConfig.DiarizationEmbeddingModel = embeddingModel;
+            _currentConfig.DiarizationSpeakerCount = speakerCount;
+            SaveConfiguration(_currentConfig);
+        }
+
+        /// <summary>
+        /// Set the Whisper decoding options (best-of candidates and sampling temperature)
+        /// </summary>
+        public void SetWhisperDecoding(int bestOf, double temperature)
+        {
+            if (bestOf < 1)
+                throw new ArgumentOutOfRangeException(nameof(bestOf), "Best-of must be at least 1.");
+
+            if (temperature < 0.0 || temperature > 1.0)
+                throw new ArgumentOutOfRangeException(nameof(temperature), "Temperature must be between 0 and 1.");
+
+            _currentConfig.WhisperBestOf = bestOf;
+            _currentConfig.WhisperTemperature = temperature;
+            SaveConfiguration(_currentConfig);
+        }
+
+        /// <summary>
+        /// Set the Whisper temperature fallback: the step added per retry (0 = off) and the thresholds that trigger a retry
+        /// </summary>
+        public void SetWhisperFallback(double temperatureIncrement, double entropyThreshold, double logProbThreshold, double noSpeechThreshold)
+        {
+            if (temperatureIncrement < 0.0 || temperatureIncrement > 1.0)
+                throw new ArgumentOutOfRangeException(nameof(temperatureIncrement), "Temperature increment must be between 0 and 1.");
+
+            if (entropyThreshold < 0.0)
+                throw new ArgumentOutOfRangeException(nameof(entropyThreshold), "Entropy threshold can't be negative.");
+
+            if (logProbThreshold > 0.0)
+                throw new ArgumentOutOfRangeException(nameof(logProbThreshold), "Log probability threshold must be 0 or below.");
+
+            if (noSpeechThreshold < 0.0 || noSpeechThreshold > 1.0)
+                throw new ArgumentOutOfRangeException(nameof(noSpeechThreshold), "No-speech threshold must be between 0 and 1.");
+
+            _currentConfig.WhisperTemperatureIncrement = temperatureIncrement;
+            _currentConfig.WhisperEntropyThreshold = entropyThreshold;
+            _currentConfig.WhisperLogProbThreshold = logProbThreshold;
+            _currentConfig.WhisperNoSpeechThreshold = noSpeechThreshold;
+            SaveConfiguration(_currentConfig);
+        }
+
+        /// <summary>
+        /// Set which tokens Whisper is not allowed to emit
+        /// </summary>
+        public void SetWhisperSuppression(bool suppressNonSpeechTokens, string? suppressRegex)
+        {
+            if (!string.IsNullOrWhiteSpace(suppressRegex))
+            {
+                try
+                {
+                    _ = new System.Text.RegularExpressions.Regex(suppressRegex);
+                }
+                catch (ArgumentException ex)
+                {
+                    throw new ArgumentException($"Invalid suppress pattern: {ex.Message}", nameof(suppressRegex), ex);
+                }
+            }
+
+            _currentConfig.WhisperSuppressNonSpeechTokens = suppressNonSpeechTokens;
+            _currentConfig.WhisperSuppressRegex = string.IsNullOrWhiteSpace(suppressRegex) ? null : suppressRegex;
+            SaveConfiguration(_currentConfig);
+        }
+
+        /// <summary>
+        /// Set the text that primes the Whisper decoder, and whether the previous transcript is added to it
+        /// </summary>
+        public void SetWhisperPrompt(string? initialPrompt, bool includePreviousTranscript)
+        {
+            _currentConfig.WhisperInitialPrompt = string.IsNullOrWhiteSpace(initialPrompt) ? null : initialPrompt.Trim();
+            _currentConfig.WhisperPromptWithPreviousTranscript = includePreviousTranscript;
+            SaveConfiguration(_currentConfig);
+        }
+
+        /// <summary>
+        /// Set the minimum transcript quality and whether low-quality results are kept from being typed
+        /// </summary>
+        public void SetQualityThreshold(double minQuality, bool suppressOutput)
+        {
+            if (minQuality < 0.0 || minQuality > 1.0)
+                throw new ArgumentOutOfRangeException(nameof(minQuality), "Quality threshold must be between 0 and 1.");
+
+            _currentConfig.MinTranscriptQuality = minQuality;
+            _currentConfig.SuppressLowQualityOutput = suppressOutput;
+            SaveConfiguration(_currentConfig);
+        }
+
+        /// <summary>
+        /// Set the no-speech probability at which a recording is flagged as dead air (0 = off)
+        /// </summary>
+        public void SetNoSpeechWarningThreshold(double threshold)
+        {
+            if (threshold < 0.0 || threshold > 1.0)
+                throw new ArgumentOutOfRangeException(nameof(threshold), "No-speech threshold must be between 0 and 1.");
+
+            _currentConfig.NoSpeechWarningThreshold = threshold;
+            SaveConfiguration(_currentConfig);
+        }
+
+        /// <summary>
+        /// Set the language-aware output formatting options
+        /// </summary>
+        public void SetLanguageFormatting(bool enabled, string? language)
+        {
+            if (!string.IsNullOrEmpty(language) && !Speech.LanguageFormattingRules.IsSupported(language))
+                throw new ArgumentException($"No formatting rules for language '{language}'.", nameof(language));
+
+            _currentConfig.EnableLanguageFormatting = enabled;
+            _currentConfig.FormattingLanguage = string.IsNullOrEmpty(language) ? null : language.ToLowerInvariant();
+            SaveConfiguration(_currentConfig);
+        }
+
+        /// <summary>
+        /// Turn the closing period for unpunctuated transcripts on or off
+        /// </summary>
+        public void SetFinalPunctuation(bool enabled)
+        {
+            _currentConfig.AddFinalPunctuation = enabled;
+            SaveConfiguration(_currentConfig);
+        }
+
+        /// <summary>
+        /// Turn spoken-number formatting on or off
+        /// </summary>
+        public void SetNumberFormatting(bool enabled)
+        {
+            _currentConfig.EnableNumberFormatting = enabled;
+            SaveConfiguration(_currentConfig);
+        }
+
+        /// <summary>
+        /// Turn profanity masking on or off
+        /// </summary>
+        public void SetProfanityFilter(bool enabled)
+        {
+            _currentConfig.EnableProfanityFilter = enabled;
+            SaveConfiguration(_currentConfig);
+        }
+
+        /// <summary>
+        /// Set the letter case applied to transcripts
+        /// </summary>
+        public void SetOutputCasing(OutputCasing casing)
+        {
+            _currentConfig.OutputCasing = casing;
+            SaveConfiguration(_currentConfig);
+        }
+
         /// <summary>
         /// Load configuration from disk
         /// </summary>
@@ -225,7 +809,21 @@ namespace EliteWhisper.Services
                 if (File.Exists(_configFilePath))
                 {
                     string json = File.ReadAllText(_configFilePath);
-                    var config = JsonSerializer.Deserialize<WhisperConfiguration>(json);
+                    WhisperConfiguration? config;
+                    try
+                    {
+                        var node = JsonNode.Parse(json) as JsonObject
+                            ?? throw new JsonException("Settings file is not a JSON object.");
+                        MigrateConfiguration(node);
+                        config = node.Deserialize<WhisperConfiguration>();
+                    }
+                    catch (JsonException ex)
+                    {
+                        // Keep the unreadable file for recovery; defaults are saved over it otherwise
+                        System.Diagnostics.Debug.WriteLine($"Config file is corrupt, starting from defaults: {ex.Message}");
+                        BackUpConfigFile("corrupt");
+                        config = null;
+                    }
                     
                     if (config != null)
                     {
@@ -246,6 +844,46 @@ namespace EliteWhisper.Services
             return new WhisperConfiguration();
         }
 
+        /// <summary>
+        /// Upgrades the raw settings JSON to the current schema before it's deserialized,
+        /// so renamed or reinterpreted settings from older versions are kept.
+        /// </summary>
+        private void MigrateConfiguration(JsonObject json)
+        {
+            int version = json["SchemaVersion"] is JsonValue value && value.TryGetValue(out int v) ? v : 0;
+
+            if (version > WhisperConfiguration.CurrentSchemaVersion)
+            {
+                // Saving drops settings this version doesn't know, so keep the newer file around
+                System.Diagnostics.Debug.WriteLine($"Config schema {version} is newer than {WhisperConfiguration.CurrentSchemaVersion}; loading what is understood");
+                BackUpConfigFile($"v{version}");
+                return;
+            }
+
+            // 0 -> 1: files from before versioning have the current layout; only the version is added.
+            // Later steps go here as "if (version < N) { ... }", oldest first.
+
+            if (version != WhisperConfiguration.CurrentSchemaVersion)
+            {
+                System.Diagnostics.Debug.WriteLine($"Migrated config schema {version} -> {WhisperConfiguration.CurrentSchemaVersion}");
+            }
+            json["SchemaVersion"] = WhisperConfiguration.CurrentSchemaVersion;
+        }
+
+        private void BackUpConfigFile(string suffix)
+        {
+            try
+            {
+                string backupPath = Path.ChangeExtension(_configFilePath, $".{suffix}.json");
+                File.Copy(_configFilePath, backupPath, overwrite: true);
+                System.Diagnostics.Debug.WriteLine($"Config backed up to {backupPath}");
+            }
+            catch (Exception ex)
+            {
+                System.Diagnostics.Debug.WriteLine($"Failed to back up config: {ex.Message}");
+            }
+        }
+
         /// <summary>
         /// Save configuration to disk
         /// </summary>
@@ -256,9 +894,15 @@ namespace EliteWhisper.Services
                 // Ensure keys are encrypted before saving
                 EncryptApiKeys(config);
 
+                config.SchemaVersion = WhisperConfiguration.CurrentSchemaVersion;
+
                 var options = new JsonSerializerOptions { WriteIndented = true };
                 string json = JsonSerializer.Serialize(config, options);
-                File.WriteAllText(_configFilePath, json);
+
+                // Write then swap, so a crash mid-write can't leave a truncated settings file
+                string tempPath = _configFilePath + ".tmp";
+                File.WriteAllText(tempPath, json);
+                File.Move(tempPath, _configFilePath, overwrite: true);
             }
             catch (Exception ex)
             {
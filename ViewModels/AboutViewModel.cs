@@ -1,7 +1,12 @@
 using System;
 using System.Runtime.InteropServices;
 using System.Reflection;
+using System.Threading;
+using System.Threading.Tasks;
+using System.Windows;
 using CommunityToolkit.Mvvm.ComponentModel;
+using CommunityToolkit.Mvvm.Input;
+using EliteWhisper.Services.Speech;
 
 namespace EliteWhisper.ViewModels
 {
@@ -25,8 +30,18 @@ namespace EliteWhisper.ViewModels
         [ObservableProperty]
         private string _licenseDescription;
 
-        public AboutViewModel()
+        [ObservableProperty]
+        private bool _isSelfTestRunning;
+
+        [ObservableProperty]
+        private bool _isEvaluationRunning;
+
+        private readonly SpeechRecognitionService _speechService;
+
+        public AboutViewModel(SpeechRecognitionService speechService)
         {
+            _speechService = speechService;
+
             // App Version
             var assembly = Assembly.GetExecutingAssembly();
             var version = assembly.GetName().Version;
@@ -42,6 +57,75 @@ namespace EliteWhisper.ViewModels
             _licenseDescription = "Elite Whisper is licensed under a Commercial End User License Agreement (EULA) with an annual subscription fee. By using this software, you agree to the terms of the EULA. Third-party components are used under their respective open-source licenses (MIT/Apache).";
         }
 
+        [RelayCommand]
+        private async Task RunSelfTest()
+        {
+            if (IsSelfTestRunning) return;
+
+            IsSelfTestRunning = true;
+            try
+            {
+                var report = await _speechService.RunSelfTestAsync(CancellationToken.None);
+                MessageBox.Show(report.ToString(), "Pipeline Self-Test", MessageBoxButton.OK,
+                    report.Passed ? MessageBoxImage.Information : MessageBoxImage.Warning);
+            }
+            catch (Exception ex)
+            {
+                MessageBox.Show($"Self-test could not run: {ex.Message}", "Pipeline Self-Test", MessageBoxButton.OK, MessageBoxImage.Error);
+            }
+            finally
+            {
+                IsSelfTestRunning = false;
+            }
+        }
+
+        /// <summary>
+        /// Transcribes a recording the user has a correct transcript for and reports WER/CER,
+        /// for comparing models on their own audio. The reference is a .txt file, by default
+        /// the one next to the recording with the same name.
+        /// </summary>
+        [RelayCommand]
+        private async Task EvaluateAccuracy()
+        {
+            if (IsEvaluationRunning) return;
+
+            var audioDialog = new Microsoft.Win32.OpenFileDialog
+            {
+                Title = "Choose a recording to evaluate",
+                Filter = "Audio files (*.wav;*.mp3;*.m4a;*.flac;*.ogg)|*.wav;*.mp3;*.m4a;*.flac;*.ogg|All files (*.*)|*.*"
+            };
+            if (audioDialog.ShowDialog() != true) return;
+
+            string referencePath = System.IO.Path.ChangeExtension(audioDialog.FileName, ".txt");
+            if (!System.IO.File.Exists(referencePath))
+            {
+                var referenceDialog = new Microsoft.Win32.OpenFileDialog
+                {
+                    Title = "Choose the correct transcript for this recording",
+                    Filter = "Text files (*.txt)|*.txt|All files (*.*)|*.*"
+                };
+                if (referenceDialog.ShowDialog() != true) return;
+                referencePath = referenceDialog.FileName;
+            }
+
+            IsEvaluationRunning = true;
+            try
+            {
+                string reference = await System.IO.File.ReadAllTextAsync(referencePath);
+                var result = await _speechService.EvaluateAsync(audioDialog.FileName, reference, CancellationToken.None);
+                MessageBox.Show($"Engine: {result.EngineName}\n{result}\n\nTranscript:\n{result.Hypothesis}",
+                    "Accuracy", MessageBoxButton.OK, MessageBoxImage.Information);
+            }
+            catch (Exception ex)
+            {
+                MessageBox.Show($"Evaluation failed: {ex.Message}", "Accuracy", MessageBoxButton.OK, MessageBoxImage.Error);
+            }
+            finally
+            {
+                IsEvaluationRunning = false;
+            }
+        }
+
         private string GetFriendlyOSName()
         {
             try
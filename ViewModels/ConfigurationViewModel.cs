@@ -1,5 +1,6 @@
 using CommunityToolkit.Mvvm.ComponentModel;
 using CommunityToolkit.Mvvm.Input;
+using EliteWhisper.Models;
 using EliteWhisper.Services;
 using System;
 using System.IO;
@@ -15,6 +16,9 @@ namespace EliteWhisper.ViewModels
 
         private readonly HistoryService _historyService;
         private readonly IUpdateService _updateService;
+        private readonly ConfigBackupService _backupService;
+        private readonly Services.Speech.SpeechEngineSelector _engineSelector;
+        private readonly Services.Speech.HotwordsService _hotwords;
         private Action? _requestFocus;
 
         [ObservableProperty]
@@ -23,23 +27,599 @@ namespace EliteWhisper.ViewModels
         [ObservableProperty]
         private bool _isCapturing;
 
+        [ObservableProperty]
+        private string _clipboardHotkeyDisplay = "Not set";
+
+        [ObservableProperty]
+        private string _cancelHotkeyDisplay = "Not set";
+
+        [ObservableProperty]
+        private string _retypeHotkeyDisplay = "Not set";
+
+        [ObservableProperty]
+        private string _recopyHotkeyDisplay = "Not set";
+
+        [ObservableProperty]
+        private bool _autoStopOnSilence;
+
+        [ObservableProperty]
+        private int _autoStopSilenceSeconds = 3;
+
+        // Which action the next captured key press is assigned to
+        private HotkeyAction _capturingAction = HotkeyAction.ToggleRecording;
+
         [ObservableProperty]
         private string _historyPath = string.Empty;
 
-        public ConfigurationViewModel(HotkeyService hotkeyService, WhisperConfigurationService configService, HistoryService historyService, IUpdateService updateService)
+        [ObservableProperty]
+        private bool _encryptHistory;
+
+        [ObservableProperty]
+        private bool _keepFailedRecordings;
+
+        // "unlimited", "count:N" or "days:N"
+        [ObservableProperty]
+        private string _historyRetention = "unlimited";
+
+        [ObservableProperty]
+        private bool _saveRecordings;
+
+        /// <summary>
+        /// Hotwords as edited on the page, one per line ("phrase" or "phrase :score").
+        /// </summary>
+        [ObservableProperty]
+        private string _hotwordsText = string.Empty;
+
+        [ObservableProperty]
+        private double _minTranscriptQuality;
+
+        [ObservableProperty]
+        private bool _suppressLowQualityOutput;
+
+        [ObservableProperty]
+        private int _recordingStorageCapMB;
+
+        [ObservableProperty]
+        private string _transcriptionLanguage = "auto";
+
+        [ObservableProperty]
+        private bool _translateToEnglish;
+
+        [ObservableProperty]
+        private bool _useBeamSearch;
+
+        [ObservableProperty]
+        private int _whisperBestOf;
+
+        [ObservableProperty]
+        private double _whisperTemperature;
+
+        [ObservableProperty]
+        private bool _retryUnclearSegments;
+
+        [ObservableProperty]
+        private bool _warmUpModelOnLoad;
+
+        [ObservableProperty]
+        private string _whisperInitialPrompt = string.Empty;
+
+        [ObservableProperty]
+        private bool _promptWithPreviousTranscript;
+
+        [ObservableProperty]
+        private bool _suppressNonSpeechTokens;
+
+        [ObservableProperty]
+        private string _suppressRegex = string.Empty;
+
+        [ObservableProperty]
+        private bool _enableStreaming;
+
+        [ObservableProperty]
+        private string _streamingModelPath = string.Empty;
+
+        [ObservableProperty]
+        private bool _enableDiarization;
+
+        [ObservableProperty]
+        private string _diarizationSegmentationModel = string.Empty;
+
+        [ObservableProperty]
+        private string _diarizationEmbeddingModel = string.Empty;
+
+        [ObservableProperty]
+        private int _diarizationSpeakerCount;
+
+        [ObservableProperty]
+        private bool _enableLanguageFormatting;
+
+        [ObservableProperty]
+        private bool _addFinalPunctuation;
+
+        [ObservableProperty]
+        private bool _enableNumberFormatting;
+
+        [ObservableProperty]
+        private bool _enableProfanityFilter;
+
+        [ObservableProperty]
+        private bool _isPushToTalk;
+
+        [ObservableProperty]
+        private OutputAction _outputAction;
+
+        [ObservableProperty]
+        private bool _restoreClipboardAfterPaste;
+
+        [ObservableProperty]
+        private OutputCasing _outputCasing;
+
+        [ObservableProperty]
+        private string _engineDescription = string.Empty;
+
+        [ObservableProperty]
+        private bool _engineSupportsTranslate = true;
+
+        [ObservableProperty]
+        private bool _engineSupportsBeamSearch = true;
+
+        [ObservableProperty]
+        private bool _engineSupportsPrompt = true;
+
+        public ConfigurationViewModel(HotkeyService hotkeyService, WhisperConfigurationService configService, HistoryService historyService, IUpdateService updateService, ConfigBackupService backupService,
+            Services.Speech.SpeechEngineSelector engineSelector, Services.Speech.HotwordsService hotwords)
         {
+            _engineSelector = engineSelector;
+            _hotwords = hotwords;
             _hotkeyService = hotkeyService;
             _configService = configService;
             _historyService = historyService;
             _updateService = updateService;
+            _backupService = backupService;
             
             UpdateHotkeyDisplay();
+            TranscriptionLanguage = _configService.CurrentConfiguration.TranscriptionLanguage;
+            TranslateToEnglish = _configService.CurrentConfiguration.WhisperTask == WhisperTask.Translate;
+            UseBeamSearch = _configService.CurrentConfiguration.DecodingStrategy == DecodingStrategy.BeamSearch;
+            WhisperBestOf = _configService.CurrentConfiguration.WhisperBestOf;
+            WhisperTemperature = _configService.CurrentConfiguration.WhisperTemperature;
+            RetryUnclearSegments = _configService.CurrentConfiguration.WhisperTemperatureIncrement > 0;
+            WarmUpModelOnLoad = _configService.CurrentConfiguration.WarmUpModelOnLoad;
+            KeepFailedRecordings = _configService.CurrentConfiguration.KeepFailedRecordings;
+            HistoryRetention = _configService.CurrentConfiguration.HistoryRetentionMode switch
+            {
+                HistoryRetentionMode.KeepCount => $"count:{_configService.CurrentConfiguration.HistoryRetentionLimit}",
+                HistoryRetentionMode.KeepDays => $"days:{_configService.CurrentConfiguration.HistoryRetentionLimit}",
+                _ => "unlimited"
+            };
+            SaveRecordings = _configService.CurrentConfiguration.SaveRecordings;
+            RecordingStorageCapMB = _configService.CurrentConfiguration.RecordingStorageCapMB;
+            WhisperInitialPrompt = _configService.CurrentConfiguration.WhisperInitialPrompt ?? string.Empty;
+            HotwordsText = string.Join(Environment.NewLine, _hotwords.GetHotwords().Select(h => h.ToLine()));
+            PromptWithPreviousTranscript = _configService.CurrentConfiguration.WhisperPromptWithPreviousTranscript;
+            SuppressNonSpeechTokens = _configService.CurrentConfiguration.WhisperSuppressNonSpeechTokens;
+            SuppressRegex = _configService.CurrentConfiguration.WhisperSuppressRegex ?? string.Empty;
+            EnableStreaming = _configService.CurrentConfiguration.EnableStreaming;
+            StreamingModelPath = _configService.CurrentConfiguration.StreamingModelPath ?? string.Empty;
+            EnableDiarization = _configService.CurrentConfiguration.EnableDiarization;
+            DiarizationSegmentationModel = _configService.CurrentConfiguration.DiarizationSegmentationModel ?? string.Empty;
+            DiarizationEmbeddingModel = _configService.CurrentConfiguration.DiarizationEmbeddingModel ?? string.Empty;
+            DiarizationSpeakerCount = _configService.CurrentConfiguration.DiarizationSpeakerCount;
+            EnableLanguageFormatting = _configService.CurrentConfiguration.EnableLanguageFormatting;
+            AddFinalPunctuation = _configService.CurrentConfiguration.AddFinalPunctuation;
+            EnableNumberFormatting = _configService.CurrentConfiguration.EnableNumberFormatting;
+            EnableProfanityFilter = _configService.CurrentConfiguration.EnableProfanityFilter;
+            IsPushToTalk = _configService.CurrentConfiguration.HotkeyMode == HotkeyMode.PushToTalk;
+            AutoStopOnSilence = _configService.CurrentConfiguration.AutoStopOnSilence;
+            MinTranscriptQuality = _configService.CurrentConfiguration.MinTranscriptQuality;
+            SuppressLowQualityOutput = _configService.CurrentConfiguration.SuppressLowQualityOutput;
+            AutoStopSilenceSeconds = _configService.CurrentConfiguration.AutoStopSilenceSeconds;
+            OutputAction = _configService.CurrentConfiguration.OutputAction;
+            RestoreClipboardAfterPaste = _configService.CurrentConfiguration.RestoreClipboardAfterPaste;
+            OutputCasing = _configService.CurrentConfiguration.OutputCasing;
+            
+            EncryptHistory = _configService.CurrentConfiguration.EncryptHistory;
             
             // Load initial history path
             HistoryPath = _configService.CurrentConfiguration.HistoryStoragePath 
                            ?? Path.Combine(Environment.GetFolderPath(Environment.SpecialFolder.LocalApplicationData), "EliteWhisper");
         }
 
+        partial void OnTranscriptionLanguageChanged(string value)
+        {
+            if (string.IsNullOrEmpty(value) || _configService.CurrentConfiguration.TranscriptionLanguage == value) return;
+
+            try
+            {
+                _configService.SetTranscriptionLanguage(value);
+            }
+            catch (Exception ex)
+            {
+                MessageBox.Show($"Error setting language: {ex.Message}", "Error");
+            }
+        }
+
+        partial void OnEncryptHistoryChanged(bool value)
+        {
+            if (_configService.CurrentConfiguration.EncryptHistory == value) return;
+
+            try
+            {
+                if (value)
+                {
+                    var dialog = new Views.PassphraseWindow { Owner = Application.Current.MainWindow };
+                    if (dialog.ShowDialog() != true)
+                    {
+                        EncryptHistory = false;
+                        return;
+                    }
+                    _historyService.EnableEncryption(dialog.Passphrase);
+                }
+                else
+                {
+                    _historyService.DisableEncryption();
+                }
+            }
+            catch (Exception ex)
+            {
+                MessageBox.Show($"Error changing history encryption: {ex.Message}", "Error");
+                EncryptHistory = _configService.CurrentConfiguration.EncryptHistory;
+            }
+        }
+
+        partial void OnOutputActionChanged(OutputAction value)
+        {
+            if (_configService.CurrentConfiguration.OutputAction != value)
+            {
+                _configService.SetOutputAction(value, RestoreClipboardAfterPaste);
+            }
+        }
+
+        partial void OnOutputCasingChanged(OutputCasing value)
+        {
+            if (_configService.CurrentConfiguration.OutputCasing != value)
+            {
+                _configService.SetOutputCasing(value);
+            }
+        }
+
+        partial void OnRestoreClipboardAfterPasteChanged(bool value)
+        {
+            if (_configService.CurrentConfiguration.RestoreClipboardAfterPaste != value)
+            {
+                _configService.SetOutputAction(OutputAction, value);
+            }
+        }
+
+        partial void OnIsPushToTalkChanged(bool value)
+        {
+            var mode = value ? HotkeyMode.PushToTalk : HotkeyMode.Toggle;
+            if (_configService.CurrentConfiguration.HotkeyMode != mode)
+            {
+                _configService.SetHotkeyMode(mode);
+            }
+        }
+
+        partial void OnMinTranscriptQualityChanged(double value)
+        {
+            if (_configService.CurrentConfiguration.MinTranscriptQuality != value)
+            {
+                _configService.SetQualityThreshold(value, SuppressLowQualityOutput);
+            }
+        }
+
+        partial void OnSuppressLowQualityOutputChanged(bool value)
+        {
+            if (_configService.CurrentConfiguration.SuppressLowQualityOutput != value)
+            {
+                _configService.SetQualityThreshold(MinTranscriptQuality, value);
+            }
+        }
+
+        partial void OnAutoStopOnSilenceChanged(bool value)
+        {
+            if (_configService.CurrentConfiguration.AutoStopOnSilence != value)
+            {
+                _configService.SetAutoStopOnSilence(value, AutoStopSilenceSeconds);
+            }
+        }
+
+        partial void OnAutoStopSilenceSecondsChanged(int value)
+        {
+            if (_configService.CurrentConfiguration.AutoStopSilenceSeconds != value)
+            {
+                _configService.SetAutoStopOnSilence(AutoStopOnSilence, value);
+            }
+        }
+
+        partial void OnTranslateToEnglishChanged(bool value)
+        {
+            var task = value ? WhisperTask.Translate : WhisperTask.Transcribe;
+            if (_configService.CurrentConfiguration.WhisperTask != task)
+            {
+                _configService.SetWhisperTask(task);
+            }
+        }
+
+        partial void OnUseBeamSearchChanged(bool value)
+        {
+            var strategy = value ? DecodingStrategy.BeamSearch : DecodingStrategy.Greedy;
+            if (_configService.CurrentConfiguration.DecodingStrategy != strategy)
+            {
+                _configService.SetDecodingStrategy(strategy, _configService.CurrentConfiguration.BeamSize);
+            }
+        }
+
+        partial void OnWhisperBestOfChanged(int value)
+        {
+            if (_configService.CurrentConfiguration.WhisperBestOf != value)
+            {
+                _configService.SetWhisperDecoding(value, WhisperTemperature);
+            }
+        }
+
+        partial void OnWhisperTemperatureChanged(double value)
+        {
+            if (_configService.CurrentConfiguration.WhisperTemperature != value)
+            {
+                _configService.SetWhisperDecoding(WhisperBestOf, value);
+            }
+        }
+
+        partial void OnRetryUnclearSegmentsChanged(bool value)
+        {
+            var config = _configService.CurrentConfiguration;
+            if ((config.WhisperTemperatureIncrement > 0) != value)
+            {
+                _configService.SetWhisperFallback(value ? 0.2 : 0.0,
+                    config.WhisperEntropyThreshold, config.WhisperLogProbThreshold, config.WhisperNoSpeechThreshold);
+            }
+        }
+
+        partial void OnHistoryRetentionChanged(string value)
+        {
+            var parts = (value ?? "unlimited").Split(':');
+            var mode = parts[0] switch
+            {
+                "count" => HistoryRetentionMode.KeepCount,
+                "days" => HistoryRetentionMode.KeepDays,
+                _ => HistoryRetentionMode.Unlimited
+            };
+            int limit = parts.Length > 1 && int.TryParse(parts[1], out int n) ? n : 0;
+
+            var config = _configService.CurrentConfiguration;
+            if (config.HistoryRetentionMode == mode && config.HistoryRetentionLimit == limit) return;
+
+            if (mode != HistoryRetentionMode.Unlimited &&
+                MessageBox.Show("Older history items, their transcript files and recordings will be deleted. Continue?",
+                    "History Retention", MessageBoxButton.YesNo, MessageBoxImage.Warning) != MessageBoxResult.Yes)
+            {
+                // Revert the selection after the binding update finishes
+                Application.Current.Dispatcher.BeginInvoke(() => HistoryRetention = config.HistoryRetentionMode switch
+                {
+                    HistoryRetentionMode.KeepCount => $"count:{config.HistoryRetentionLimit}",
+                    HistoryRetentionMode.KeepDays => $"days:{config.HistoryRetentionLimit}",
+                    _ => "unlimited"
+                });
+                return;
+            }
+
+            _configService.SetHistoryRetention(mode, limit);
+            _ = Task.Run(_historyService.ApplyRetentionPolicy);
+        }
+
+        partial void OnSaveRecordingsChanged(bool value)
+        {
+            if (_configService.CurrentConfiguration.SaveRecordings != value)
+            {
+                _configService.SetSaveRecordings(value, RecordingStorageCapMB);
+            }
+        }
+
+        partial void OnRecordingStorageCapMBChanged(int value)
+        {
+            if (_configService.CurrentConfiguration.RecordingStorageCapMB != value)
+            {
+                _configService.SetSaveRecordings(SaveRecordings, value);
+            }
+        }
+
+        partial void OnKeepFailedRecordingsChanged(bool value)
+        {
+            if (_configService.CurrentConfiguration.KeepFailedRecordings != value)
+            {
+                _configService.SetKeepFailedRecordings(value);
+            }
+        }
+
+        partial void OnWarmUpModelOnLoadChanged(bool value)
+        {
+            if (_configService.CurrentConfiguration.WarmUpModelOnLoad != value)
+            {
+                _configService.SetWarmUpModelOnLoad(value);
+            }
+        }
+
+        partial void OnWhisperInitialPromptChanged(string value)
+        {
+            if ((_configService.CurrentConfiguration.WhisperInitialPrompt ?? string.Empty) != value.Trim())
+            {
+                _configService.SetWhisperPrompt(value, PromptWithPreviousTranscript);
+            }
+        }
+
+        partial void OnPromptWithPreviousTranscriptChanged(bool value)
+        {
+            if (_configService.CurrentConfiguration.WhisperPromptWithPreviousTranscript != value)
+            {
+                _configService.SetWhisperPrompt(WhisperInitialPrompt, value);
+            }
+        }
+
+        partial void OnSuppressNonSpeechTokensChanged(bool value)
+        {
+            if (_configService.CurrentConfiguration.WhisperSuppressNonSpeechTokens != value)
+            {
+                _configService.SetWhisperSuppression(value, _configService.CurrentConfiguration.WhisperSuppressRegex);
+            }
+        }
+
+        partial void OnEnableStreamingChanged(bool value)
+        {
+            if (_configService.CurrentConfiguration.EnableStreaming == value) return;
+
+            try
+            {
+                string? path = string.IsNullOrEmpty(StreamingModelPath) ? null : StreamingModelPath;
+                if (value && path == null)
+                {
+                    path = PickStreamingModelFolder();
+                    if (path == null)
+                    {
+                        EnableStreaming = false;
+                        return;
+                    }
+                }
+
+                _configService.SetStreaming(value, path);
+                StreamingModelPath = path ?? string.Empty;
+            }
+            catch (Exception ex)
+            {
+                MessageBox.Show($"Error enabling live transcription: {ex.Message}", "Error");
+                EnableStreaming = _configService.CurrentConfiguration.EnableStreaming;
+            }
+        }
+
+        [RelayCommand]
+        private void BrowseStreamingModel()
+        {
+            string? path = PickStreamingModelFolder();
+            if (path == null) return;
+
+            try
+            {
+                _configService.SetStreaming(EnableStreaming, path);
+                StreamingModelPath = path;
+            }
+            catch (Exception ex)
+            {
+                MessageBox.Show($"Error setting streaming model: {ex.Message}", "Error");
+            }
+        }
+
+        private string? PickStreamingModelFolder()
+        {
+            using var dialog = new System.Windows.Forms.FolderBrowserDialog();
+            dialog.Description = "Select a Sherpa streaming model folder (encoder, decoder, joiner and tokens.txt)";
+            dialog.UseDescriptionForTitle = true;
+            dialog.SelectedPath = StreamingModelPath;
+
+            return dialog.ShowDialog() == System.Windows.Forms.DialogResult.OK ? dialog.SelectedPath : null;
+        }
+
+        partial void OnEnableDiarizationChanged(bool value)
+        {
+            if (_configService.CurrentConfiguration.EnableDiarization != value) SaveDiarization();
+        }
+
+        partial void OnDiarizationSpeakerCountChanged(int value)
+        {
+            if (_configService.CurrentConfiguration.DiarizationSpeakerCount != value) SaveDiarization();
+        }
+
+        [RelayCommand]
+        private void BrowseSegmentationModel()
+        {
+            string? path = PickOnnxModel("Select the speaker segmentation model (pyannote)");
+            if (path == null) return;
+            DiarizationSegmentationModel = path;
+            SaveDiarization();
+        }
+
+        [RelayCommand]
+        private void BrowseEmbeddingModel()
+        {
+            string? path = PickOnnxModel("Select the speaker embedding model");
+            if (path == null) return;
+            DiarizationEmbeddingModel = path;
+            SaveDiarization();
+        }
+
+        private void SaveDiarization()
+        {
+            try
+            {
+                _configService.SetDiarization(EnableDiarization,
+                    string.IsNullOrEmpty(DiarizationSegmentationModel) ? null : DiarizationSegmentationModel,
+                    string.IsNullOrEmpty(DiarizationEmbeddingModel) ? null : DiarizationEmbeddingModel,
+                    DiarizationSpeakerCount);
+            }
+            catch (Exception ex)
+            {
+                MessageBox.Show($"Error setting speaker labels: {ex.Message}", "Error");
+                EnableDiarization = _configService.CurrentConfiguration.EnableDiarization;
+                DiarizationSpeakerCount = _configService.CurrentConfiguration.DiarizationSpeakerCount;
+            }
+        }
+
+        private static string? PickOnnxModel(string title)
+        {
+            var dialog = new Microsoft.Win32.OpenFileDialog
+            {
+                Title = title,
+                Filter = "ONNX models (*.onnx)|*.onnx"
+            };
+
+            return dialog.ShowDialog() == true ? dialog.FileName : null;
+        }
+
+        partial void OnEnableLanguageFormattingChanged(bool value)
+        {
+            if (_configService.CurrentConfiguration.EnableLanguageFormatting != value)
+            {
+                _configService.SetLanguageFormatting(value, _configService.CurrentConfiguration.FormattingLanguage);
+            }
+        }
+
+        partial void OnAddFinalPunctuationChanged(bool value)
+        {
+            if (_configService.CurrentConfiguration.AddFinalPunctuation != value)
+            {
+                _configService.SetFinalPunctuation(value);
+            }
+        }
+
+        partial void OnEnableNumberFormattingChanged(bool value)
+        {
+            if (_configService.CurrentConfiguration.EnableNumberFormatting != value)
+            {
+                _configService.SetNumberFormatting(value);
+            }
+        }
+
+        partial void OnEnableProfanityFilterChanged(bool value)
+        {
+            if (_configService.CurrentConfiguration.EnableProfanityFilter != value)
+            {
+                _configService.SetProfanityFilter(value);
+            }
+        }
+
+        /// <summary>
+        /// Reads what the current speech engine supports, so options it would ignore are greyed out.
+        /// </summary>
+        public void RefreshEngineInfo()
+        {
+            var info = _engineSelector.GetEngineInfo();
+            EngineDescription = info.ModelName != null
+                ? $"Current engine: {info.Name}, {info.ModelName}. Options it doesn't support are greyed out."
+                : $"Current engine: {info.Name}. Options it doesn't support are greyed out.";
+            EngineSupportsTranslate = info.SupportsTranslate;
+            EngineSupportsBeamSearch = info.SupportsBeamSearch;
+            EngineSupportsPrompt = info.SupportsPrompt;
+        }
+
         public void SetFocusCallback(Action requestFocus)
         {
             _requestFocus = requestFocus;
@@ -48,8 +628,33 @@ namespace EliteWhisper.ViewModels
         [RelayCommand]
         public void ChangeHotkey()
         {
+            BeginCapture(HotkeyAction.ToggleRecording);
+        }
+
+        [RelayCommand]
+        private void ChangeActionHotkey(string action)
+        {
+            if (Enum.TryParse(action, out HotkeyAction parsed)) BeginCapture(parsed);
+        }
+
+        [RelayCommand]
+        private void ClearActionHotkey(string action)
+        {
+            if (!Enum.TryParse(action, out HotkeyAction parsed)) return;
+
+            IsCapturing = false;
+            _hotkeyService.ClearActionHotkey(parsed);
+            UpdateHotkeyDisplay();
+        }
+
+        private void BeginCapture(HotkeyAction action)
+        {
+            // Abandon a capture started on another row
+            if (IsCapturing) UpdateHotkeyDisplay();
+
+            _capturingAction = action;
             IsCapturing = true;
-            CurrentHotkeyDisplay = "Press a key...";
+            SetHotkeyDisplay(action, "Press a key...");
             _requestFocus?.Invoke();
         }
 
@@ -75,67 +680,64 @@ namespace EliteWhisper.ViewModels
             if ((modifiers & ModifierKeys.Shift) != 0) modifierFlags |= 0x0004;   // MOD_SHIFT
             if ((modifiers & ModifierKeys.Alt) != 0) modifierFlags |= 0x0001;     // MOD_ALT
 
-            // Update hotkey service
-            bool success = _hotkeyService.UpdateHotkey(vk, modifierFlags);
-            
-            if (success)
-            {
-                // Save to settings (simple registry approach)
-                try
-                {
-                    using var regKey = Microsoft.Win32.Registry.CurrentUser.CreateSubKey(@"Software\EliteWhisper");
-                    regKey?.SetValue("HotkeyVK", vk);
-                    regKey?.SetValue("HotkeyModifiers", modifierFlags);
-                }
-                catch { }
-
-                UpdateHotkeyDisplay();
-            }
-            else
+            // Validates, checks for clashes with the other actions and saves the shortcut on success
+            string? error = _hotkeyService.UpdateActionHotkey(_capturingAction, vk, modifierFlags);
+            if (error != null)
             {
-                MessageBox.Show("Failed to register hotkey. It may be in use by another application.", 
+                MessageBox.Show($"Couldn't set the shortcut for \"{HotkeyService.DescribeAction(_capturingAction)}\": {error}",
                     "Hotkey Error", MessageBoxButton.OK, MessageBoxImage.Warning);
-                UpdateHotkeyDisplay();
             }
 
+            UpdateHotkeyDisplay();
             IsCapturing = false;
         }
 
         public void LoadSavedHotkey()
         {
-            try
+            // The service loads the saved shortcut at startup; just reflect it
+            UpdateHotkeyDisplay();
+        }
+
+        private void UpdateHotkeyDisplay()
+        {
+            foreach (HotkeyAction action in Enum.GetValues<HotkeyAction>())
             {
-                using var regKey = Microsoft.Win32.Registry.CurrentUser.OpenSubKey(@"Software\EliteWhisper");
-                if (regKey != null)
-                {
-                    var vk = regKey.GetValue("HotkeyVK");
-                    var modifiers = regKey.GetValue("HotkeyModifiers");
-                    
-                    if (vk != null && modifiers != null)
-                    {
-                        _hotkeyService.CurrentVirtualKey = Convert.ToUInt32(vk);
-                        _hotkeyService.CurrentModifiers = Convert.ToUInt32(modifiers);
-                        UpdateHotkeyDisplay();
-                    }
-                }
+                var binding = _hotkeyService.GetBinding(action);
+                string display = binding == null ? "Not set" : FormatHotkey(binding.VirtualKey, binding.Modifiers);
+
+                // Saved shortcuts another application grabbed first are shown but don't work
+                if (binding != null && _hotkeyService.GetRegistrationError(action) != null) display += " (unavailable)";
+
+                SetHotkeyDisplay(action, display);
             }
-            catch { }
         }
 
-        private void UpdateHotkeyDisplay()
+        private void SetHotkeyDisplay(HotkeyAction action, string display)
+        {
+            switch (action)
+            {
+                case HotkeyAction.ToggleRecording: CurrentHotkeyDisplay = display; break;
+                case HotkeyAction.TranscribeClipboardAudio: ClipboardHotkeyDisplay = display; break;
+                case HotkeyAction.Cancel: CancelHotkeyDisplay = display; break;
+                case HotkeyAction.RetypeLast: RetypeHotkeyDisplay = display; break;
+                case HotkeyAction.RecopyLast: RecopyHotkeyDisplay = display; break;
+            }
+        }
+
+        private static string FormatHotkey(uint vk, uint modifiers)
         {
             string display = "";
             
             // Add modifiers
-            if ((_hotkeyService.CurrentModifiers & 0x0002) != 0) display += "Ctrl+";
-            if ((_hotkeyService.CurrentModifiers & 0x0004) != 0) display += "Shift+";
-            if ((_hotkeyService.CurrentModifiers & 0x0001) != 0) display += "Alt+";
+            if ((modifiers & 0x0002) != 0) display += "Ctrl+";
+            if ((modifiers & 0x0004) != 0) display += "Shift+";
+            if ((modifiers & 0x0001) != 0) display += "Alt+";
             
             // Add key name
-            Key key = KeyInterop.KeyFromVirtualKey((int)_hotkeyService.CurrentVirtualKey);
+            Key key = KeyInterop.KeyFromVirtualKey((int)vk);
             display += key.ToString();
             
-            CurrentHotkeyDisplay = display;
+            return display;
         }
 
         [RelayCommand]
@@ -161,6 +763,88 @@ namespace EliteWhisper.ViewModels
                 }
             }
         }
+
+        [RelayCommand]
+        private void SaveSuppressRegex()
+        {
+            try
+            {
+                _configService.SetWhisperSuppression(SuppressNonSpeechTokens, SuppressRegex.Trim());
+            }
+            catch (ArgumentException ex)
+            {
+                MessageBox.Show(ex.Message, "Blocked Words", MessageBoxButton.OK, MessageBoxImage.Warning);
+            }
+        }
+
+        [RelayCommand]
+        private void SaveHotwords()
+        {
+            var lines = HotwordsText.Split('\n').Select(l => l.Trim()).Where(l => l.Length > 0);
+
+            try
+            {
+                _hotwords.SaveHotwords(lines.Select(Hotword.Parse));
+                HotwordsText = string.Join(Environment.NewLine, _hotwords.GetHotwords().Select(h => h.ToLine()));
+            }
+            catch (Exception ex)
+            {
+                MessageBox.Show($"Could not save the vocabulary: {ex.Message}", "Custom Vocabulary",
+                    MessageBoxButton.OK, MessageBoxImage.Warning);
+            }
+        }
+
+        [RelayCommand]
+        private void ExportSettings()
+        {
+            var dialog = new Microsoft.Win32.SaveFileDialog
+            {
+                Title = "Export settings",
+                Filter = "Elite Whisper settings (*.json)|*.json",
+                FileName = $"elite-whisper-settings-{DateTime.Now:yyyy-MM-dd}.json"
+            };
+
+            if (dialog.ShowDialog() != true) return;
+
+            try
+            {
+                _backupService.Export(dialog.FileName);
+                MessageBox.Show("Settings exported. API keys are not included.", "Export Settings",
+                    MessageBoxButton.OK, MessageBoxImage.Information);
+            }
+            catch (Exception ex)
+            {
+                MessageBox.Show($"Error exporting settings: {ex.Message}", "Error");
+            }
+        }
+
+        [RelayCommand]
+        private void ImportSettings()
+        {
+            var dialog = new Microsoft.Win32.OpenFileDialog
+            {
+                Title = "Import settings",
+                Filter = "Elite Whisper settings (*.json)|*.json|All files (*.*)|*.*"
+            };
+
+            if (dialog.ShowDialog() != true) return;
+
+            var confirm = MessageBox.Show("This replaces your current settings, modes, hotwords, replacements and filters. Continue?",
+                "Import Settings", MessageBoxButton.YesNo, MessageBoxImage.Question);
+            if (confirm != MessageBoxResult.Yes) return;
+
+            try
+            {
+                _backupService.Import(dialog.FileName);
+                MessageBox.Show("Settings imported. Restart Elite Whisper to apply all changes.", "Import Settings",
+                    MessageBoxButton.OK, MessageBoxImage.Information);
+            }
+            catch (Exception ex)
+            {
+                MessageBox.Show($"Error importing settings: {ex.Message}", "Error");
+            }
+        }
+
         [RelayCommand]
         private async Task CheckForUpdates()
         {
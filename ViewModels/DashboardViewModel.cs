@@ -22,7 +22,7 @@ namespace EliteWhisper.ViewModels
         private string _statusMessage = "Press F2 to dictate";
 
         // Dynamic Card Properties
-        public string CurrentMicName => _audioService.DefaultMicName;
+        public string CurrentMicName => _audioService.ActiveDeviceName;
 
         public System.Collections.Generic.IReadOnlyList<DictationMode> Modes => _modeService.Modes;
 
@@ -86,6 +86,8 @@ namespace EliteWhisper.ViewModels
 
             _aiEngine.StateChanged += OnEngineStateChanged;
             _modeService.ActiveModeChanged += OnModeChanged;
+            _audioService.InputDeviceChanged += (s, name) =>
+                System.Windows.Application.Current.Dispatcher.Invoke(() => OnPropertyChanged(nameof(CurrentMicName)));
             
             // Listen for new records
             CommunityToolkit.Mvvm.Messaging.WeakReferenceMessenger.Default.Register<Messages.RecordAddedMessage>(this, (r, m) =>
@@ -303,46 +305,22 @@ namespace EliteWhisper.ViewModels
                 {
                     if (records.Count == 0) return;
                     
-                    // 1. Total Words
-                    int totalWords = records.Sum(r => r.WordCount);
-                    
-                    // 2. Average WPM
-                    // Only consider records with valid duration > 0 and WordCount > 0
-                    var validRecords = records.Where(r => r.DurationSeconds > 0 && r.WordCount > 0).ToList();
-                    int avgWpm = 0;
-                    if (validRecords.Any())
-                    {
-                        double totalDurationMin = validRecords.Sum(r => r.DurationSeconds) / 60.0;
-                        if (totalDurationMin > 0.1)
-                        {
-                            avgWpm = (int)(validRecords.Sum(r => r.WordCount) / totalDurationMin);
-                        }
-                    }
-
-                    // 3. Words This Week
+                    // 1. All-time totals (words, WPM, apps, minutes saved)
+                    var allTime = DictationStatsCalculator.Compute(records, DateTime.MinValue, DateTime.MaxValue);
+                    int totalWords = allTime.TotalWords;
+                    int avgWpm = allTime.AverageWpm;
+                    int appsUsed = allTime.AppsUsed;
+                    int minutesSaved = allTime.MinutesSaved;
+
+                    // 2. Words This Week
                     var now = DateTime.Now;
                     var oneWeekAgo = now.AddDays(-7);
                     int wordsThisWeek = records.Where(r => r.Timestamp >= oneWeekAgo).Sum(r => r.WordCount);
 
-                    // 4. Previous Week for Trend
+                    // 3. Previous Week for Trend
                     var twoWeeksAgo = now.AddDays(-14);
                     int wordsPrevWeek = records.Where(r => r.Timestamp >= twoWeeksAgo && r.Timestamp < oneWeekAgo).Sum(r => r.WordCount);
 
-                    // 5. Apps Used (Distinct)
-                    int appsUsed = records
-                        .Where(r => !string.IsNullOrEmpty(r.ApplicationName) && r.ApplicationName != "Unknown")
-                        .Select(r => r.ApplicationName)
-                        .Distinct()
-                        .Count();
-
-                    // 6. Minutes Saved
-                    // Typing speed assumption: 40 WPM
-                    // Dictation Time: Actual duration
-                    // Saved = (Words / 40) - (Duration / 60)
-                    double typingMinutes = totalWords / 40.0;
-                    double dictationMinutes = records.Sum(r => r.DurationSeconds) / 60.0;
-                    int minutesSaved = (int)Math.Max(0, typingMinutes - dictationMinutes);
-
                     // Trends
                     double wpmTrendVal = 0; 
                     // WPM trend is tricky, let's do Words Trend as requested
@@ -357,19 +335,8 @@ namespace EliteWhisper.ViewModels
                     }
 
                     // For WPM Trend, compare this week vs last week average
-                    double wpmThisWeek = 0;
-                    var thisWeekRecords = records.Where(r => r.Timestamp >= oneWeekAgo && r.DurationSeconds > 0).ToList();
-                    if (thisWeekRecords.Any())
-                    {
-                        wpmThisWeek = thisWeekRecords.Sum(r => r.WordCount) / (thisWeekRecords.Sum(r => r.DurationSeconds) / 60.0);
-                    }
-
-                    double wpmPrevWeek = 0;
-                    var prevWeekRecords = records.Where(r => r.Timestamp >= twoWeeksAgo && r.Timestamp < oneWeekAgo && r.DurationSeconds > 0).ToList();
-                    if (prevWeekRecords.Any())
-                    {
-                        wpmPrevWeek = prevWeekRecords.Sum(r => r.WordCount) / (prevWeekRecords.Sum(r => r.DurationSeconds) / 60.0);
-                    }
+                    double wpmThisWeek = DictationStatsCalculator.ComputeWpm(records.Where(r => r.Timestamp >= oneWeekAgo));
+                    double wpmPrevWeek = DictationStatsCalculator.ComputeWpm(records.Where(r => r.Timestamp >= twoWeeksAgo && r.Timestamp < oneWeekAgo));
 
                     if (wpmPrevWeek > 0)
                     {
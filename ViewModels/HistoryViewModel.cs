@@ -1,8 +1,11 @@
 using System;
+using System.Collections.Generic;
+using System.Collections.Specialized;
 using System.Collections.ObjectModel;
 using System.ComponentModel;
 using System.Linq;
 using System.Windows;
+using System.Windows.Controls;
 using System.Windows.Data;
 using CommunityToolkit.Mvvm.ComponentModel;
 using CommunityToolkit.Mvvm.Input;
@@ -14,6 +17,20 @@ namespace EliteWhisper.ViewModels
     public partial class HistoryViewModel : ObservableObject
     {
         private readonly HistoryService _historyService;
+        private readonly BatchTranscriptionService _batchService;
+        private readonly AudioPlayerService _audioPlayerService;
+        private const int PAGE_SIZE = 50;
+
+        // Records on the loaded pages; searching looks through everything
+        private HashSet<Guid> _loadedIds = new();
+        private int _loadedCount = PAGE_SIZE;
+
+        // Set while showing the near-duplicates of one record
+        private HashSet<Guid>? _similarIds;
+
+        // Record being played back and the timer that follows its words
+        private DictationRecord? _playingRecord;
+        private readonly System.Windows.Threading.DispatcherTimer _playbackTimer = new() { Interval = TimeSpan.FromMilliseconds(100) };
 
         [ObservableProperty]
         private string _searchText = string.Empty;
@@ -21,17 +38,65 @@ namespace EliteWhisper.ViewModels
         [ObservableProperty]
         private bool _hasHistory = false;
 
+        [ObservableProperty]
+        private bool _hasMoreHistory;
+
+        [ObservableProperty]
+        private bool _isLocked;
+
+        /// <summary>
+        /// Progress of a running folder transcription, empty when none is running.
+        /// </summary>
+        [ObservableProperty]
+        private string _batchStatusText = string.Empty;
+
+        /// <summary>
+        /// Note about items the retention setting removed, empty when none were.
+        /// </summary>
+        [ObservableProperty]
+        private string _retentionStatusText = string.Empty;
+
+        /// <summary>
+        /// Describes the "similar recordings" filter, empty when it is off.
+        /// </summary>
+        [ObservableProperty]
+        private string _similarFilterText = string.Empty;
+
+        /// <summary>
+        /// Words around the one being played, split so the current word can be highlighted. All empty when nothing plays.
+        /// </summary>
+        [ObservableProperty]
+        private string _playbackBefore = string.Empty;
+
+        [ObservableProperty]
+        private string _playbackWord = string.Empty;
+
+        [ObservableProperty]
+        private string _playbackAfter = string.Empty;
+
+        [ObservableProperty]
+        private bool _isFollowingPlayback;
+
         public ReadOnlyObservableCollection<DictationRecord> History => _historyService.History;
         
         public ICollectionView HistoryView { get; private set; }
 
-        public HistoryViewModel(HistoryService historyService)
+        public HistoryViewModel(HistoryService historyService, BatchTranscriptionService batchService, AudioPlayerService audioPlayerService)
         {
             _historyService = historyService;
+            _batchService = batchService;
+            _audioPlayerService = audioPlayerService;
 
             // Setup CollectionView for filtering
             HistoryView = CollectionViewSource.GetDefaultView(History);
+            LoadPages();
             HistoryView.Filter = FilterHistory;
+
+            ((INotifyCollectionChanged)History).CollectionChanged += (s, e) =>
+            {
+                LoadPages();
+                HistoryView.Refresh();
+            };
             
             // Monitor count changes
             ((INotifyPropertyChanged)History).PropertyChanged += (s, e) => 
@@ -42,6 +107,29 @@ namespace EliteWhisper.ViewModels
             
             // Initial sort if needed (Service already sorts, but view can too)
             HistoryView.SortDescriptions.Add(new SortDescription("Timestamp", ListSortDirection.Descending));
+
+            IsLocked = _historyService.IsLocked;
+            _historyService.LockStateChanged += (s, e) => Application.Current.Dispatcher.Invoke(() =>
+            {
+                IsLocked = _historyService.IsLocked;
+                LoadPages();
+                HistoryView.Refresh();
+            });
+
+            _batchService.BatchProgress += OnBatchProgress;
+            _batchService.BatchFinished += OnBatchFinished;
+            _historyService.HistoryPruned += OnHistoryPruned;
+
+            _playbackTimer.Tick += (s, e) => UpdatePlaybackWord();
+            _audioPlayerService.PlaybackStopped += (s, e) => Application.Current.Dispatcher.BeginInvoke(StopFollowingPlayback);
+        }
+
+        private void OnHistoryPruned(object? sender, int count)
+        {
+            Application.Current.Dispatcher.BeginInvoke(() =>
+            {
+                RetentionStatusText = $"{count} older item(s) removed by the history retention setting.";
+            });
         }
 
         private void UpdateHasHistory()
@@ -49,19 +137,49 @@ namespace EliteWhisper.ViewModels
             HasHistory = History.Count > 0;
         }
 
+        private void LoadPages()
+        {
+            if (_historyService.IsLocked)
+            {
+                _loadedIds.Clear();
+                HasMoreHistory = false;
+                return;
+            }
+
+            var page = _historyService.GetPage(0, _loadedCount);
+            _loadedIds = page.Items.Select(r => r.Id).ToHashSet();
+            HasMoreHistory = page.TotalCount > _loadedCount;
+        }
+
+        [RelayCommand]
+        private void LoadMore()
+        {
+            _loadedCount += PAGE_SIZE;
+            LoadPages();
+            HistoryView.Refresh();
+        }
+
         partial void OnSearchTextChanged(string value)
         {
+            _similarIds = null;
+            SimilarFilterText = string.Empty;
             HistoryView.Refresh();
         }
 
         private bool FilterHistory(object item)
         {
-            if (string.IsNullOrWhiteSpace(SearchText)) return true;
-            if (item is DictationRecord record)
-            {
-                return record.Content.Contains(SearchText, StringComparison.OrdinalIgnoreCase);
-            }
-            return false;
+            if (item is not DictationRecord record) return false;
+            if (_similarIds != null) return _similarIds.Contains(record.Id);
+            if (string.IsNullOrWhiteSpace(SearchText)) return _loadedIds.Contains(record.Id);
+
+            // "#tag" shows only records with that tag
+            string query = SearchText.Trim();
+            if (query.StartsWith('#') && query.Length > 1)
+                return record.Tags?.Any(t => string.Equals(t, query.Substring(1), StringComparison.OrdinalIgnoreCase)) == true;
+
+            return record.Content.Contains(SearchText, StringComparison.OrdinalIgnoreCase) ||
+                   record.Title?.Contains(SearchText, StringComparison.OrdinalIgnoreCase) == true ||
+                   record.ApplicationName?.Contains(SearchText, StringComparison.OrdinalIgnoreCase) == true;
         }
 
         [RelayCommand]
@@ -73,6 +191,129 @@ namespace EliteWhisper.ViewModels
             }
         }
 
+        [RelayCommand]
+        private void EditRecord(DictationRecord? record)
+        {
+            if (record == null) return;
+
+            var dialog = new Views.EditTranscriptWindow(record.Content, record.Title, record.Tags)
+            {
+                Owner = Application.Current.MainWindow
+            };
+
+            if (dialog.ShowDialog() != true) return;
+
+            try
+            {
+                _historyService.UpdateRecord(record.Id, dialog.TranscriptText, dialog.TranscriptTitle);
+
+                var tags = dialog.Tags;
+                foreach (var tag in (record.Tags ?? new List<string>()).ToList())
+                {
+                    if (!tags.Contains(tag, StringComparer.OrdinalIgnoreCase))
+                        _historyService.RemoveTag(record.Id, tag);
+                }
+                foreach (var tag in tags)
+                {
+                    _historyService.AddTag(record.Id, tag);
+                }
+            }
+            catch (Exception ex)
+            {
+                MessageBox.Show($"Could not save changes: {ex.Message}", "Edit Transcript",
+                    MessageBoxButton.OK, MessageBoxImage.Error);
+            }
+        }
+
+        [RelayCommand]
+        private void PlayRecording(DictationRecord? record)
+        {
+            if (record == null) return;
+
+            string? path = _historyService.GetRecordingPath(record.Id);
+            if (path == null)
+            {
+                MessageBox.Show("The recording for this item is no longer available.", "Play Recording",
+                    MessageBoxButton.OK, MessageBoxImage.Information);
+                return;
+            }
+
+            try
+            {
+                if (_audioPlayerService.IsPlaying)
+                {
+                    _audioPlayerService.Stop();
+                    return;
+                }
+
+                _audioPlayerService.Play(path);
+                if (record.Words is { Count: > 0 })
+                {
+                    _playingRecord = record;
+                    IsFollowingPlayback = true;
+                    _playbackTimer.Start();
+                }
+            }
+            catch (Exception ex)
+            {
+                MessageBox.Show(ex.Message, "Play Recording", MessageBoxButton.OK, MessageBoxImage.Error);
+            }
+        }
+
+        private void UpdatePlaybackWord()
+        {
+            if (_playingRecord?.Words == null) return;
+
+            var current = _historyService.WordAt(_playingRecord.Id, (long)_audioPlayerService.Position.TotalMilliseconds);
+            if (current == null) return;
+
+            // A few words of context on either side, like a karaoke line
+            var words = _playingRecord.Words;
+            int index = current.Value.Index;
+            int first = Math.Max(0, index - 4);
+            int last = Math.Min(words.Count - 1, index + 4);
+
+            PlaybackBefore = string.Join(" ", words.Skip(first).Take(index - first).Select(w => w.Word.Trim())) + " ";
+            PlaybackWord = current.Value.Word.Word.Trim();
+            PlaybackAfter = " " + string.Join(" ", words.Skip(index + 1).Take(last - index).Select(w => w.Word.Trim()));
+        }
+
+        private void StopFollowingPlayback()
+        {
+            _playbackTimer.Stop();
+            _playingRecord = null;
+            IsFollowingPlayback = false;
+            PlaybackBefore = string.Empty;
+            PlaybackWord = string.Empty;
+            PlaybackAfter = string.Empty;
+        }
+
+        [RelayCommand]
+        private void ShowSimilar(DictationRecord? record)
+        {
+            if (record == null) return;
+
+            var similar = _historyService.FindSimilar(record.Id);
+            if (similar.Count == 0)
+            {
+                MessageBox.Show("No other item was transcribed from the same audio.", "Similar Recordings",
+                    MessageBoxButton.OK, MessageBoxImage.Information);
+                return;
+            }
+
+            _similarIds = similar.Select(r => r.Id).Append(record.Id).ToHashSet();
+            SimilarFilterText = $"Showing {similar.Count} item(s) with the same audio as the one from {record.Timestamp:g}.";
+            HistoryView.Refresh();
+        }
+
+        [RelayCommand]
+        private void ClearSimilar()
+        {
+            _similarIds = null;
+            SimilarFilterText = string.Empty;
+            HistoryView.Refresh();
+        }
+
         [RelayCommand]
         private void DeleteRecord(DictationRecord? record)
         {
@@ -82,10 +323,219 @@ namespace EliteWhisper.ViewModels
             }
         }
 
+        [RelayCommand]
+        private void Unlock(PasswordBox? passwordBox)
+        {
+            if (passwordBox == null || string.IsNullOrEmpty(passwordBox.Password)) return;
+
+            try
+            {
+                _historyService.Unlock(passwordBox.Password);
+                passwordBox.Clear();
+            }
+            catch (Exception ex)
+            {
+                MessageBox.Show(ex.Message, "Unlock History", MessageBoxButton.OK, MessageBoxImage.Warning);
+            }
+        }
+
+        [RelayCommand]
+        private void ExportHistory()
+        {
+            if (History.Count == 0 || IsLocked) return;
+
+            var dialog = new Microsoft.Win32.SaveFileDialog
+            {
+                Title = "Export history",
+                Filter = "JSON backup (*.json)|*.json|CSV spreadsheet (*.csv)|*.csv|Markdown (*.md)|*.md",
+                FileName = $"elite-whisper-history-{DateTime.Now:yyyy-MM-dd}"
+            };
+
+            if (dialog.ShowDialog() != true) return;
+
+            try
+            {
+                var format = dialog.FilterIndex switch
+                {
+                    2 => HistoryExportFormat.Csv,
+                    3 => HistoryExportFormat.Markdown,
+                    _ => HistoryExportFormat.Json
+                };
+                HistoryExporter.Export(History, format, dialog.FileName);
+            }
+            catch (Exception ex)
+            {
+                MessageBox.Show($"Export failed: {ex.Message}", "Export History",
+                    MessageBoxButton.OK, MessageBoxImage.Error);
+            }
+        }
+
+        [RelayCommand]
+        private void ExportSubtitles(DictationRecord? record)
+        {
+            if (record == null) return;
+
+            var dialog = new Microsoft.Win32.SaveFileDialog
+            {
+                Title = "Export subtitles",
+                Filter = "SubRip subtitles (*.srt)|*.srt|WebVTT subtitles (*.vtt)|*.vtt",
+                FileName = $"dictation-{record.Timestamp:yyyy-MM-dd-HHmmss}"
+            };
+
+            if (dialog.ShowDialog() != true) return;
+
+            try
+            {
+                var format = dialog.FilterIndex == 2 ? SubtitleFormat.Vtt : SubtitleFormat.Srt;
+                SubtitleExporter.Export(record, format, dialog.FileName);
+            }
+            catch (Exception ex)
+            {
+                MessageBox.Show($"Export failed: {ex.Message}", "Export Subtitles",
+                    MessageBoxButton.OK, MessageBoxImage.Error);
+            }
+        }
+
+        [RelayCommand]
+        private void ImportCsv()
+        {
+            var dialog = new Microsoft.Win32.OpenFileDialog
+            {
+                Title = "Import history from CSV",
+                Filter = "CSV files (*.csv)|*.csv|All files (*.*)|*.*"
+            };
+
+            if (dialog.ShowDialog() == true)
+            {
+                RunImport(dialog.FileName, ExternalHistoryFormat.Csv);
+            }
+        }
+
+        [RelayCommand]
+        private void ImportTextFolder()
+        {
+            var dialog = new Microsoft.Win32.OpenFolderDialog
+            {
+                Title = "Import history from a folder of .txt files"
+            };
+
+            if (dialog.ShowDialog() == true)
+            {
+                RunImport(dialog.FolderName, ExternalHistoryFormat.TextFolder);
+            }
+        }
+
+        [RelayCommand]
+        private void ImportBackup()
+        {
+            var dialog = new Microsoft.Win32.OpenFileDialog
+            {
+                Title = "Import history backup",
+                Filter = "JSON backup (*.json)|*.json|All files (*.*)|*.*"
+            };
+
+            if (dialog.ShowDialog() != true) return;
+
+            var mode = HistoryImportMode.Merge;
+            if (History.Count > 0)
+            {
+                var choice = MessageBox.Show(
+                    "Merge the backup into your current history?\n\nChoose No to replace the current history with the backup.",
+                    "Import History", MessageBoxButton.YesNoCancel, MessageBoxImage.Question);
+                if (choice == MessageBoxResult.Cancel) return;
+                if (choice == MessageBoxResult.No) mode = HistoryImportMode.Replace;
+            }
+
+            try
+            {
+                var result = _historyService.ImportBackup(dialog.FileName, mode);
+                string message = $"Imported {result.Imported} item(s), skipped {result.Skipped} duplicate(s).";
+                if (result.Rejected > 0)
+                    message += $"\n{result.Rejected} malformed item(s) were rejected.";
+                MessageBox.Show(message, "Import History", MessageBoxButton.OK, MessageBoxImage.Information);
+            }
+            catch (Exception ex)
+            {
+                MessageBox.Show($"Import failed: {ex.Message}", "Import History",
+                    MessageBoxButton.OK, MessageBoxImage.Error);
+            }
+        }
+
+        [RelayCommand]
+        private void TranscribeFolder()
+        {
+            var dialog = new Microsoft.Win32.OpenFolderDialog
+            {
+                Title = "Transcribe all audio files in a folder"
+            };
+
+            if (dialog.ShowDialog() != true) return;
+
+            var choice = MessageBox.Show("Include audio files in subfolders?", "Transcribe Folder",
+                MessageBoxButton.YesNoCancel, MessageBoxImage.Question);
+            if (choice == MessageBoxResult.Cancel) return;
+
+            try
+            {
+                var batch = _batchService.TranscribeFolder(dialog.FolderName, choice == MessageBoxResult.Yes);
+                if (batch.Total > 0) BatchStatusText = $"Transcribing 0/{batch.Total} files...";
+            }
+            catch (Exception ex)
+            {
+                MessageBox.Show($"Error starting transcription: {ex.Message}", "Error");
+            }
+        }
+
+        private void OnBatchProgress(object? sender, BatchTranscriptionStatus batch)
+        {
+            Application.Current.Dispatcher.BeginInvoke(() =>
+            {
+                if (batch.IsFinished) return;
+                string current = batch.CurrentFile != null ? $" - {System.IO.Path.GetFileName(batch.CurrentFile)}" : "";
+                BatchStatusText = $"Transcribing {batch.Processed}/{batch.Total} files ({batch.Percent}%){current}";
+            });
+        }
+
+        private void OnBatchFinished(object? sender, BatchTranscriptionStatus batch)
+        {
+            Application.Current.Dispatcher.BeginInvoke(() =>
+            {
+                BatchStatusText = string.Empty;
+
+                string message = batch.Total == 0
+                    ? "No supported audio files were found in the folder."
+                    : $"Transcribed {batch.Succeeded} of {batch.Total} file(s).";
+                if (batch.Failures.Count > 0)
+                {
+                    var lines = batch.Failures.Take(10).Select(f => $"{System.IO.Path.GetFileName(f.FilePath)}: {f.Reason}");
+                    message += $"\n\nSkipped {batch.Failures.Count}:\n" + string.Join("\n", lines);
+                    if (batch.Failures.Count > 10) message += $"\n...and {batch.Failures.Count - 10} more";
+                }
+
+                MessageBox.Show(message, "Transcribe Folder", MessageBoxButton.OK,
+                    batch.Failures.Count > 0 ? MessageBoxImage.Warning : MessageBoxImage.Information);
+            });
+        }
+
+        private void RunImport(string path, ExternalHistoryFormat format)
+        {
+            try
+            {
+                var result = _historyService.ImportExternal(path, format);
+                MessageBox.Show($"Imported {result.Imported} item(s), skipped {result.Skipped}.", "Import History",
+                    MessageBoxButton.OK, MessageBoxImage.Information);
+            }
+            catch (Exception ex)
+            {
+                MessageBox.Show($"Import failed: {ex.Message}", "Import History",
+                    MessageBoxButton.OK, MessageBoxImage.Error);
+            }
+        }
+
         [RelayCommand]
         private void ClearAll()
         {
-            if (MessageBox.Show("Are you sure you want to clear all history?", "Clear History", 
+            if (MessageBox.Show("Are you sure you want to clear all history?\nSaved recordings are deleted too.", "Clear History", 
                 MessageBoxButton.YesNo, MessageBoxImage.Question) == MessageBoxResult.Yes)
             {
                 _historyService.ClearAll();
@@ -119,10 +119,15 @@ namespace EliteWhisper.ViewModels
 
             try
             {
-                var progress = new Progress<double>(p => 
+                var progress = new Progress<Models.DownloadProgress>(p => 
                 {
-                    DownloadProgress = p;
-                    model.DownloadProgress = p;
+                    DownloadProgress = p.Percent;
+                    model.DownloadProgress = p.Percent;
+
+                    string speed = p.Describe();
+                    StatusMessage = string.IsNullOrEmpty(speed)
+                        ? $"Downloading {model.DisplayName}..."
+                        : $"Downloading {model.DisplayName}... {speed}";
                 });
                 await _downloadService.DownloadModelAsync(model.DownloadUrl, destPath, progress, _downloadCts.Token);
                 
@@ -132,17 +137,15 @@ namespace EliteWhisper.ViewModels
             }
             catch (OperationCanceledException)
             {
+                // The partial file is discarded; pausing is what keeps it
                 StatusMessage = "Download Canceled";
                 model.Status = "Canceled";
-                // Clean up partial file
-                if (File.Exists(destPath)) File.Delete(destPath);
             }
             catch (Exception ex)
             {
                 StatusMessage = "Download Error";
                 model.Status = "Error";
                 MessageBox.Show($"Download failed: {ex.Message}", "Error", MessageBoxButton.OK, MessageBoxImage.Error);
-                if (File.Exists(destPath)) File.Delete(destPath);
             }
             finally
             {
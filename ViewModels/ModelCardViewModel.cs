@@ -20,6 +20,21 @@ namespace EliteWhisper.ViewModels
         [ObservableProperty]
         private double _downloadProgress;
 
+        [ObservableProperty]
+        private bool _isExtracting;
+
+        /// <summary>
+        /// A partial download is on disk; downloading again resumes it.
+        /// </summary>
+        [ObservableProperty]
+        private bool _isPaused;
+
+        /// <summary>
+        /// Speed and time left of the running download, e.g. "2.3 MB/s, 40s left".
+        /// </summary>
+        [ObservableProperty]
+        private string _downloadSpeedText = string.Empty;
+
         public ModelCardViewModel(AIModelRegistryEntry entry)
         {
             _registryEntry = entry;
@@ -37,6 +52,7 @@ namespace EliteWhisper.ViewModels
         public string? Sha256 => _registryEntry.Sha256;
         public string? DataFilename => _registryEntry.DataFilename;
         public string? EngineType => _registryEntry.EngineType;
+        public System.Collections.Generic.IReadOnlyList<AIModelRegistryFile>? Files => _registryEntry.Files;
         
         // Visual Helpers
         public int SpeedRating => _registryEntry.SpeedRating;
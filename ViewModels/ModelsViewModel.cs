@@ -5,7 +5,7 @@ using System;
 using System.Collections.ObjectModel;
 using System.IO;
 using System.Linq;
-using System.Net.Http;
+using System.Threading;
 using System.Threading.Tasks;
 using System.Windows;
 using SharpCompress.Readers;
@@ -17,11 +17,20 @@ namespace EliteWhisper.ViewModels
         private readonly ModelRegistryService _registryService;
         private readonly WhisperConfigurationService _configService;
         private readonly AIEngineService _aiEngine;
-        private readonly HttpClient _httpClient;
+        private readonly RecentModelsService _recentModels;
+        private readonly Services.Speech.SpeechEngineSelector _engineSelector;
+        private readonly ModelDownloadService _downloadService;
+        private readonly InstalledModelService _installedModelService;
 
         [ObservableProperty]
         private ObservableCollection<ModelCardViewModel> _models = new();
 
+        /// <summary>
+        /// Everything in the models folder, including models that aren't in the registry.
+        /// </summary>
+        [ObservableProperty]
+        private ObservableCollection<Models.InstalledModelInfo> _installedModels = new();
+
         [ObservableProperty]
         private string _currentStoragePath = string.Empty;
 
@@ -38,12 +47,19 @@ namespace EliteWhisper.ViewModels
         public ModelsViewModel(
             ModelRegistryService registryService,
             WhisperConfigurationService configService,
-            AIEngineService aiEngine)
+            AIEngineService aiEngine,
+            RecentModelsService recentModels,
+            Services.Speech.SpeechEngineSelector engineSelector,
+            ModelDownloadService downloadService,
+            InstalledModelService installedModelService)
         {
             _registryService = registryService;
             _configService = configService;
             _aiEngine = aiEngine;
-            _httpClient = new HttpClient();
+            _recentModels = recentModels;
+            _engineSelector = engineSelector;
+            _downloadService = downloadService;
+            _installedModelService = installedModelService;
             
             // Listen to engine state
             _aiEngine.StateChanged += OnEngineStateChanged;
@@ -94,6 +110,11 @@ namespace EliteWhisper.ViewModels
                     {
                         card.IsInstalled = true;
                     }
+                    else
+                    {
+                        // A download paused or interrupted in an earlier session can be resumed
+                        card.IsPaused = HasPartialDownload(card, modelsDir);
+                    }
                 }
                 
                 // Set ActiveModelId if this is the active model
@@ -104,6 +125,20 @@ namespace EliteWhisper.ViewModels
 
                 Models.Add(card);
             }
+
+            RefreshInstalledModels();
+        }
+
+        private void RefreshInstalledModels()
+        {
+            try
+            {
+                InstalledModels = new ObservableCollection<Models.InstalledModelInfo>(_installedModelService.GetInstalledModels());
+            }
+            catch (Exception ex)
+            {
+                System.Diagnostics.Debug.WriteLine($"[Models] Failed to list installed models: {ex.Message}");
+            }
         }
 
         [RelayCommand]
@@ -200,6 +235,7 @@ namespace EliteWhisper.ViewModels
                     // Refresh
                     _configService.RevalidateCurrentConfiguration();
                     card.IsInstalled = false;
+                    RefreshInstalledModels();
                     MessageBox.Show("Model deleted successfully.", "Success", MessageBoxButton.OK, MessageBoxImage.Information);
                 }
                 catch (Exception ex)
@@ -217,15 +253,20 @@ namespace EliteWhisper.ViewModels
             try
             {
                 card.IsDownloading = true;
+                card.IsPaused = false;
                 card.DownloadProgress = 0;
 
                 string modelsDir = CurrentStoragePath; 
                 Directory.CreateDirectory(modelsDir);
 
-                bool isSherpaArchive = card.DownloadUrl.EndsWith(".tar.bz2", StringComparison.OrdinalIgnoreCase) ||
+                bool isSherpaArchive = GetArchiveExtension(card.DownloadUrl) != null ||
                                        string.Equals(card.EngineType, "sherpa", StringComparison.OrdinalIgnoreCase);
 
-                if (isSherpaArchive)
+                if (card.Files is { Count: > 0 })
+                {
+                    await DownloadSherpaFilesAsync(card, modelsDir);
+                }
+                else if (isSherpaArchive)
                 {
                     await DownloadAndExtractSherpaModelAsync(card, modelsDir);
                 }
@@ -239,20 +280,29 @@ namespace EliteWhisper.ViewModels
                 
                 card.IsInstalled = true;
                 card.IsDownloading = false;
+                RefreshInstalledModels();
+            }
+            catch (DownloadPausedException)
+            {
+                // Partial files stay so resuming picks up where the download stopped
+                card.IsDownloading = false;
+                card.IsPaused = true;
+            }
+            catch (OperationCanceledException)
+            {
+                card.IsDownloading = false;
+                card.DownloadProgress = 0;
+                DiscardPartialDownload(card, CurrentStoragePath);
             }
             catch (Exception ex)
             {
                 card.IsDownloading = false;
                 card.DownloadProgress = 0;
                 
-                // Cleanup temp files
+                // Cleanup a complete archive that failed to extract; .partial files are kept for resume
                 try
                 {
-                    string modelsDir = CurrentStoragePath;
-                    string tempPath = Path.Combine(modelsDir, card.Filename + ".tar.bz2.tmp");
-                    if (File.Exists(tempPath)) File.Delete(tempPath);
-
-                    string archivePath = Path.Combine(modelsDir, card.Filename + ".tar.bz2");
+                    string archivePath = GetArchivePath(card, CurrentStoragePath);
                     if (File.Exists(archivePath)) File.Delete(archivePath);
                 }
                 catch { }
@@ -261,92 +311,262 @@ namespace EliteWhisper.ViewModels
             }
         }
 
+        [RelayCommand]
+        private void DeleteInstalledModel(Models.InstalledModelInfo model)
+        {
+            var result = MessageBox.Show(
+                $"Are you sure you want to delete '{model.Name}' ({model.SizeDisplay})?\nThis will remove it from your disk.",
+                "Delete Model",
+                MessageBoxButton.YesNo,
+                MessageBoxImage.Warning);
+
+            if (result != MessageBoxResult.Yes) return;
+
+            try
+            {
+                _installedModelService.DeleteModel(model.Path);
+                LoadModels();
+            }
+            catch (Exception ex)
+            {
+                MessageBox.Show($"Failed to delete model: {ex.Message}", "Error", MessageBoxButton.OK, MessageBoxImage.Error);
+            }
+        }
+
+        [RelayCommand]
+        private void CancelDownload(ModelCardViewModel card)
+        {
+            if (card.IsPaused && !card.IsDownloading)
+            {
+                DiscardPartialDownload(card, CurrentStoragePath);
+                card.IsPaused = false;
+                card.DownloadProgress = 0;
+                return;
+            }
+
+            // Whichever of the card's files is currently downloading; its partial file is discarded
+            foreach (var path in GetDownloadPaths(card, CurrentStoragePath))
+            {
+                _downloadService.CancelDownload(Path.GetFileName(path));
+            }
+        }
+
+        [RelayCommand]
+        private void PauseDownload(ModelCardViewModel card)
+        {
+            foreach (var path in GetDownloadPaths(card, CurrentStoragePath))
+            {
+                _downloadService.PauseDownload(Path.GetFileName(path));
+            }
+        }
+
         /// <summary>
-        /// Downloads and extracts a Sherpa-ONNX model archive (.tar.bz2) into the models directory.
+        /// Every file a card's download may write, whichever way the model is published.
         /// </summary>
-        private async Task DownloadAndExtractSherpaModelAsync(ModelCardViewModel card, string modelsDir)
+        private static System.Collections.Generic.IEnumerable<string> GetDownloadPaths(ModelCardViewModel card, string modelsDir)
         {
-            long totalBytesExpected = (long)card.SizeMB * 1024 * 1024;
-            if (totalBytesExpected <= 0) totalBytesExpected = 1;
+            yield return GetArchivePath(card, modelsDir);
+            yield return Path.Combine(modelsDir, card.Filename);
+            if (!string.IsNullOrEmpty(card.DataFilename))
+            {
+                yield return Path.Combine(modelsDir, card.DataFilename);
+            }
+            if (card.Files != null)
+            {
+                string stagingDir = Path.Combine(modelsDir, card.Filename) + ModelDownloadService.PARTIAL_EXTENSION;
+                foreach (var file in card.Files)
+                {
+                    yield return Path.Combine(stagingDir, file.Filename);
+                }
+            }
+        }
 
-            // Download archive
-            string archiveTempPath = Path.Combine(modelsDir, card.Filename + ".tar.bz2.tmp");
-            string archivePath = Path.Combine(modelsDir, card.Filename + ".tar.bz2");
+        private static bool HasPartialDownload(ModelCardViewModel card, string modelsDir) =>
+            GetDownloadPaths(card, modelsDir).Any(ModelDownloadService.HasPartialDownload) ||
+            (card.Files is { Count: > 0 } && Directory.Exists(Path.Combine(modelsDir, card.Filename) + ModelDownloadService.PARTIAL_EXTENSION));
 
-            using (var response = await _httpClient.GetAsync(card.DownloadUrl, HttpCompletionOption.ResponseHeadersRead))
+        private void DiscardPartialDownload(ModelCardViewModel card, string modelsDir)
+        {
+            foreach (var path in GetDownloadPaths(card, modelsDir))
             {
-                response.EnsureSuccessStatusCode();
-                long totalRead = 0;
+                _downloadService.DeletePartialDownload(path);
+            }
 
-                using (var contentStream = await response.Content.ReadAsStreamAsync())
-                using (var fileStream = new FileStream(archiveTempPath, FileMode.Create, FileAccess.Write, FileShare.None, 81920, true))
+            if (card.Files is { Count: > 0 })
+            {
+                try
                 {
-                    var buffer = new byte[81920];
-                    int read;
-
-                    while ((read = await contentStream.ReadAsync(buffer, 0, buffer.Length)) > 0)
-                    {
-                        await fileStream.WriteAsync(buffer, 0, read);
-                        totalRead += read;
-                        // Download is ~80% of the work, extraction is ~20%
-                        card.DownloadProgress = (double)totalRead / totalBytesExpected * 80;
-                    }
+                    string stagingDir = Path.Combine(modelsDir, card.Filename) + ModelDownloadService.PARTIAL_EXTENSION;
+                    if (Directory.Exists(stagingDir)) Directory.Delete(stagingDir, true);
                 }
+                catch { }
             }
+        }
+
+        /// <summary>
+        /// Downloads a model archive (.tar.bz2, .tar.gz or .zip) and extracts it into a folder named after the card.
+        /// The archive is deleted once the extracted files are verified.
+        /// </summary>
+        private async Task DownloadAndExtractSherpaModelAsync(ModelCardViewModel card, string modelsDir)
+        {
+            // Download archive
+            string archivePath = GetArchivePath(card, modelsDir);
 
-            // Move temp to final archive name
-            if (File.Exists(archivePath)) File.Delete(archivePath);
-            File.Move(archiveTempPath, archivePath);
+            // Download is ~80% of the work, extraction is ~20%
+            var progress = new Progress<Models.DownloadProgress>(p =>
+            {
+                card.DownloadProgress = p.Percent * 0.8;
+                card.DownloadSpeedText = p.Describe();
+            });
+            await _downloadService.DownloadModelAsync(card.DownloadUrl, archivePath, progress, CancellationToken.None);
+
+            if (!string.IsNullOrEmpty(card.Sha256))
+            {
+                // A bad archive is deleted by the caller so the next attempt starts clean
+                string calculatedHash = await ComputeSha256HashAsync(archivePath);
+                if (!string.Equals(calculatedHash, card.Sha256, StringComparison.OrdinalIgnoreCase))
+                {
+                    throw new Exception($"Checksum mismatch.\nExpected: {card.Sha256}\nActual: {calculatedHash}");
+                }
+            }
 
-            // Extract archive
+            // Extract archive into a staging folder so a failed extraction never looks like an installed model
+            card.IsExtracting = true;
             card.DownloadProgress = 85;
             string targetDir = Path.Combine(modelsDir, card.Filename);
-            
-            await Task.Run(() =>
+            string stagingDir = targetDir + ModelDownloadService.PARTIAL_EXTENSION;
+
+            try
             {
-                using (var stream = File.OpenRead(archivePath))
-                using (var reader = SharpCompress.Readers.ReaderFactory.Open(stream))
+                await Task.Run(() =>
                 {
-                    while (reader.MoveToNextEntry())
+                    if (Directory.Exists(stagingDir)) Directory.Delete(stagingDir, true);
+                    Directory.CreateDirectory(stagingDir);
+
+                    using (var stream = File.OpenRead(archivePath))
+                    using (var reader = SharpCompress.Readers.ReaderFactory.Open(stream))
                     {
-                        if (!reader.Entry.IsDirectory)
+                        while (reader.MoveToNextEntry())
                         {
-                            reader.WriteEntryToDirectory(modelsDir, new SharpCompress.Common.ExtractionOptions
+                            if (!reader.Entry.IsDirectory)
                             {
-                                ExtractFullPath = true,
-                                Overwrite = true
-                            });
+                                reader.WriteEntryToDirectory(stagingDir, new SharpCompress.Common.ExtractionOptions
+                                {
+                                    ExtractFullPath = true,
+                                    Overwrite = true
+                                });
+                            }
                         }
                     }
+                });
+
+                card.DownloadProgress = 95;
+
+                // Archives usually wrap everything in a top-level folder (sometimes nested);
+                // the model files must sit directly in the target folder for detection
+                string modelRoot = FindModelRoot(stagingDir);
+
+                // Verify the extracted files form a model layout Sherpa can load
+                if (Services.Speech.SherpaOnnxEngine.DetectModelType(modelRoot) == null)
+                {
+                    string layout = Services.Speech.SherpaOnnxEngine.DescribeLayout(modelRoot);
+                    throw new Exception($"Unrecognized model layout: expected a transducer, Whisper, Paraformer, SenseVoice or CTC model. {layout}");
                 }
-            });
 
-            card.DownloadProgress = 95;
+                if (Directory.Exists(targetDir)) Directory.Delete(targetDir, true);
+                Directory.Move(modelRoot, targetDir);
+            }
+            finally
+            {
+                card.IsExtracting = false;
+                try { if (Directory.Exists(stagingDir)) Directory.Delete(stagingDir, true); } catch { }
+            }
+
+            // Cleanup archive to save disk space
+            try { File.Delete(archivePath); } catch { }
+
+            card.DownloadProgress = 100;
+        }
 
-            // Validate extracted files
-            if (!Directory.Exists(targetDir))
+        /// <summary>
+        /// Descends through folders that contain nothing but a single subfolder.
+        /// </summary>
+        private static string FindModelRoot(string dir)
+        {
+            while (Directory.GetFiles(dir).Length == 0)
             {
-                // Cleanup archive
-                if (File.Exists(archivePath)) File.Delete(archivePath);
-                throw new Exception($"Extraction failed: directory '{card.Filename}' not found after extracting archive.");
+                var subDirs = Directory.GetDirectories(dir);
+                if (subDirs.Length != 1) break;
+                dir = subDirs[0];
             }
+            return dir;
+        }
+
+        /// <summary>
+        /// Known archive extensions, longest first so ".tar.gz" wins over ".gz".
+        /// </summary>
+        private static readonly string[] ArchiveExtensions = { ".tar.bz2", ".tar.gz", ".tgz", ".zip" };
+
+        private static string? GetArchiveExtension(string url)
+        {
+            // Ignore any query string when matching the extension
+            string path = url.Split('?')[0];
+            return ArchiveExtensions.FirstOrDefault(ext => path.EndsWith(ext, StringComparison.OrdinalIgnoreCase));
+        }
+
+        private static string GetArchivePath(ModelCardViewModel card, string modelsDir) =>
+            Path.Combine(modelsDir, card.Filename + (GetArchiveExtension(card.DownloadUrl) ?? ".tar.bz2"));
+
+        /// <summary>
+        /// Downloads the individual files of a Sherpa-ONNX model into a folder named after the card.
+        /// Files land in a staging folder that is only renamed once every file is verified,
+        /// so an interrupted download is never picked up as an installed model.
+        /// </summary>
+        private async Task DownloadSherpaFilesAsync(ModelCardViewModel card, string modelsDir)
+        {
+            var files = card.Files!;
+            string targetDir = Path.Combine(modelsDir, card.Filename);
+            string stagingDir = targetDir + ModelDownloadService.PARTIAL_EXTENSION;
+            Directory.CreateDirectory(stagingDir);
+
+            for (int i = 0; i < files.Count; i++)
+            {
+                var file = files[i];
+                string targetPath = Path.Combine(stagingDir, file.Filename);
+                Directory.CreateDirectory(Path.GetDirectoryName(targetPath)!);
+
+                // Files already completed by an earlier attempt are kept
+                if (!File.Exists(targetPath))
+                {
+                    int index = i;
+                    var progress = new Progress<Models.DownloadProgress>(p =>
+                    {
+                        card.DownloadProgress = (index + p.Percent / 100) / files.Count * 95;
+                        card.DownloadSpeedText = p.Describe();
+                    });
+                    await _downloadService.DownloadModelAsync(file.Url, targetPath, progress, CancellationToken.None);
+                }
 
-            // Verify required model files exist
-            bool hasEncoder = Directory.GetFiles(targetDir, "encoder*.onnx").Length > 0;
-            bool hasDecoder = Directory.GetFiles(targetDir, "decoder*.onnx").Length > 0;
-            bool hasJoiner = Directory.GetFiles(targetDir, "joiner*.onnx").Length > 0;
-            bool hasTokens = File.Exists(Path.Combine(targetDir, "tokens.txt"));
+                if (!string.IsNullOrEmpty(file.Sha256))
+                {
+                    string calculatedHash = await ComputeSha256HashAsync(targetPath);
+                    if (!string.Equals(calculatedHash, file.Sha256, StringComparison.OrdinalIgnoreCase))
+                    {
+                        File.Delete(targetPath);
+                        throw new Exception($"Checksum mismatch for {file.Filename}.\nExpected: {file.Sha256}\nActual: {calculatedHash}");
+                    }
+                }
+            }
 
-            if (!hasEncoder || !hasDecoder || !hasJoiner || !hasTokens)
+            if (Services.Speech.SherpaOnnxEngine.DetectModelType(stagingDir) == null)
             {
-                // Cleanup
-                try { Directory.Delete(targetDir, true); } catch { }
-                if (File.Exists(archivePath)) File.Delete(archivePath);
-                throw new Exception("Incomplete model: extracted files are missing encoder, decoder, joiner, or tokens.txt.");
+                string layout = Services.Speech.SherpaOnnxEngine.DescribeLayout(stagingDir);
+                try { Directory.Delete(stagingDir, true); } catch { }
+                throw new Exception($"Unrecognized model layout: expected a transducer, Whisper, Paraformer, SenseVoice or CTC model. {layout}");
             }
 
-            // Cleanup archive to save disk space
-            try { File.Delete(archivePath); } catch { }
+            if (Directory.Exists(targetDir)) Directory.Delete(targetDir, true);
+            Directory.Move(stagingDir, targetDir);
 
             card.DownloadProgress = 100;
         }
@@ -366,37 +586,19 @@ namespace EliteWhisper.ViewModels
                 filesToDownload.Add((card.DataDownloadUrl, card.DataFilename));
             }
 
-            long totalBytesAllFiles = (long)card.SizeMB * 1024 * 1024;
-            if (totalBytesAllFiles <= 0) totalBytesAllFiles = 1;
-
-            long totalReadSoFar = 0;
-
-            foreach (var file in filesToDownload)
+            for (int i = 0; i < filesToDownload.Count; i++)
             {
+                var file = filesToDownload[i];
                 string targetPath = Path.Combine(modelsDir, file.Filename);
-                string tempPath = targetPath + ".tmp";
 
-                using (var response = await _httpClient.GetAsync(file.Url, HttpCompletionOption.ResponseHeadersRead))
+                // Each file gets an equal share of the progress bar
+                int index = i;
+                var progress = new Progress<Models.DownloadProgress>(p =>
                 {
-                    response.EnsureSuccessStatusCode();
-
-                    using (var contentStream = await response.Content.ReadAsStreamAsync())
-                    using (var fileStream = new FileStream(tempPath, FileMode.Create, FileAccess.Write, FileShare.None, 8192, true))
-                    {
-                        var buffer = new byte[8192];
-                        int read;
-
-                        while ((read = await contentStream.ReadAsync(buffer, 0, buffer.Length)) > 0)
-                        {
-                            await fileStream.WriteAsync(buffer, 0, read);
-                            totalReadSoFar += read;
-                            card.DownloadProgress = (double)totalReadSoFar / totalBytesAllFiles * 100;
-                        }
-                    }
-                }
-
-                if (File.Exists(targetPath)) File.Delete(targetPath);
-                File.Move(tempPath, targetPath);
+                    card.DownloadProgress = (index + p.Percent / 100) / filesToDownload.Count * 100;
+                    card.DownloadSpeedText = p.Describe();
+                });
+                await _downloadService.DownloadModelAsync(file.Url, targetPath, progress, CancellationToken.None);
             }
 
             // Validation
@@ -488,7 +690,7 @@ namespace EliteWhisper.ViewModels
         [RelayCommand]
         private async Task ActivateModel(ModelCardViewModel card)
         {
-            if (!card.IsInstalled || IsBusy) return;
+            if (!card.IsInstalled || IsBusy || _aiEngine.IsActivating) return;
 
             string? modelsDir = _configService.CurrentConfiguration.ModelsDirectory;
             if (string.IsNullOrEmpty(modelsDir) || string.IsNullOrEmpty(card.Filename)) return;
@@ -501,11 +703,26 @@ namespace EliteWhisper.ViewModels
                 // Instead, we set the STT engine preference and the SpeechEngineSelector picks them up.
                 if (string.Equals(card.EngineType, "sherpa", StringComparison.OrdinalIgnoreCase))
                 {
-                    var config = _configService.CurrentConfiguration;
-                    config.PreferredSTTEngine = "Sherpa";
-                    config.AutoSelectSTT = false;
-                    config.DefaultModelPath = fullPath; // Crucial for UI resolving ActiveModelId
-                    _configService.SaveConfiguration(config);
+                    // Load and test the recognizer first; a broken model must not replace the working one
+                    string? error;
+                    IsBusy = true;
+                    try
+                    {
+                        error = await Task.Run(() => _engineSelector.ActivateSherpaModel(fullPath));
+                    }
+                    finally
+                    {
+                        IsBusy = false;
+                    }
+
+                    if (error != null)
+                    {
+                        MessageBox.Show($"Unable to activate {card.Name}. The current model is still active.\n\n{error}",
+                            "Activation Failed", MessageBoxButton.OK, MessageBoxImage.Warning);
+                        return;
+                    }
+
+                    _recentModels.RecordUsage(fullPath, "Sherpa");
 
                     ActiveModelId = card.Id;
                     MessageBox.Show("Parakeet (Sherpa) is now the active speech engine.\nIt will be used for your next transcription.", 
@@ -522,7 +739,8 @@ namespace EliteWhisper.ViewModels
                     }
                     else
                     {
-                        MessageBox.Show("Unable to activate model. Please try again.", "Activation Failed", MessageBoxButton.OK, MessageBoxImage.Warning);
+                        MessageBox.Show($"Unable to activate model. The current model is still active.\n\n{_aiEngine.LastActivationError ?? "Please try again."}",
+                            "Activation Failed", MessageBoxButton.OK, MessageBoxImage.Warning);
                     }
                 }
             }
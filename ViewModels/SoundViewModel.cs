@@ -1,7 +1,9 @@
 using CommunityToolkit.Mvvm.ComponentModel;
 using CommunityToolkit.Mvvm.Input;
+using EliteWhisper.Models;
 using EliteWhisper.Services;
 using System;
+using System.Collections.Generic;
 using System.Collections.ObjectModel;
 using System.IO;
 using System.Windows;
@@ -13,8 +15,11 @@ namespace EliteWhisper.ViewModels
     {
         private readonly AudioCaptureService _audioCaptureService;
         private readonly AudioPlayerService _audioPlayerService;
+        private readonly WhisperConfigurationService _configService;
         private readonly Dispatcher _dispatcher;
         private string? _tempTestFilePath;
+        private List<InputDeviceInfo> _inputDevices = new();
+        private bool _isRefreshingDevices;
 
         [ObservableProperty]
         private bool _isMonitoring;
@@ -31,6 +36,10 @@ namespace EliteWhisper.ViewModels
         [ObservableProperty]
         private string _inputLevelColor = "#9CA3AF"; // Gray/TextSecondary
 
+        // Peak level in dBFS, e.g. "-12 dB"
+        [ObservableProperty]
+        private string _inputLevelDb = string.Empty;
+
         // Microphone Selection
         [ObservableProperty]
         private ObservableCollection<string> _availableMicrophones = new();
@@ -38,6 +47,12 @@ namespace EliteWhisper.ViewModels
         [ObservableProperty]
         private int _selectedMicrophoneIndex = 0;
 
+        [ObservableProperty]
+        private int _preRollMs;
+
+        [ObservableProperty]
+        private bool _enableNormalization;
+
         // Test Recording Properties
         [ObservableProperty]
         [NotifyPropertyChangedFor(nameof(TestButtonText))]
@@ -53,18 +68,45 @@ namespace EliteWhisper.ViewModels
         public string TestButtonText => IsTestingRecording ? "Stop Test" : "Start Test";
         public string PlayButtonText => IsTestingPlaying ? "Stop Playing" : "Play Recording";
 
-        public SoundViewModel(AudioCaptureService audioCaptureService, AudioPlayerService audioPlayerService)
+        public SoundViewModel(AudioCaptureService audioCaptureService, AudioPlayerService audioPlayerService,
+            WhisperConfigurationService configService)
         {
             _audioCaptureService = audioCaptureService;
             _audioPlayerService = audioPlayerService;
+            _configService = configService;
             _dispatcher = Application.Current.Dispatcher;
             
             _audioCaptureService.AudioLevelUpdated += OnAudioLevelUpdated;
             _audioPlayerService.PlaybackStopped += OnPlaybackStopped;
 
+            PreRollMs = _audioCaptureService.PreRollMs;
+            EnableNormalization = _configService.CurrentConfiguration.EnableNormalization;
             RefreshDevices();
         }
 
+        partial void OnEnableNormalizationChanged(bool value)
+        {
+            var config = _configService.CurrentConfiguration;
+            if (config.EnableNormalization != value)
+            {
+                _configService.SetNormalization(value, config.NormalizationTargetDb);
+            }
+        }
+
+        partial void OnPreRollMsChanged(int value)
+        {
+            if (_audioCaptureService.PreRollMs == value) return;
+
+            try
+            {
+                _audioCaptureService.SetPreRoll(value);
+            }
+            catch (Exception ex)
+            {
+                MessageBox.Show($"Error setting pre-roll: {ex.Message}", "Error");
+            }
+        }
+
         partial void OnIsMonitoringChanged(bool value)
         {
             MonitoringButtonText = value ? "Stop Monitoring" : "Start Monitoring";
@@ -144,13 +186,16 @@ namespace EliteWhisper.ViewModels
 
         partial void OnSelectedMicrophoneIndexChanged(int value)
         {
-            if (value >= 0)
+            if (_isRefreshingDevices) return;
+
+            if (value >= 0 && value < _inputDevices.Count)
             {
                 // If monitoring, restart to apply change
                 bool wasMonitoring = IsMonitoring;
                 if (wasMonitoring) IsMonitoring = false;
 
-                _audioCaptureService.DeviceNumber = value;
+                // Persist by name - device indices shift when devices are plugged in or out
+                _audioCaptureService.SetInputDevice(_inputDevices[value].Name);
                 
                 if (wasMonitoring) IsMonitoring = true;
             }
@@ -159,29 +204,33 @@ namespace EliteWhisper.ViewModels
         [RelayCommand]
         public void RefreshDevices()
         {
-            AvailableMicrophones.Clear();
-            var mics = AudioCaptureService.GetAvailableMicrophones();
-            if (mics.Length == 0)
-            {
-                AvailableMicrophones.Add("No Microphone Found");
-                SelectedMicrophoneIndex = -1;
-            }
-            else
+            _isRefreshingDevices = true;
+            try
             {
-                foreach (var mic in mics)
+                AvailableMicrophones.Clear();
+                _inputDevices = AudioCaptureService.GetInputDevices();
+                if (_inputDevices.Count == 0)
                 {
-                    AvailableMicrophones.Add(mic);
-                }
-                // Validate index
-                if (_audioCaptureService.DeviceNumber < mics.Length)
-                {
-                    SelectedMicrophoneIndex = _audioCaptureService.DeviceNumber;
+                    AvailableMicrophones.Add("No Microphone Found");
+                    SelectedMicrophoneIndex = -1;
                 }
                 else
                 {
-                    SelectedMicrophoneIndex = 0;
+                    foreach (var device in _inputDevices)
+                    {
+                        AvailableMicrophones.Add(device.ToString());
+                    }
+
+                    // Show the device recordings will actually use (falls back to default if unplugged)
+                    string activeName = _audioCaptureService.ActiveDeviceName;
+                    int index = _inputDevices.FindIndex(d => d.Name == activeName);
+                    SelectedMicrophoneIndex = Math.Max(0, index);
                 }
             }
+            finally
+            {
+                _isRefreshingDevices = false;
+            }
         }
 
         [RelayCommand]
@@ -220,6 +269,7 @@ namespace EliteWhisper.ViewModels
             _dispatcher.Invoke(() =>
             {
                 InputLevel = level * 100;
+                InputLevelDb = $"{AudioCaptureService.ToDecibels(level):F0} dB";
 
                 if (InputLevel < 1)
                 {
@@ -247,6 +297,7 @@ namespace EliteWhisper.ViewModels
         private void ResetLevel()
         {
             InputLevel = 0;
+            InputLevelDb = string.Empty;
             InputLevelStatus = "Silent";
             InputLevelColor = "#9CA3AF";
         }
@@ -0,0 +1,43 @@
+using System.Collections.Generic;
+using System.Linq;
+using System.Windows;
+
+namespace EliteWhisper.Views
+{
+    /// <summary>
+    /// Dialog for correcting a history item's transcript and giving it a title and tags.
+    /// </summary>
+    public partial class EditTranscriptWindow : Window
+    {
+        public EditTranscriptWindow(string content, string? title, IEnumerable<string>? tags = null)
+        {
+            InitializeComponent();
+            ContentBox.Text = content;
+            TitleBox.Text = title ?? string.Empty;
+            TagsBox.Text = tags == null ? string.Empty : string.Join(", ", tags);
+            Loaded += (s, e) => ContentBox.Focus();
+        }
+
+        public string TranscriptText => ContentBox.Text;
+        public string TranscriptTitle => TitleBox.Text;
+
+        public List<string> Tags => TagsBox.Text
+            .Split(',', System.StringSplitOptions.RemoveEmptyEntries | System.StringSplitOptions.TrimEntries)
+            .Select(t => t.TrimStart('#').Trim())
+            .Where(t => t.Length > 0)
+            .Distinct(System.StringComparer.OrdinalIgnoreCase)
+            .ToList();
+
+        private void Save_Click(object sender, RoutedEventArgs e)
+        {
+            if (string.IsNullOrWhiteSpace(ContentBox.Text))
+            {
+                MessageBox.Show(this, "The transcript can't be empty.", "Edit Transcript",
+                    MessageBoxButton.OK, MessageBoxImage.Warning);
+                return;
+            }
+
+            DialogResult = true;
+        }
+    }
+}
@@ -23,6 +23,9 @@ namespace EliteWhisper.Views.Pages
                     
                     // Load saved hotkey
                     _viewModel.LoadSavedHotkey();
+
+                    // The engine may have changed on the Models page since the last visit
+                    Loaded += (s, e) => _viewModel?.RefreshEngineInfo();
                 }
             }
 
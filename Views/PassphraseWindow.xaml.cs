@@ -0,0 +1,36 @@
+using System.Windows;
+
+namespace EliteWhisper.Views
+{
+    /// <summary>
+    /// Asks for a new history passphrase twice so a typo doesn't lock the user out.
+    /// </summary>
+    public partial class PassphraseWindow : Window
+    {
+        public PassphraseWindow()
+        {
+            InitializeComponent();
+            Loaded += (s, e) => PassphraseBox.Focus();
+        }
+
+        public string Passphrase => PassphraseBox.Password;
+
+        private void Ok_Click(object sender, RoutedEventArgs e)
+        {
+            string? error = null;
+            if (PassphraseBox.Password.Length < 8)
+                error = "The passphrase must be at least 8 characters.";
+            else if (PassphraseBox.Password != ConfirmBox.Password)
+                error = "The passphrases don't match.";
+
+            if (error != null)
+            {
+                ErrorText.Text = error;
+                ErrorText.Visibility = Visibility.Visible;
+                return;
+            }
+
+            DialogResult = true;
+        }
+    }
+}
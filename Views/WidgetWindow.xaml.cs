@@ -15,17 +15,25 @@ namespace EliteWhisper.Views
         private readonly WidgetViewModel _viewModel;
         private readonly HotkeyService _hotkeyService;
         private readonly DictationService _dictationService;
+        private readonly WhisperConfigurationService _configService;
         private IntPtr _handle;
 
+        // Collapsed size, used for placement before the first layout pass
+        private const double COLLAPSED_WIDTH = 148;
+        private const double COLLAPSED_HEIGHT = 52;
+        private const double BOTTOM_MARGIN = 24;
+
         public WidgetWindow(
             WidgetViewModel viewModel, 
             HotkeyService hotkeyService,
-            DictationService dictationService)
+            DictationService dictationService,
+            WhisperConfigurationService configService)
         {
             InitializeComponent();
             _viewModel = viewModel;
             _hotkeyService = hotkeyService;
             _dictationService = dictationService;
+            _configService = configService;
             DataContext = _viewModel;
 
             // Win32 hooks
@@ -39,6 +47,8 @@ namespace EliteWhisper.Views
             
             // Subscribe to hotkey
             _hotkeyService.OnHotkeyPressed += OnHotkeyPressed;
+            _hotkeyService.OnHotkeyReleased += OnHotkeyReleased;
+            _hotkeyService.ActionTriggered += OnHotkeyAction;
             
             // Subscribe to ViewModel events (button clicks mirror hotkey)
             _viewModel.OnRecordButtonClicked += () => _ = HandleDictationToggle();
@@ -136,7 +146,12 @@ namespace EliteWhisper.Views
         {
             if (e.Key == Key.Escape)
             {
-                if (_viewModel.VisualState == WidgetVisualState.Expanded)
+                if (_dictationService.IsTranscribing)
+                {
+                    // First Escape aborts a long transcription instead of hiding the widget
+                    _dictationService.Cancel();
+                }
+                else if (_viewModel.VisualState == WidgetVisualState.Expanded)
                 {
                     TransitionToCollapsed();
                 }
@@ -167,6 +182,84 @@ namespace EliteWhisper.Views
             
             // Register hotkey here - handle is now valid
             _hotkeyService.Register(_handle);
+
+            RestorePosition();
+        }
+
+        // ==================== POSITION ====================
+
+        /// <summary>
+        /// Moves the widget back to the default spot (centered above the taskbar) and forgets the saved position.
+        /// </summary>
+        public void ResetPosition()
+        {
+            _configService.SetWidgetPosition(null, null);
+            MoveToDefaultPosition();
+        }
+
+        private void RestorePosition()
+        {
+            var config = _configService.CurrentConfiguration;
+            if (config.WidgetLeft is not double left || config.WidgetTop is not double top)
+            {
+                MoveToDefaultPosition();
+                return;
+            }
+
+            // A monitor may have been disconnected since; only reuse the spot if it's still on a screen
+            var area = FindWorkArea(left, top);
+            if (area == null)
+            {
+                MoveToDefaultPosition();
+                return;
+            }
+
+            double width = ActualWidth > 0 ? ActualWidth : COLLAPSED_WIDTH;
+            double height = ActualHeight > 0 ? ActualHeight : COLLAPSED_HEIGHT;
+            Left = Math.Max(area.Value.Left, Math.Min(left, area.Value.Right - width));
+            Top = Math.Max(area.Value.Top, Math.Min(top, area.Value.Bottom - height));
+        }
+
+        private void MoveToDefaultPosition()
+        {
+            var area = SystemParameters.WorkArea;
+            double width = ActualWidth > 0 ? ActualWidth : COLLAPSED_WIDTH;
+            double height = ActualHeight > 0 ? ActualHeight : COLLAPSED_HEIGHT;
+            Left = area.Left + (area.Width - width) / 2;
+            Top = area.Bottom - height - BOTTOM_MARGIN;
+        }
+
+        /// <summary>
+        /// Work area (in DIPs) of the screen containing the point, or null if it is off every screen.
+        /// </summary>
+        private Rect? FindWorkArea(double left, double top)
+        {
+            var dpi = System.Windows.Media.VisualTreeHelper.GetDpi(this);
+            foreach (var screen in System.Windows.Forms.Screen.AllScreens)
+            {
+                var wa = screen.WorkingArea;
+                var area = new Rect(wa.Left / dpi.DpiScaleX, wa.Top / dpi.DpiScaleY,
+                    wa.Width / dpi.DpiScaleX, wa.Height / dpi.DpiScaleY);
+                if (area.Contains(new System.Windows.Point(left, top))) return area;
+            }
+            return null;
+        }
+
+        private void SavePosition()
+        {
+            if (double.IsNaN(Left) || double.IsNaN(Top)) return;
+
+            var config = _configService.CurrentConfiguration;
+            if (config.WidgetLeft == Left && config.WidgetTop == Top) return;
+
+            try
+            {
+                _configService.SetWidgetPosition(Left, Top);
+            }
+            catch (Exception ex)
+            {
+                System.Diagnostics.Debug.WriteLine($"Failed to save widget position: {ex.Message}");
+            }
         }
 
         private void WidgetWindow_Loaded(object sender, RoutedEventArgs e)
@@ -179,9 +272,66 @@ namespace EliteWhisper.Views
         
         private async void OnHotkeyPressed()
         {
+            if (_hotkeyService.IsPushToTalk)
+            {
+                // Key down: start recording straight away, even from hidden
+                if (_viewModel.State == WidgetState.Hidden) ShowOverlay();
+                if (_viewModel.State == WidgetState.Hidden || _viewModel.State == WidgetState.Ready)
+                {
+                    _dictationService.StartListening(RecordingSource.Widget);
+                }
+                return;
+            }
+
             await HandleDictationToggle();
         }
 
+        private async void OnHotkeyReleased()
+        {
+            // Key up: stop and transcribe
+            if (_viewModel.State == WidgetState.Listening)
+            {
+                await _dictationService.StopListeningAndProcessAsync();
+            }
+        }
+
+        private async void OnHotkeyAction(HotkeyAction action)
+        {
+            switch (action)
+            {
+                case HotkeyAction.Cancel:
+                    if (_viewModel.State == WidgetState.Listening || _dictationService.IsTranscribing)
+                    {
+                        _dictationService.Cancel();
+                    }
+                    break;
+
+                case HotkeyAction.TranscribeClipboardAudio:
+                    if (_viewModel.State == WidgetState.Hidden)
+                    {
+                        ShowOverlay();
+                        _viewModel.State = WidgetState.Ready;
+                    }
+                    await _dictationService.TranscribeClipboardAudioAsync();
+                    break;
+
+                case HotkeyAction.RetypeLast:
+                case HotkeyAction.RecopyLast:
+                    await HotkeyService.WaitForModifiersReleasedAsync(TimeSpan.FromSeconds(2));
+                    bool delivered = action == HotkeyAction.RetypeLast
+                        ? await _dictationService.RetypeLastAsync()
+                        : await _dictationService.RecopyLastAsync();
+
+                    if (_viewModel.State == WidgetState.Ready)
+                    {
+                        _viewModel.StatusText = delivered
+                            ? (action == HotkeyAction.RetypeLast ? "Re-typed last transcript" : "Copied last transcript")
+                            : "No transcript to repeat";
+                    }
+                    break;
+            }
+        }
+
         /// <summary>
         /// Shared dictation toggle logic - used by BOTH hotkey and record button.
         /// </summary>
@@ -242,6 +392,9 @@ namespace EliteWhisper.Views
         {
             base.OnMouseLeftButtonDown(e);
             this.DragMove();
+
+            // DragMove returns once the button is released
+            SavePosition();
         }
     }
 }